@@ -0,0 +1,130 @@
+// Potential curve editor
+// The plotted Lennard-Jones curve doubles as a controller: grab the well
+// bottom and drag it sideways to move the equilibrium separation (σ) or
+// up and down to change the well depth (ε). The parameters are written
+// straight into the live `LennardJones` resource.
+
+use bevy::prelude::*;
+use crate::physics::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+use crate::physics::curves::{CurveHit, FeatureKind, PotentialCurve};
+use crate::physics::lennard_jones::{LennardJones, WELL_TO_SIGMA};
+use crate::rendering::curve_plot::CurvePlot;
+
+/// Drag state and limits for the curve editor.
+#[derive(Resource, Debug, Clone)]
+pub struct CurveEditor {
+    /// Whether the well is currently held
+    pub grabbed: bool,
+    /// How close (pixels) the pointer must be to grab the well
+    pub grab_radius: f32,
+    /// Allowed σ in meters
+    pub sigma_range: (f64, f64),
+    /// Allowed ε in Joules
+    pub epsilon_range: (f64, f64),
+}
+
+impl Default for CurveEditor {
+    fn default() -> Self {
+        Self {
+            grabbed: false,
+            grab_radius: 12.0,
+            sigma_range: (0.25 * ANGSTROM, 2.5 * ANGSTROM),
+            epsilon_range: (0.02 * ELEMENTARY_CHARGE, 3.0 * ELEMENTARY_CHARGE),
+        }
+    }
+}
+
+impl CurveEditor {
+    /// Try to grab the well minimum under the pointer.
+    ///
+    /// # Returns
+    /// Whether the well was grabbed.
+    pub fn press(&mut self, pointer: Vec2, plot: &CurvePlot, curve: &PotentialCurve) -> bool {
+        let (distance, energy) = plot.to_curve(pointer);
+        let (distance_tolerance, energy_tolerance) = plot.tolerances(self.grab_radius);
+        self.grabbed = matches!(
+            curve.hit_test(distance, energy, distance_tolerance, energy_tolerance),
+            Some(CurveHit::Feature(index)) if curve.features[index].kind == FeatureKind::Minimum
+        );
+        self.grabbed
+    }
+
+    /// Move the held well to the pointer, within the allowed parameter ranges.
+    ///
+    /// # Returns
+    /// Whether the parameters changed.
+    pub fn drag(&self, pointer: Vec2, plot: &CurvePlot, lj: &mut LennardJones) -> bool {
+        if !self.grabbed {
+            return false;
+        }
+        let (distance, energy) = plot.to_curve(pointer);
+        let sigma = (distance / WELL_TO_SIGMA).clamp(self.sigma_range.0, self.sigma_range.1);
+        let epsilon = (-energy).clamp(self.epsilon_range.0, self.epsilon_range.1);
+
+        let changed = sigma != lj.sigma || epsilon != lj.epsilon;
+        lj.sigma = sigma;
+        lj.epsilon = epsilon;
+        changed
+    }
+
+    /// Let go of the well
+    pub fn release(&mut self) {
+        self.grabbed = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::curves::{sample_curve, CurveConfig};
+    use approx::assert_relative_eq;
+
+    fn curve(lj: &LennardJones, plot: &CurvePlot) -> PotentialCurve {
+        sample_curve(&lj.potential(), plot.distance_range.0, plot.distance_range.1, &CurveConfig::default())
+    }
+
+    #[test]
+    fn dragging_the_well_sets_sigma_and_epsilon() {
+        let plot = CurvePlot::default();
+        let mut lj = LennardJones::default();
+        let mut editor = CurveEditor::default();
+
+        let well = plot.to_screen(lj.well_distance(), -lj.epsilon);
+        assert!(editor.press(well + Vec2::new(3.0, -2.0), &plot, &curve(&lj, &plot)));
+
+        let target = plot.to_screen(1.5 * ANGSTROM, -0.5 * ELEMENTARY_CHARGE);
+        assert!(editor.drag(target, &plot, &mut lj));
+        assert_relative_eq!(lj.well_distance(), 1.5 * ANGSTROM, max_relative = 1e-4);
+        assert_relative_eq!(lj.epsilon, 0.5 * ELEMENTARY_CHARGE, max_relative = 1e-4);
+
+        // The resampled curve has its minimum where the pointer left it
+        let minimum = curve(&lj, &plot).minimum().unwrap();
+        assert_relative_eq!(minimum.distance, 1.5 * ANGSTROM, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn missing_the_well_grabs_nothing() {
+        let plot = CurvePlot::default();
+        let mut lj = LennardJones::default();
+        let mut editor = CurveEditor::default();
+
+        let elsewhere = plot.to_screen(2.5 * ANGSTROM, 2.0 * ELEMENTARY_CHARGE);
+        assert!(!editor.press(elsewhere, &plot, &curve(&lj, &plot)));
+        assert!(!editor.drag(plot.origin, &plot, &mut lj));
+        assert_eq!(lj, LennardJones::default());
+    }
+
+    #[test]
+    fn parameters_stay_in_range() {
+        let plot = CurvePlot::default();
+        let mut lj = LennardJones::default();
+        let mut editor = CurveEditor { grabbed: true, ..default() };
+
+        // Above the axis: the well cannot turn into a hill
+        editor.drag(plot.to_screen(1.0 * ANGSTROM, 1.0 * ELEMENTARY_CHARGE), &plot, &mut lj);
+        assert_eq!(lj.epsilon, editor.epsilon_range.0);
+
+        editor.release();
+        assert!(!editor.drag(plot.origin, &plot, &mut lj));
+    }
+}
@@ -1,3 +1,4 @@
 // Input handling (touch abstraction, virtual spring)
 
 pub mod spring;
+pub mod curve_editor;
@@ -6,3 +6,5 @@ pub mod physics;
 pub mod particles;
 pub mod input;
 pub mod rendering;
+pub mod math;
+pub mod quantum;
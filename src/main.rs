@@ -3,16 +3,30 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use bevy::prelude::*;
-use glam::DVec3;
+use glam::{DVec2, DVec3};
 
 use dynachem::physics::constants::{BOHR_RADIUS, COULOMB_CONSTANT, ELEMENTARY_CHARGE};
-use dynachem::physics::coulomb::coulomb_force;
-use dynachem::physics::simulation::{verlet_position_step, verlet_velocity_step, Integratable};
+use dynachem::physics::medium::Medium;
+use dynachem::physics::lennard_jones::LennardJones;
+use dynachem::physics::curves::{sample_curve, CurveConfig};
+use dynachem::physics::structure::{Phase, PhaseClassifier};
+use dynachem::physics::diffusion::MsdTracker;
+use dynachem::physics::conservation::{ConservationMonitor, ConservationSnapshot, ConservationThresholds, ConservationWarning};
+use dynachem::physics::simulation::{boris_step, verlet_position_step, verlet_velocity_step, Integratable};
+use dynachem::physics::magnetic::MagneticField;
+use dynachem::physics::bohr::{snap_electron, BohrNucleus, BohrSnapConfig, BohrState};
+use dynachem::physics::external::{ExternalField, HarmonicTrap, PotentialGrid, SourceKind, UniformField};
+use dynachem::physics::bonding::{BondBroken, BondFormed, BondGraph, BondPerception, PerceivedAtom};
+use dynachem::physics::ionization::{count_bound, update_binding, Binding, IonizationMeter, Ionized, NetCharge};
 use dynachem::particles::proton::Proton;
-use dynachem::particles::electron::Electron;
+use dynachem::particles::electron::{Electron, ProbabilityCloud};
+use dynachem::particles::photon::{Photon, PhotonEmitted};
 use dynachem::input::spring::{spring_force, SpringConfig, TouchInput, Draggable};
+use dynachem::input::curve_editor::CurveEditor;
 use dynachem::rendering::proton::{ProtonRenderConfig, physics_to_screen, screen_to_physics};
 use dynachem::rendering::electron_cloud::ElectronCloudVisual;
+use dynachem::rendering::curve_plot::CurvePlot;
+use dynachem::rendering::phase::PhaseColoring;
 
 fn main() {
     App::new()
@@ -33,14 +47,48 @@ fn main() {
         })
         .insert_resource(TouchInput::default())
         .insert_resource(SimulationTime { dt: 1.0e-17 })
+        .insert_resource(BohrSnapConfig::default())
+        .insert_resource(IonizationMeter::default())
+        .insert_resource(default_external_field())
+        .insert_resource(MagneticField::default())
+        .insert_resource(Medium::vacuum())
+        .insert_resource(BondPerception::default())
+        .insert_resource(BondGraph::default())
+        .insert_resource(LennardJones::default())
+        .insert_resource(CurvePlot::default())
+        .insert_resource(CurveEditor::default())
+        .insert_resource(PhaseClassifier::planar())
+        .insert_resource(PhaseColoring::default())
+        .insert_resource(MsdTracker::default())
+        .insert_resource(ConservationMonitor::default())
+        .add_event::<PhotonEmitted>()
+        .add_event::<Ionized>()
+        .add_event::<BondFormed>()
+        .add_event::<BondBroken>()
+        .add_event::<ConservationWarning>()
         .add_systems(Startup, setup)
         .add_systems(Update, (
+            edit_potential_curve,
             handle_mouse_input,
+            handle_field_controls,
             apply_spring_force,
             apply_coulomb_forces,
+            apply_lennard_jones_forces,
+            apply_external_fields,
             physics_step,
+            measure_spring_work,
+            detect_ionization,
+            perceive_bonds,
+            classify_phase,
+            track_diffusion,
+            bohr_snap,
+            monitor_conservation,
             sync_visuals,
             update_electron_cloud_shimmer,
+            update_ionization_readout,
+            draw_potential_curve,
+            draw_msd_plot,
+            update_debug_overlay,
         ).chain())
         .run();
 }
@@ -56,6 +104,59 @@ struct PhysicsProton(Proton);
 #[derive(Component)]
 struct PhysicsElectron(Electron);
 
+#[derive(Component)]
+struct IonizationReadout;
+
+#[derive(Component)]
+struct CurveReadout;
+
+#[derive(Component)]
+struct PhaseIndicator;
+
+#[derive(Component)]
+struct DiffusionReadout;
+
+#[derive(Component)]
+struct DebugOverlay;
+
+/// Hydrogen nuclei as seen by the binding and snap calculations
+fn hydrogen_nuclei(protons: &Query<(Entity, &PhysicsProton)>) -> Vec<BohrNucleus> {
+    protons.iter()
+        .map(|(entity, p)| BohrNucleus {
+            entity,
+            position: p.0.position,
+            velocity: p.0.velocity,
+            z: 1,
+            mass: Proton::mass(),
+        })
+        .collect()
+}
+
+/// Index of each external source in the scene's `ExternalField`
+const UNIFORM_FIELD: usize = 0;
+const CENTRE_TRAP: usize = 1;
+
+/// Scene fields: a capacitor field and a trap (both off) and an empty paintable landscape
+fn default_external_field() -> ExternalField {
+    let mut field = ExternalField::default();
+
+    // 1 V across 1 nm, pushing protons to the right
+    field.add(SourceKind::Uniform(UniformField::capacitor(1.0, 1.0e-9, DVec3::X)));
+    field.add(SourceKind::Trap(HarmonicTrap {
+        center: DVec3::ZERO,
+        stiffness: 1.0,
+    }));
+    field.add(SourceKind::Custom(PotentialGrid::flat(
+        DVec2::ZERO,
+        DVec2::new(8.0e-10, 6.0e-10),
+        0.05e-10,
+    )));
+
+    field.set_active(UNIFORM_FIELD, false);
+    field.set_active(CENTRE_TRAP, false);
+    field
+}
+
 fn setup(mut commands: Commands) {
     // Camera
     commands.spawn(Camera2d);
@@ -68,6 +169,7 @@ fn setup(mut commands: Commands) {
 
     commands.spawn((
         PhysicsProton(Proton::new(proton_physics_pos)),
+        NetCharge::neutral(1),
         Draggable::default(),
         Sprite {
             color: Color::srgb(1.0, 0.4, 0.2),
@@ -92,6 +194,10 @@ fn setup(mut commands: Commands) {
 
     commands.spawn((
         PhysicsElectron(electron),
+        ProbabilityCloud::hydrogen_1s(electron_physics_pos),
+        BohrState::default(),
+        Binding::default(),
+        Draggable::default(),
         ElectronCloudVisual::default(),
         Sprite {
             color: Color::srgba(0.3, 0.5, 1.0, 0.4),
@@ -103,7 +209,7 @@ fn setup(mut commands: Commands) {
 
     // Instructions text
     commands.spawn((
-        Text::new("Click and drag the orange proton!\nThe blue electron cloud responds to Coulomb forces."),
+        Text::new("Click and drag the orange proton!\nThe blue electron cloud responds to Coulomb forces.\nDrag the electron off to measure the ionization energy.\nE: capacitor field  T: trap  Right-drag: paint hills (Shift: wells)  C: clear  W: vacuum/water/saline  P: colour by phase  F3: debug\nDrag the well in the curve (bottom right) to tune the Lennard-Jones σ and ε."),
         TextFont {
             font_size: 18.0,
             ..default()
@@ -116,6 +222,92 @@ fn setup(mut commands: Commands) {
             ..default()
         },
     ));
+
+    // Ionization measurement readout
+    commands.spawn((
+        IonizationReadout,
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgba(1.0, 0.9, 0.5, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+    ));
+
+    // Phase of the sandbox, top right
+    commands.spawn((
+        PhaseIndicator,
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.8, 0.9, 1.0, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+    ));
+
+    // Mean-square displacement and diffusion estimate, under the MSD plot
+    commands.spawn((
+        DiffusionReadout,
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.7, 0.8, 1.0, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(150.0),
+            right: Val::Px(20.0),
+            ..default()
+        },
+    ));
+
+    // Conservation diagnostics, hidden until F3
+    commands.spawn((
+        DebugOverlay,
+        Text::new(""),
+        TextFont {
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(Color::srgba(1.0, 0.8, 0.6, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(110.0),
+            left: Val::Px(10.0),
+            ..default()
+        },
+        Visibility::Hidden,
+    ));
+
+    // Lennard-Jones parameters, above the potential curve
+    commands.spawn((
+        CurveReadout,
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.6, 1.0, 0.7, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(175.0),
+            right: Val::Px(20.0),
+            ..default()
+        },
+    ));
 }
 
 fn handle_mouse_input(
@@ -123,8 +315,13 @@ fn handle_mouse_input(
     windows: Query<&Window>,
     cameras: Query<(&Camera, &GlobalTransform)>,
     mut touch_input: ResMut<TouchInput>,
+    mut meter: ResMut<IonizationMeter>,
     render_config: Res<ProtonRenderConfig>,
-    protons: Query<(Entity, &PhysicsProton, &Transform), With<Draggable>>,
+    draggables: Query<(Entity, &Transform), With<Draggable>>,
+    bindings: Query<&Binding>,
+    charges: Query<&NetCharge>,
+    plot: Res<CurvePlot>,
+    editor: Res<CurveEditor>,
 ) {
     let window = windows.single();
     let (camera, camera_transform) = cameras.single();
@@ -132,29 +329,135 @@ fn handle_mouse_input(
     if let Some(cursor_pos) = window.cursor_position()
         .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
     {
+        // Presses on the potential curve belong to the curve editor
+        if editor.grabbed || (mouse_button.just_pressed(MouseButton::Left) && plot.contains(cursor_pos)) {
+            return;
+        }
+
         let physics_pos = screen_to_physics(cursor_pos, &render_config);
 
         if mouse_button.just_pressed(MouseButton::Left) {
-            // Check if we clicked on a proton
-            for (entity, proton, transform) in protons.iter() {
-                let proton_screen = Vec2::new(transform.translation.x, transform.translation.y);
-                if cursor_pos.distance(proton_screen) < 30.0 {
-                    touch_input.begin(physics_pos, entity);
-                    break;
+            // Pick the closest draggable particle under the cursor
+            let picked = draggables.iter()
+                .map(|(entity, transform)| (entity, cursor_pos.distance(transform.translation.truncate())))
+                .filter(|(_, distance)| *distance < 30.0)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            if let Some((entity, _)) = picked {
+                touch_input.begin(physics_pos, entity);
+
+                // Grabbing a bound electron starts an ionization measurement
+                let nucleus = bindings.get(entity).ok().and_then(|b| b.nucleus);
+                if let Some(charge) = nucleus.and_then(|n| charges.get(n).ok()) {
+                    meter.start(entity, charge.z);
                 }
             }
         } else if mouse_button.pressed(MouseButton::Left) && touch_input.active {
             touch_input.update_position(physics_pos);
         } else if mouse_button.just_released(MouseButton::Left) {
             touch_input.end();
+            meter.cancel();
+        }
+    }
+}
+
+fn edit_potential_curve(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    plot: Res<CurvePlot>,
+    mut editor: ResMut<CurveEditor>,
+    mut lj: ResMut<LennardJones>,
+) {
+    if mouse_button.just_released(MouseButton::Left) {
+        editor.release();
+        return;
+    }
+
+    let window = windows.single();
+    let (camera, camera_transform) = cameras.single();
+    let Some(cursor_pos) = window.cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+
+    if mouse_button.just_pressed(MouseButton::Left) && plot.contains(cursor_pos) {
+        let (r_min, r_max) = plot.distance_range;
+        let curve = sample_curve(&lj.potential(), r_min, r_max, &CurveConfig::default());
+        editor.press(cursor_pos, &plot, &curve);
+    } else if mouse_button.pressed(MouseButton::Left) && editor.grabbed {
+        // Only touch the resource when something changed, so change detection stays meaningful
+        let mut edited = lj.clone();
+        if editor.drag(cursor_pos, &plot, &mut edited) {
+            *lj = edited;
+        }
+    }
+}
+
+fn handle_field_controls(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    render_config: Res<ProtonRenderConfig>,
+    mut external: ResMut<ExternalField>,
+    mut medium: ResMut<Medium>,
+    mut coloring: ResMut<PhaseColoring>,
+) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        coloring.enabled = !coloring.enabled;
+    }
+    if keys.just_pressed(KeyCode::KeyE) {
+        external.toggle(UNIFORM_FIELD);
+    }
+    if keys.just_pressed(KeyCode::KeyT) {
+        external.toggle(CENTRE_TRAP);
+    }
+    if keys.just_pressed(KeyCode::KeyC) {
+        if let Some(grid) = external.paintable_mut() {
+            grid.clear();
         }
     }
+    if keys.just_pressed(KeyCode::KeyW) {
+        // Cycle vacuum → water → saline (0.15 M, physiological) → vacuum
+        *medium = if medium.relative_permittivity == 1.0 {
+            Medium::water()
+        } else if medium.ionic_strength == 0.0 {
+            Medium::saline(0.15)
+        } else {
+            Medium::vacuum()
+        };
+    }
+
+    if !mouse_button.pressed(MouseButton::Right) {
+        return;
+    }
+
+    let window = windows.single();
+    let (camera, camera_transform) = cameras.single();
+    let Some(cursor_pos) = window.cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+
+    // A few hundredths of an eV per frame builds up a visible hill in about a second
+    let dig = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let amount = if dig { -0.05 } else { 0.05 } * ELEMENTARY_CHARGE;
+    if let Some(grid) = external.paintable_mut() {
+        grid.paint(screen_to_physics(cursor_pos, &render_config), 0.3e-10, amount);
+    }
 }
 
 fn apply_spring_force(
     touch_input: Res<TouchInput>,
     spring_config: Res<SpringConfig>,
+    sim_time: Res<SimulationTime>,
+    mut meter: ResMut<IonizationMeter>,
+    mut monitor: ResMut<ConservationMonitor>,
     mut protons: Query<(Entity, &mut PhysicsProton)>,
+    mut electrons: Query<(Entity, &mut PhysicsElectron)>,
 ) {
     if !touch_input.active {
         return;
@@ -170,53 +473,134 @@ fn apply_spring_force(
                     &spring_config,
                 );
                 proton.0.apply_force(force);
+                monitor.inject(force.dot(proton.0.velocity) * sim_time.dt);
+            }
+        }
+
+        for (entity, mut electron) in electrons.iter_mut() {
+            if entity == selected {
+                let force = spring_force(
+                    electron.0.position,
+                    electron.0.velocity,
+                    touch_input.position,
+                    &spring_config,
+                );
+                electron.0.apply_force(force);
+                monitor.inject(force.dot(electron.0.velocity) * sim_time.dt);
+
+                if meter.electron == Some(entity) {
+                    meter.spring_applied(force, electron.0.position);
+                }
             }
         }
     }
 }
 
 fn apply_coulomb_forces(
+    medium: Res<Medium>,
     mut protons: Query<&mut PhysicsProton>,
-    mut electrons: Query<&mut PhysicsElectron>,
+    mut electrons: Query<(Entity, &mut PhysicsElectron, &ProbabilityCloud)>,
 ) {
     // Get all positions first to avoid borrow issues
     let proton_data: Vec<_> = protons.iter()
         .map(|p| (p.0.position, Proton::charge()))
         .collect();
 
+    // Electrons are Gaussian charge clouds whose width follows their orbital
     let electron_data: Vec<_> = electrons.iter()
-        .map(|e| (e.0.position, Electron::charge()))
+        .map(|(entity, e, cloud)| (entity, e.0.position, Electron::charge(), cloud.gaussian_width()))
         .collect();
 
-    // Apply forces from electrons to protons
+    // Apply forces from electrons to protons (point nucleus vs. Gaussian cloud)
     for mut proton in protons.iter_mut() {
-        for (e_pos, e_charge) in &electron_data {
-            let force = coulomb_force(
+        for (_, e_pos, e_charge, e_width) in &electron_data {
+            let force = medium.force(
                 Proton::charge(),
                 *e_charge,
                 proton.0.position,
                 *e_pos,
+                0.0,
+                *e_width,
             );
             proton.0.apply_force(force);
         }
     }
 
-    // Apply forces from protons to electrons
-    for mut electron in electrons.iter_mut() {
+    // Apply forces from protons and other electrons to electrons
+    for (entity, mut electron, cloud) in electrons.iter_mut() {
+        let width = cloud.gaussian_width();
+
         for (p_pos, p_charge) in &proton_data {
-            let force = coulomb_force(
+            let force = medium.force(
                 Electron::charge(),
                 *p_charge,
                 electron.0.position,
                 *p_pos,
+                width,
+                0.0,
+            );
+            electron.0.apply_force(force);
+        }
+
+        for (other, e_pos, e_charge, e_width) in &electron_data {
+            if *other == entity {
+                continue;
+            }
+            let force = medium.force(
+                Electron::charge(),
+                *e_charge,
+                electron.0.position,
+                *e_pos,
+                width,
+                *e_width,
             );
             electron.0.apply_force(force);
         }
     }
 }
 
+fn apply_lennard_jones_forces(
+    lj: Res<LennardJones>,
+    mut protons: Query<&mut PhysicsProton>,
+    mut electrons: Query<&mut PhysicsElectron>,
+) {
+    // Every particle feels every other one through the same tunable pair potential
+    let positions: Vec<DVec3> = protons.iter().map(|p| p.0.position)
+        .chain(electrons.iter().map(|e| e.0.position))
+        .collect();
+    let net_force = |own: DVec3| -> DVec3 {
+        positions.iter().map(|other| lj.force(own, *other)).sum()
+    };
+
+    for mut proton in protons.iter_mut() {
+        let force = net_force(proton.0.position);
+        proton.0.apply_force(force);
+    }
+    for mut electron in electrons.iter_mut() {
+        let force = net_force(electron.0.position);
+        electron.0.apply_force(force);
+    }
+}
+
+fn apply_external_fields(
+    external: Res<ExternalField>,
+    mut protons: Query<&mut PhysicsProton>,
+    mut electrons: Query<&mut PhysicsElectron>,
+) {
+    for mut proton in protons.iter_mut() {
+        let force = external.force(proton.0.position, Proton::charge());
+        proton.0.apply_force(force);
+    }
+
+    for mut electron in electrons.iter_mut() {
+        let force = external.force(electron.0.position, Electron::charge());
+        electron.0.apply_force(force);
+    }
+}
+
 fn physics_step(
     sim_time: Res<SimulationTime>,
+    magnetic: Res<MagneticField>,
     mut protons: Query<&mut PhysicsProton>,
     mut electrons: Query<&mut PhysicsElectron>,
 ) {
@@ -227,26 +611,258 @@ fn physics_step(
     let sub_dt = dt / substeps as f64;
 
     for _ in 0..substeps {
-        // Update protons
+        // Update protons (Boris where a magnetic field makes the force velocity-dependent)
         for mut proton in protons.iter_mut() {
-            let old_accel = verlet_position_step(&mut proton.0, sub_dt);
-            verlet_velocity_step(&mut proton.0, old_accel, sub_dt);
+            let b = magnetic.field_at(proton.0.position);
+            if b == DVec3::ZERO {
+                let old_accel = verlet_position_step(&mut proton.0, sub_dt);
+                verlet_velocity_step(&mut proton.0, old_accel, sub_dt);
+            } else {
+                boris_step(&mut proton.0, Proton::charge(), b, sub_dt);
+            }
             proton.0.clear_forces();
         }
 
         // Update electrons
         for mut electron in electrons.iter_mut() {
-            let old_accel = verlet_position_step(&mut electron.0, sub_dt);
-            verlet_velocity_step(&mut electron.0, old_accel, sub_dt);
+            let b = magnetic.field_at(electron.0.position);
+            if b == DVec3::ZERO {
+                let old_accel = verlet_position_step(&mut electron.0, sub_dt);
+                verlet_velocity_step(&mut electron.0, old_accel, sub_dt);
+            } else {
+                boris_step(&mut electron.0, Electron::charge(), b, sub_dt);
+            }
             electron.0.clear_forces();
         }
     }
 }
 
+fn measure_spring_work(
+    mut meter: ResMut<IonizationMeter>,
+    electrons: Query<&PhysicsElectron>,
+) {
+    let Some(entity) = meter.electron else {
+        return;
+    };
+
+    if let Ok(electron) = electrons.get(entity) {
+        meter.electron_moved(electron.0.position);
+    }
+}
+
+fn detect_ionization(
+    protons: Query<(Entity, &PhysicsProton)>,
+    mut charges: Query<(Entity, &mut NetCharge)>,
+    mut electrons: Query<(Entity, &PhysicsElectron, &mut Binding)>,
+    mut meter: ResMut<IonizationMeter>,
+    mut ionizations: EventWriter<Ionized>,
+) {
+    let nuclei = hydrogen_nuclei(&protons);
+
+    for (entity, electron, mut binding) in electrons.iter_mut() {
+        let Some(event) = update_binding(entity, &electron.0, &mut binding, &nuclei) else {
+            continue;
+        };
+
+        if meter.electron == Some(entity) {
+            if let Some(reading) = meter.finish() {
+                info!("{}", reading.summary());
+            }
+        }
+        ionizations.send(event);
+    }
+
+    // Recount the electrons held by each nucleus
+    let bindings: Vec<_> = electrons.iter().map(|(_, _, b)| b.clone()).collect();
+    for (entity, mut charge) in charges.iter_mut() {
+        charge.bound_electrons = count_bound(entity, &bindings);
+    }
+}
+
+fn perceive_bonds(
+    atoms: Query<(Entity, &PhysicsProton, &NetCharge)>,
+    perception: Res<BondPerception>,
+    mut graph: ResMut<BondGraph>,
+    mut formed: EventWriter<BondFormed>,
+    mut broken: EventWriter<BondBroken>,
+) {
+    let atoms: Vec<PerceivedAtom> = atoms
+        .iter()
+        .map(|(entity, proton, charge)| PerceivedAtom { entity, z: charge.z, position: proton.0.position })
+        .collect();
+
+    let changes = graph.update(&atoms, &perception);
+    if changes.formed.is_empty() && changes.broken.is_empty() {
+        return;
+    }
+
+    let formulas: Vec<String> = graph.molecules(&atoms).into_iter().map(|m| m.formula).collect();
+    info!("Molecules: {}", formulas.join(" + "));
+    formed.send_batch(changes.formed);
+    broken.send_batch(changes.broken);
+}
+
+fn classify_phase(
+    classifier: Res<PhaseClassifier>,
+    coloring: Res<PhaseColoring>,
+    render_config: Res<ProtonRenderConfig>,
+    mut nuclei: Query<(&PhysicsProton, &mut Sprite)>,
+    mut indicators: Query<&mut Text, With<PhaseIndicator>>,
+) {
+    let positions: Vec<DVec3> = nuclei.iter().map(|(proton, _)| proton.0.position).collect();
+    let report = classifier.classify(&positions);
+
+    for ((_, mut sprite), label) in nuclei.iter_mut().zip(&report.labels) {
+        sprite.color = if coloring.enabled { coloring.color(*label) } else { render_config.color };
+    }
+
+    let summary = match report.phase() {
+        Some(phase) => format!(
+            "Phase: {}  (solid {:.0}%, liquid {:.0}%, gas {:.0}%)",
+            phase.name(),
+            100.0 * report.fraction(Phase::Solid),
+            100.0 * report.fraction(Phase::Liquid),
+            100.0 * report.fraction(Phase::Gas),
+        ),
+        None => String::from("Phase: -"),
+    };
+    for mut text in indicators.iter_mut() {
+        text.0 = summary.clone();
+    }
+}
+
+fn track_diffusion(
+    sim_time: Res<SimulationTime>,
+    mut tracker: ResMut<MsdTracker>,
+    protons: Query<&PhysicsProton>,
+    electrons: Query<&PhysicsElectron>,
+) {
+    let positions: Vec<DVec3> = protons.iter().map(|p| p.0.position)
+        .chain(electrons.iter().map(|e| e.0.position))
+        .collect();
+    tracker.record(&positions, sim_time.dt);
+}
+
+fn bohr_snap(
+    time: Res<Time>,
+    config: Res<BohrSnapConfig>,
+    touch_input: Res<TouchInput>,
+    protons: Query<(Entity, &PhysicsProton)>,
+    mut electrons: Query<(Entity, &mut PhysicsElectron, &mut ProbabilityCloud, &mut BohrState)>,
+    mut emissions: EventWriter<PhotonEmitted>,
+    mut monitor: ResMut<ConservationMonitor>,
+) {
+    if !config.enabled {
+        // Classical mode: the cloud simply follows the electron
+        for (_, electron, mut cloud, _) in electrons.iter_mut() {
+            cloud.center = electron.0.position;
+        }
+        return;
+    }
+
+    let nuclei = hydrogen_nuclei(&protons);
+
+    for (entity, mut electron, mut cloud, mut state) in electrons.iter_mut() {
+        // An electron held by the spring is free to be pulled off its level
+        if touch_input.selected_entity == Some(entity) {
+            cloud.center = electron.0.position;
+            continue;
+        }
+
+        let outcome = snap_electron(
+            &mut electron.0,
+            &mut cloud,
+            &mut state,
+            &nuclei,
+            &config,
+            time.delta_secs(),
+        );
+
+        let Some(outcome) = outcome else {
+            // Unbound: nothing to snap to, the cloud travels with the electron
+            cloud.center = electron.0.position;
+            continue;
+        };
+
+        // Snapping to a level hands the difference to (or takes it from) the light field
+        monitor.inject(-outcome.released_energy);
+
+        if outcome.released_energy > config.min_emission_energy {
+            // Photon leaves radially outward from the nucleus
+            let direction = (electron.0.position - cloud.center).try_normalize().unwrap_or(DVec3::X);
+            emissions.send(PhotonEmitted {
+                emitter: entity,
+                photon: Photon::new(electron.0.position, direction, outcome.released_energy),
+                upper: outcome.previous_level,
+                lower: outcome.level,
+            });
+        }
+    }
+}
+
+fn monitor_conservation(
+    sim_time: Res<SimulationTime>,
+    medium: Res<Medium>,
+    lj: Res<LennardJones>,
+    external: Res<ExternalField>,
+    touch_input: Res<TouchInput>,
+    mut monitor: ResMut<ConservationMonitor>,
+    mut warnings: EventWriter<ConservationWarning>,
+    protons: Query<&PhysicsProton>,
+    electrons: Query<(&PhysicsElectron, &ProbabilityCloud)>,
+) {
+    // Editing the interactions changes what "the same energy" means
+    if medium.is_changed() || lj.is_changed() || external.is_changed() {
+        monitor.reset();
+    }
+
+    // (mass, charge, Gaussian width, position, velocity) of every particle
+    let particles: Vec<(f64, f64, f64, DVec3, DVec3)> = protons.iter()
+        .map(|p| (Proton::mass(), Proton::charge(), 0.0, p.0.position, p.0.velocity))
+        .chain(electrons.iter().map(|(e, cloud)| {
+            (Electron::mass(), Electron::charge(), cloud.gaussian_width(), e.0.position, e.0.velocity)
+        }))
+        .collect();
+
+    // Fields, traps and the drag spring push without pushing back: momentum is not conserved
+    let driven = touch_input.active
+        || particles.iter().any(|(_, q, _, r, _)| external.force(*r, *q) != DVec3::ZERO);
+    if driven {
+        monitor.thresholds.momentum = None;
+        monitor.thresholds.angular_momentum = None;
+    } else if monitor.thresholds.momentum.is_none() {
+        // Isolated again: measure drift from here
+        monitor.reset();
+        monitor.thresholds = ConservationThresholds::default();
+    }
+
+    let mut potential = 0.0;
+    for (i, (_, q1, w1, r1, _)) in particles.iter().enumerate() {
+        potential += external.potential_energy(*r1, *q1);
+        for (_, q2, w2, r2, _) in &particles[i + 1..] {
+            let distance = r1.distance(*r2);
+            potential += medium.potential_energy(*q1, *q2, distance, *w1, *w2) + lj.potential().energy(distance);
+        }
+    }
+
+    let time = monitor.latest().map_or(0.0, |s| s.time) + sim_time.dt;
+    let snapshot = ConservationSnapshot::from_motion(time, particles.iter().map(|(m, _, _, r, v)| (*m, *r, *v)))
+        .with_potential(potential);
+    for warning in monitor.record(snapshot) {
+        warn!(
+            "{} drifted by {:.1}% at t = {:.1} fs",
+            warning.quantity.name(),
+            100.0 * warning.relative_drift,
+            warning.time * 1.0e15,
+        );
+        warnings.send(warning);
+    }
+}
+
 fn sync_visuals(
     render_config: Res<ProtonRenderConfig>,
     mut protons: Query<(&PhysicsProton, &mut Transform), Without<PhysicsElectron>>,
-    mut electrons: Query<(&PhysicsElectron, &mut Transform), Without<PhysicsProton>>,
+    mut electrons: Query<(&ProbabilityCloud, &mut Transform), Without<PhysicsProton>>,
 ) {
     for (proton, mut transform) in protons.iter_mut() {
         let screen_pos = physics_to_screen(proton.0.position, &render_config);
@@ -254,8 +870,9 @@ fn sync_visuals(
         transform.translation.y = screen_pos.y;
     }
 
-    for (electron, mut transform) in electrons.iter_mut() {
-        let screen_pos = physics_to_screen(electron.0.position, &render_config);
+    // The cloud is drawn where the probability is centred, not at the mean electron position
+    for (cloud, mut transform) in electrons.iter_mut() {
+        let screen_pos = physics_to_screen(cloud.center, &render_config);
         transform.translation.x = screen_pos.x;
         transform.translation.y = screen_pos.y;
     }
@@ -263,17 +880,128 @@ fn sync_visuals(
 
 fn update_electron_cloud_shimmer(
     time: Res<Time>,
-    mut clouds: Query<(&mut ElectronCloudVisual, &mut Sprite)>,
+    mut clouds: Query<(&mut ElectronCloudVisual, &mut Sprite, &ProbabilityCloud)>,
 ) {
-    for (mut cloud, mut sprite) in clouds.iter_mut() {
+    for (mut cloud, mut sprite, probability) in clouds.iter_mut() {
         cloud.update_shimmer(time.delta_secs());
 
-        // Pulse the size slightly
+        // Pulse the size slightly; excited levels spread out as n²
         let scale = cloud.shimmer_scale();
-        sprite.custom_size = Some(Vec2::splat(200.0 * scale));
+        let n = probability.orbital.principal() as f32;
+        sprite.custom_size = Some(Vec2::splat(200.0 * scale * n * n));
 
         // Subtle color shift based on phase
         let hue_shift = 0.05 * cloud.shimmer_phase.sin();
         sprite.color = Color::srgba(0.3 + hue_shift, 0.5, 1.0 - hue_shift, 0.4);
     }
 }
+
+fn update_ionization_readout(
+    meter: Res<IonizationMeter>,
+    charges: Query<&NetCharge>,
+    mut readouts: Query<&mut Text, With<IonizationReadout>>,
+) {
+    let ions: Vec<_> = charges.iter().map(NetCharge::label).collect();
+
+    let status = if meter.is_measuring() {
+        format!("Spring work: {:.1} eV", meter.work_ev())
+    } else if let Some(reading) = meter.reading {
+        reading.summary()
+    } else {
+        String::new()
+    };
+
+    for mut text in readouts.iter_mut() {
+        text.0 = format!("{}\n{}", ions.join(" "), status);
+    }
+}
+
+fn draw_potential_curve(
+    mut gizmos: Gizmos,
+    lj: Res<LennardJones>,
+    plot: Res<CurvePlot>,
+    editor: Res<CurveEditor>,
+    protons: Query<&PhysicsProton>,
+    electrons: Query<&PhysicsElectron>,
+    mut readouts: Query<&mut Text, With<CurveReadout>>,
+) {
+    let (r_min, r_max) = plot.distance_range;
+    let potential = lj.potential();
+    let curve = sample_curve(&potential, r_min, r_max, &CurveConfig::default());
+
+    // Frame and the V = 0 axis
+    gizmos.rect_2d(plot.origin + plot.size / 2.0, plot.size, Color::srgba(0.5, 0.5, 0.6, 0.6));
+    gizmos.line_2d(plot.to_screen(r_min, 0.0), plot.to_screen(r_max, 0.0), Color::srgba(0.5, 0.5, 0.6, 0.4));
+    gizmos.linestrip_2d(plot.polyline(&curve), Color::srgb(0.6, 1.0, 0.7));
+
+    // The draggable well handle
+    if let Some(minimum) = curve.minimum() {
+        let color = if editor.grabbed { Color::srgb(1.0, 1.0, 0.4) } else { Color::srgb(0.6, 1.0, 0.7) };
+        gizmos.circle_2d(plot.to_screen(minimum.distance, minimum.energy), editor.grab_radius * 0.6, color);
+    }
+
+    // The live proton–electron pair rides along the curve
+    if let (Some(proton), Some(electron)) = (protons.iter().next(), electrons.iter().next()) {
+        let separation = proton.0.position.distance(electron.0.position);
+        if (r_min..=r_max).contains(&separation) {
+            let dot = plot.to_screen(separation, potential.energy(separation));
+            gizmos.circle_2d(dot, 3.0, Color::srgb(1.0, 0.4, 0.2));
+        }
+    }
+
+    for mut text in readouts.iter_mut() {
+        text.0 = format!(
+            "Lennard-Jones  σ = {:.2} Å  ε = {:.2} eV",
+            lj.sigma * 1.0e10,
+            lj.epsilon / ELEMENTARY_CHARGE,
+        );
+    }
+}
+
+/// Lower-left corner and size of the MSD plot, in pixels
+const MSD_PLOT_ORIGIN: Vec2 = Vec2::new(150.0, 150.0);
+const MSD_PLOT_SIZE: Vec2 = Vec2::new(230.0, 100.0);
+
+fn draw_msd_plot(
+    mut gizmos: Gizmos,
+    tracker: Res<MsdTracker>,
+    mut readouts: Query<&mut Text, With<DiffusionReadout>>,
+) {
+    let samples = tracker.samples();
+    gizmos.rect_2d(MSD_PLOT_ORIGIN + MSD_PLOT_SIZE / 2.0, MSD_PLOT_SIZE, Color::srgba(0.5, 0.5, 0.6, 0.6));
+
+    // Autoscale both axes to the run so far
+    let max_time = tracker.elapsed().max(f64::MIN_POSITIVE);
+    let max_msd = samples.iter().map(|s| s.msd).fold(f64::MIN_POSITIVE, f64::max);
+    let points = samples.iter().map(|s| {
+        MSD_PLOT_ORIGIN + MSD_PLOT_SIZE * Vec2::new((s.time / max_time) as f32, (s.msd / max_msd) as f32)
+    });
+    gizmos.linestrip_2d(points, Color::srgb(0.7, 0.8, 1.0));
+
+    // The scene is planar: MSD = 4 D t
+    let estimate = match tracker.diffusion_coefficient(2, 0.5) {
+        Some(d) => format!("D ≈ {:.2e} m²/s", d),
+        None => String::from("D: -"),
+    };
+    for mut text in readouts.iter_mut() {
+        text.0 = format!("MSD {:.2} Å² over {:.0} fs   {}", tracker.msd() * 1.0e20, tracker.elapsed() * 1.0e15, estimate);
+    }
+}
+
+fn update_debug_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    monitor: Res<ConservationMonitor>,
+    mut overlays: Query<(&mut Text, &mut Visibility), With<DebugOverlay>>,
+) {
+    for (mut text, mut visibility) in overlays.iter_mut() {
+        if keys.just_pressed(KeyCode::F3) {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
+        }
+        if *visibility != Visibility::Hidden {
+            text.0 = monitor.overlay_lines().join("\n");
+        }
+    }
+}
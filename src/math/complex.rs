@@ -0,0 +1,104 @@
+// Minimal complex arithmetic for wavefunctions and FFTs
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+
+/// A complex number re + i·im.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// e^(iθ) = cos θ + i sin θ
+    pub fn cis(theta: f64) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self { re: cos, im: sin }
+    }
+
+    /// Complex conjugate
+    pub fn conj(self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// |z|²
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Complex) {
+        self.re += rhs.re;
+        self.im += rhs.im;
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Complex) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+    fn mul(self, rhs: f64) -> Complex {
+        Complex::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn multiplication_follows_i_squared() {
+        let i = Complex::new(0.0, 1.0);
+        assert_eq!(i * i, Complex::new(-1.0, 0.0));
+
+        let z = Complex::new(3.0, 4.0);
+        assert_relative_eq!((z * z.conj()).re, 25.0, epsilon = 1e-12);
+        assert_relative_eq!(z.norm_sqr(), 25.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn cis_lies_on_unit_circle() {
+        let z = Complex::cis(0.7) * Complex::cis(0.5);
+        let expected = Complex::cis(1.2);
+        assert_relative_eq!(z.re, expected.re, epsilon = 1e-12);
+        assert_relative_eq!(z.im, expected.im, epsilon = 1e-12);
+        assert_relative_eq!(z.norm_sqr(), 1.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,192 @@
+// Fast Fourier transform (radix-2 Cooley–Tukey)
+// Forward: X[k] = Σ x[n] e^(-2πikn/N); inverse includes the 1/N factor
+
+use std::f64::consts::PI;
+use super::complex::Complex;
+
+/// In-place forward FFT. Length must be a power of two.
+pub fn fft(data: &mut [Complex]) {
+    transform(data, false);
+}
+
+/// In-place inverse FFT, normalized so `ifft(fft(x)) == x`.
+pub fn ifft(data: &mut [Complex]) {
+    transform(data, true);
+    let scale = 1.0 / data.len() as f64;
+    for value in data.iter_mut() {
+        *value = *value * scale;
+    }
+}
+
+/// In-place forward 2D FFT of a row-major `nx × ny` array.
+pub fn fft_2d(data: &mut [Complex], nx: usize, ny: usize) {
+    transform_2d(data, nx, ny, false);
+}
+
+/// In-place inverse 2D FFT of a row-major `nx × ny` array.
+pub fn ifft_2d(data: &mut [Complex], nx: usize, ny: usize) {
+    transform_2d(data, nx, ny, true);
+    let scale = 1.0 / data.len() as f64;
+    for value in data.iter_mut() {
+        *value = *value * scale;
+    }
+}
+
+/// Angular wavenumbers matching FFT bin order for `n` points spaced `dx`:
+/// 0, 1, ..., n/2 - 1, -n/2, ..., -1 times 2π/(n·dx).
+pub fn wavenumbers(n: usize, dx: f64) -> Vec<f64> {
+    let dk = 2.0 * PI / (n as f64 * dx);
+    (0..n)
+        .map(|i| if i < n / 2 { i as f64 } else { i as f64 - n as f64 } * dk)
+        .collect()
+}
+
+fn transform_2d(data: &mut [Complex], nx: usize, ny: usize, inverse: bool) {
+    assert_eq!(data.len(), nx * ny, "2D FFT data must hold nx × ny values");
+
+    // Rows are contiguous
+    for row in data.chunks_mut(nx) {
+        transform(row, inverse);
+    }
+
+    // Columns go through a scratch buffer
+    let mut column = vec![Complex::ZERO; ny];
+    for i in 0..nx {
+        for (j, value) in column.iter_mut().enumerate() {
+            *value = data[j * nx + i];
+        }
+        transform(&mut column, inverse);
+        for (j, value) in column.iter().enumerate() {
+            data[j * nx + i] = *value;
+        }
+    }
+}
+
+fn transform(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    assert!(n.is_power_of_two(), "FFT length must be a power of two, got {}", n);
+    if n == 1 {
+        return;
+    }
+
+    // Bit-reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            data.swap(i, j);
+        }
+    }
+
+    // Twiddles for the largest stage; smaller stages stride through them
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let twiddles: Vec<Complex> = (0..n / 2)
+        .map(|k| Complex::cis(sign * 2.0 * PI * k as f64 / n as f64))
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let even = data[start + k];
+                let odd = data[start + k + half] * twiddles[k * stride];
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+        }
+        len *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input.iter().enumerate().fold(Complex::ZERO, |sum, (j, x)| {
+                    sum + *x * Complex::cis(-2.0 * PI * (j * k) as f64 / n as f64)
+                })
+            })
+            .collect()
+    }
+
+    fn sample_signal(n: usize) -> Vec<Complex> {
+        (0..n)
+            .map(|i| Complex::new((0.3 * i as f64).sin() + 0.1 * i as f64, (1.7 * i as f64).cos()))
+            .collect()
+    }
+
+    #[test]
+    fn matches_naive_dft() {
+        let input = sample_signal(32);
+        let expected = naive_dft(&input);
+        let mut output = input.clone();
+        fft(&mut output);
+
+        for (a, b) in output.iter().zip(&expected) {
+            assert_relative_eq!(a.re, b.re, epsilon = 1e-10);
+            assert_relative_eq!(a.im, b.im, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let input = sample_signal(256);
+        let mut data = input.clone();
+        fft(&mut data);
+        ifft(&mut data);
+
+        for (a, b) in data.iter().zip(&input) {
+            assert_relative_eq!(a.re, b.re, epsilon = 1e-12);
+            assert_relative_eq!(a.im, b.im, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn parseval_theorem() {
+        // Σ|x|² = (1/N) Σ|X|²
+        let input = sample_signal(128);
+        let mut spectrum = input.clone();
+        fft(&mut spectrum);
+
+        let time_energy: f64 = input.iter().map(|z| z.norm_sqr()).sum();
+        let freq_energy: f64 = spectrum.iter().map(|z| z.norm_sqr()).sum::<f64>() / 128.0;
+        assert_relative_eq!(time_energy, freq_energy, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn plane_wave_lands_in_one_bin() {
+        // e^(2πi·3j/N) transforms to N at bin 3 in both directions of a 2D grid
+        let (nx, ny) = (16, 8);
+        let mut data: Vec<Complex> = (0..nx * ny)
+            .map(|idx| {
+                let (i, j) = (idx % nx, idx / nx);
+                Complex::cis(2.0 * PI * (3.0 * i as f64 / nx as f64 + 2.0 * j as f64 / ny as f64))
+            })
+            .collect();
+        fft_2d(&mut data, nx, ny);
+
+        let peak = 2 * nx + 3;
+        assert_relative_eq!(data[peak].re, (nx * ny) as f64, epsilon = 1e-9);
+        let leakage: f64 = data.iter().enumerate().filter(|(i, _)| *i != peak).map(|(_, z)| z.norm_sqr()).sum();
+        assert!(leakage < 1e-15);
+
+        ifft_2d(&mut data, nx, ny);
+        assert_relative_eq!(data[5].norm_sqr(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn wavenumber_ordering() {
+        let k = wavenumbers(8, 0.5);
+        let dk = 2.0 * PI / 4.0;
+        assert_relative_eq!(k[1], dk, epsilon = 1e-12);
+        assert_relative_eq!(k[4], -4.0 * dk, epsilon = 1e-12);
+        assert_relative_eq!(k[7], -dk, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,391 @@
+// Small dense linear algebra
+// Symmetric eigenproblems via cyclic Jacobi rotations
+// Symmetric tridiagonal eigenproblems via Sturm bisection and inverse iteration
+// General linear systems via Gaussian elimination with partial pivoting
+
+/// Eigen-decomposition of a real symmetric matrix.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen {
+    /// Eigenvalues in ascending order
+    pub values: Vec<f64>,
+    /// Normalized eigenvectors; `vectors[k]` belongs to `values[k]`
+    pub vectors: Vec<Vec<f64>>,
+}
+
+/// Diagonalize a real symmetric matrix with the cyclic Jacobi method.
+///
+/// Each rotation zeroes one off-diagonal element; sweeps repeat until the
+/// off-diagonal part is negligible. Robust and exact to machine precision,
+/// which suits the small matrices of Hückel and similar models (n ≲ 100).
+///
+/// # Arguments
+/// * `matrix` - Square symmetric matrix as rows
+pub fn symmetric_eigen(matrix: &[Vec<f64>]) -> SymmetricEigen {
+    let n = matrix.len();
+    assert!(matrix.iter().all(|row| row.len() == n), "Matrix must be square");
+
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    // Accumulated rotations; columns become the eigenvectors
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    let scale: f64 = a.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+
+    for _sweep in 0..100 {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum::<f64>()
+            .sqrt();
+        if off_diagonal <= 1e-15 * scale || scale == 0.0 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q].abs() <= 1e-300 {
+                    continue;
+                }
+
+                // Rotation angle that annihilates a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let t = if theta == 0.0 { 1.0 } else { t };
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // A ← Jᵀ A J: rotate columns p, q then rows p, q
+                for row in a.iter_mut() {
+                    (row[p], row[q]) = rotate(row[p], row[q], c, s);
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    (*apk, *aqk) = rotate(*apk, *aqk, c, s);
+                }
+                for row in v.iter_mut() {
+                    (row[p], row[q]) = rotate(row[p], row[q], c, s);
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+
+    SymmetricEigen {
+        values: order.iter().map(|&k| a[k][k]).collect(),
+        vectors: order.iter().map(|&k| (0..n).map(|i| v[i][k]).collect()).collect(),
+    }
+}
+
+/// Lowest eigenpairs of a symmetric tridiagonal matrix.
+///
+/// Eigenvalues are bracketed by Sturm-sequence bisection, then each
+/// eigenvector is found by inverse iteration. Cost is O(n) per eigenvalue
+/// per bisection step, so large finite-difference grids stay cheap.
+///
+/// # Arguments
+/// * `diagonal` - Main diagonal (length n)
+/// * `off_diagonal` - Sub/super diagonal (length n - 1)
+/// * `count` - Number of lowest eigenpairs to return
+pub fn tridiagonal_lowest_eigen(diagonal: &[f64], off_diagonal: &[f64], count: usize) -> SymmetricEigen {
+    let n = diagonal.len();
+    assert!(n > 0 && off_diagonal.len() + 1 == n, "Off-diagonal must have length n - 1");
+    assert!(count <= n, "Cannot ask for more eigenpairs than the matrix size");
+
+    // Gershgorin bounds enclose the whole spectrum
+    let mut lower = f64::INFINITY;
+    let mut upper = f64::NEG_INFINITY;
+    for i in 0..n {
+        let radius = if i > 0 { off_diagonal[i - 1].abs() } else { 0.0 }
+            + if i + 1 < n { off_diagonal[i].abs() } else { 0.0 };
+        lower = lower.min(diagonal[i] - radius);
+        upper = upper.max(diagonal[i] + radius);
+    }
+    let tolerance = 1e-15 * lower.abs().max(upper.abs()).max(f64::MIN_POSITIVE);
+
+    let mut values: Vec<f64> = Vec::with_capacity(count);
+    let mut vectors: Vec<Vec<f64>> = Vec::with_capacity(count);
+
+    for k in 0..count {
+        // Bisect for the (k+1)-th smallest eigenvalue
+        let (mut lo, mut hi) = (lower, upper);
+        while hi - lo > tolerance {
+            let mid = 0.5 * (lo + hi);
+            if mid == lo || mid == hi {
+                break;
+            }
+            if sturm_count(diagonal, off_diagonal, mid) > k {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        let value = 0.5 * (lo + hi);
+
+        let mut vector = inverse_iteration(diagonal, off_diagonal, value, k);
+        // Keep nearly degenerate partners orthogonal
+        for (previous, &previous_value) in vectors.iter().zip(&values) {
+            if (value - previous_value).abs() < 1e-8 * (upper - lower) {
+                let overlap: f64 = vector.iter().zip(previous).map(|(a, b)| a * b).sum();
+                for (x, p) in vector.iter_mut().zip(previous) {
+                    *x -= overlap * p;
+                }
+                normalize(&mut vector);
+            }
+        }
+
+        values.push(value);
+        vectors.push(vector);
+    }
+
+    SymmetricEigen { values, vectors }
+}
+
+/// Solve the dense linear system A x = b by Gaussian elimination with
+/// partial pivoting.
+///
+/// # Arguments
+/// * `matrix` - Square matrix A as rows
+/// * `rhs` - Right-hand side b
+///
+/// # Returns
+/// `None` if the matrix is singular to working precision.
+pub fn solve_linear(matrix: &[Vec<f64>], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n = matrix.len();
+    assert!(matrix.iter().all(|row| row.len() == n), "Matrix must be square");
+    assert_eq!(rhs.len(), n, "Right-hand side must match the matrix size");
+
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut b = rhs.to_vec();
+    let scale = a.iter().flatten().fold(0.0_f64, |m, x| m.max(x.abs()));
+
+    for column in 0..n {
+        // Largest remaining entry in this column becomes the pivot
+        let pivot = (column..n).max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))?;
+        if a[pivot][column].abs() <= 1e-14 * scale {
+            return None;
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+
+        let (upper, lower) = a.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            if factor == 0.0 {
+                continue;
+            }
+            for (x, p) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *x -= factor * p;
+            }
+            b[column + 1 + offset] -= factor * b[column];
+        }
+    }
+
+    // Back substitution
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let known: f64 = a[i][i + 1..].iter().zip(&x[i + 1..]).map(|(a, x)| a * x).sum();
+        x[i] = (b[i] - known) / a[i][i];
+    }
+    Some(x)
+}
+
+/// Number of eigenvalues strictly below `shift` (Sturm sequence sign count).
+fn sturm_count(diagonal: &[f64], off_diagonal: &[f64], shift: f64) -> usize {
+    let mut count = 0;
+    let mut q = 1.0;
+    for i in 0..diagonal.len() {
+        let coupling = if i > 0 { off_diagonal[i - 1] * off_diagonal[i - 1] / q } else { 0.0 };
+        q = diagonal[i] - shift - coupling;
+        if q == 0.0 {
+            q = -f64::EPSILON * (diagonal[i].abs() + shift.abs()).max(f64::MIN_POSITIVE);
+        }
+        if q < 0.0 {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Eigenvector for a known eigenvalue by repeated solves of (T - λI)y = x.
+fn inverse_iteration(diagonal: &[f64], off_diagonal: &[f64], value: f64, seed: usize) -> Vec<f64> {
+    let n = diagonal.len();
+    // Nudge the shift so the system is not exactly singular
+    let shift = value + 1e-12 * (value.abs() + off_diagonal.iter().fold(0.0, |m: f64, e| m.max(e.abs())));
+
+    // Deterministic, non-symmetric start so no eigenvector is missed by parity
+    let mut x: Vec<f64> = (0..n)
+        .map(|i| 1.0 + 0.37 * (((i + seed) * 7919 % 1013) as f64 / 1013.0))
+        .collect();
+    normalize(&mut x);
+
+    for _ in 0..4 {
+        x = solve_tridiagonal(diagonal, off_diagonal, shift, &x);
+        normalize(&mut x);
+    }
+    x
+}
+
+/// Solve (T - shift·I) y = rhs with the Thomas algorithm.
+fn solve_tridiagonal(diagonal: &[f64], off_diagonal: &[f64], shift: f64, rhs: &[f64]) -> Vec<f64> {
+    let n = diagonal.len();
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    let tiny = f64::MIN_POSITIVE.sqrt();
+
+    let mut pivot = diagonal[0] - shift;
+    for i in 0..n {
+        if i > 0 {
+            pivot = diagonal[i] - shift - off_diagonal[i - 1] * c_prime[i - 1];
+        }
+        if pivot.abs() < tiny {
+            pivot = tiny;
+        }
+        c_prime[i] = if i + 1 < n { off_diagonal[i] / pivot } else { 0.0 };
+        let previous = if i > 0 { off_diagonal[i - 1] * d_prime[i - 1] } else { 0.0 };
+        d_prime[i] = (rhs[i] - previous) / pivot;
+    }
+
+    let mut y = d_prime;
+    for i in (0..n - 1).rev() {
+        y[i] -= c_prime[i] * y[i + 1];
+    }
+    y
+}
+
+/// Scale a vector to unit Euclidean length.
+fn normalize(v: &mut [f64]) {
+    let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    for x in v.iter_mut() {
+        *x /= norm;
+    }
+}
+
+/// Apply a plane rotation to a pair of entries.
+fn rotate(x: f64, y: f64, c: f64, s: f64) -> (f64, f64) {
+    (c * x - s * y, s * x + c * y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn diagonal_matrix_is_already_solved() {
+        let m = vec![vec![3.0, 0.0], vec![0.0, -1.0]];
+        let eigen = symmetric_eigen(&m);
+
+        assert_eq!(eigen.values, vec![-1.0, 3.0]);
+        assert_relative_eq!(eigen.vectors[0][1].abs(), 1.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn two_by_two_eigenvalues() {
+        // [[2, 1], [1, 2]] has eigenvalues 1 and 3
+        let m = vec![vec![2.0, 1.0], vec![1.0, 2.0]];
+        let eigen = symmetric_eigen(&m);
+
+        assert_relative_eq!(eigen.values[0], 1.0, epsilon = 1e-14);
+        assert_relative_eq!(eigen.values[1], 3.0, epsilon = 1e-14);
+
+        // Eigenvector of 3 is (1, 1)/√2
+        let v = &eigen.vectors[1];
+        assert_relative_eq!(v[0].abs(), std::f64::consts::FRAC_1_SQRT_2, epsilon = 1e-14);
+        assert_relative_eq!(v[0], v[1], epsilon = 1e-14);
+    }
+
+    #[test]
+    fn eigenpairs_satisfy_definition() {
+        // A v = λ v and eigenvectors are orthonormal, for a generic matrix
+        let m = vec![
+            vec![4.0, -1.0, 0.5, 0.0],
+            vec![-1.0, 3.0, 2.0, 0.1],
+            vec![0.5, 2.0, -2.0, 1.5],
+            vec![0.0, 0.1, 1.5, 1.0],
+        ];
+        let eigen = symmetric_eigen(&m);
+
+        for (k, (lambda, v)) in eigen.values.iter().zip(&eigen.vectors).enumerate() {
+            for i in 0..4 {
+                let av: f64 = (0..4).map(|j| m[i][j] * v[j]).sum();
+                assert_relative_eq!(av, lambda * v[i], epsilon = 1e-12);
+            }
+            for (l, w) in eigen.vectors.iter().enumerate() {
+                let dot: f64 = v.iter().zip(w).map(|(a, b)| a * b).sum();
+                let expected = if k == l { 1.0 } else { 0.0 };
+                assert_relative_eq!(dot, expected, epsilon = 1e-12);
+            }
+        }
+
+        // Trace is preserved
+        let trace: f64 = eigen.values.iter().sum();
+        assert_relative_eq!(trace, 6.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn tridiagonal_matches_dense_solver() {
+        let diagonal = vec![2.0, -1.0, 0.5, 3.0, 1.0, -2.0];
+        let off_diagonal = vec![0.7, -1.2, 0.3, 2.0, 0.9];
+
+        let n = diagonal.len();
+        let mut dense = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            dense[i][i] = diagonal[i];
+            if i + 1 < n {
+                dense[i][i + 1] = off_diagonal[i];
+                dense[i + 1][i] = off_diagonal[i];
+            }
+        }
+
+        let reference = symmetric_eigen(&dense);
+        let lowest = tridiagonal_lowest_eigen(&diagonal, &off_diagonal, 4);
+
+        for k in 0..4 {
+            assert_relative_eq!(lowest.values[k], reference.values[k], epsilon = 1e-12);
+            // Eigenvectors agree up to sign
+            let dot: f64 = lowest.vectors[k].iter().zip(&reference.vectors[k]).map(|(a, b)| a * b).sum();
+            assert_relative_eq!(dot.abs(), 1.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn tridiagonal_second_difference_spectrum() {
+        // The matrix tridiag(-1, 2, -1) has eigenvalues 2 - 2cos(kπ/(n+1))
+        let n = 200;
+        let diagonal = vec![2.0; n];
+        let off_diagonal = vec![-1.0; n - 1];
+        let eigen = tridiagonal_lowest_eigen(&diagonal, &off_diagonal, 3);
+
+        for (k, value) in eigen.values.iter().enumerate() {
+            let expected = 2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+            assert_relative_eq!(*value, expected, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn linear_solve_needs_pivoting() {
+        // Zero in the top-left corner forces a row swap
+        let m = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, -1.0, 0.0],
+            vec![3.0, 0.0, -2.0],
+        ];
+        let expected = [1.0, -2.0, 0.5];
+        let rhs: Vec<f64> = m.iter().map(|row| row.iter().zip(&expected).map(|(a, x)| a * x).sum()).collect();
+
+        let x = solve_linear(&m, &rhs).unwrap();
+        for (value, want) in x.iter().zip(&expected) {
+            assert_relative_eq!(*value, *want, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn singular_system_has_no_solution() {
+        let m = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert!(solve_linear(&m, &[1.0, 1.0]).is_none());
+    }
+}
@@ -0,0 +1,7 @@
+// Numerical utilities (special functions, linear algebra, complex numbers, FFT, random numbers)
+
+pub mod special;
+pub mod linalg;
+pub mod complex;
+pub mod fft;
+pub mod random;
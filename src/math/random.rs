@@ -0,0 +1,100 @@
+// Seedable pseudo-random numbers
+// xoshiro256** (Blackman & Vigna 2018), seeded through SplitMix64 so that
+// nearby seeds give unrelated streams. Normal deviates use Box–Muller.
+// Reproducible runs matter more here than cryptographic quality.
+
+/// A small, fast, seedable random number generator.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: [u64; 4],
+    /// Second Box–Muller deviate, kept for the next call
+    spare: Option<f64>,
+}
+
+impl Random {
+    /// Generator with a fixed seed (the same seed always gives the same stream)
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut split_mix = || {
+            x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [split_mix(), split_mix(), split_mix(), split_mix()],
+            spare: None,
+        }
+    }
+
+    /// Next raw 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform deviate in [0, 1)
+    pub fn uniform(&mut self) -> f64 {
+        // The top 53 bits fill the mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal deviate (mean 0, variance 1)
+    pub fn gaussian(&mut self) -> f64 {
+        if let Some(spare) = self.spare.take() {
+            return spare;
+        }
+        // 1 - u lies in (0, 1], so the logarithm is finite
+        let radius = (-2.0 * (1.0 - self.uniform()).ln()).sqrt();
+        let angle = std::f64::consts::TAU * self.uniform();
+        self.spare = Some(radius * angle.sin());
+        radius * angle.cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_stream() {
+        let (mut a, mut b, mut c) = (Random::new(7), Random::new(7), Random::new(8));
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(first[0], c.next_u64());
+    }
+
+    #[test]
+    fn uniform_moments() {
+        let mut random = Random::new(1);
+        let samples: Vec<f64> = (0..100_000).map(|_| random.uniform()).collect();
+        assert!(samples.iter().all(|&u| (0.0..1.0).contains(&u)));
+
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 0.5).abs() < 0.005, "Mean {mean}");
+    }
+
+    #[test]
+    fn gaussian_moments() {
+        let mut random = Random::new(2);
+        let n = 200_000;
+        let samples: Vec<f64> = (0..n).map(|_| random.gaussian()).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        let kurtosis = samples.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n as f64 / variance.powi(2);
+
+        assert!(mean.abs() < 0.01, "Mean {mean}");
+        assert!((variance - 1.0).abs() < 0.01, "Variance {variance}");
+        assert!((kurtosis - 3.0).abs() < 0.05, "Kurtosis {kurtosis}");
+    }
+}
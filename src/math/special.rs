@@ -0,0 +1,133 @@
+// Special functions not provided by the standard library
+// erf(x) = (2/√π) ∫₀ˣ e^(-t²) dt
+// E₁(x) = ∫ₓ^∞ e^(-t)/t dt
+
+use std::f64::consts::FRAC_2_SQRT_PI;
+
+/// The error function erf(x).
+///
+/// Accurate to near machine precision over the whole real line.
+/// Small arguments use a positive-term series, large arguments use the
+/// continued fraction for erfc, so neither branch suffers cancellation.
+pub fn erf(x: f64) -> f64 {
+    if x < 0.0 {
+        return -erf(-x);
+    }
+    if x < 3.0 {
+        erf_series(x)
+    } else {
+        1.0 - erfc_continued_fraction(x)
+    }
+}
+
+/// The complementary error function erfc(x) = 1 - erf(x).
+///
+/// Computed directly for large x so the tail is not lost to rounding.
+pub fn erfc(x: f64) -> f64 {
+    if x < 3.0 {
+        1.0 - erf(x)
+    } else {
+        erfc_continued_fraction(x)
+    }
+}
+
+/// erf(x) = (2/√π) e^(-x²) Σ 2ⁿ x^(2n+1) / (1·3·5···(2n+1))
+/// Every term is positive, so this converges without cancellation.
+fn erf_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 0.0;
+    while term > sum * 1e-17 {
+        n += 1.0;
+        term *= 2.0 * x2 / (2.0 * n + 1.0);
+        sum += term;
+    }
+    FRAC_2_SQRT_PI * (-x2).exp() * sum
+}
+
+/// erfc(x) = e^(-x²)/√π · 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + ...))))
+/// Evaluated bottom-up; converges quickly for x ≥ 3.
+fn erfc_continued_fraction(x: f64) -> f64 {
+    let mut fraction = x;
+    for k in (1..=60).rev() {
+        fraction = x + (k as f64 / 2.0) / fraction;
+    }
+    0.5 * FRAC_2_SQRT_PI * (-x * x).exp() / fraction
+}
+
+/// Euler–Mascheroni constant γ
+pub const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// The exponential integral E₁(x) for x > 0.
+///
+/// Related to the other common convention by Ei(-x) = -E₁(x).
+/// Uses the power series for x ≤ 1 and a continued fraction above.
+pub fn exp_integral_e1(x: f64) -> f64 {
+    assert!(x > 0.0, "E₁(x) is only defined here for x > 0");
+
+    if x <= 1.0 {
+        // E₁(x) = -γ - ln x - Σ (-x)ᵏ / (k·k!)
+        let mut sum = 0.0;
+        let mut term = 1.0; // (-x)ᵏ / k!
+        for k in 1..=40 {
+            term *= -x / k as f64;
+            sum += term / k as f64;
+        }
+        -EULER_GAMMA - x.ln() - sum
+    } else {
+        // E₁(x) = e^(-x) / (x + 1/(1 + 1/(x + 2/(1 + 2/(x + ...)))))
+        let mut fraction = x;
+        for k in (1..=80).rev() {
+            let k = k as f64;
+            fraction = x + k / (1.0 + k / fraction);
+        }
+        (-x).exp() / fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn erf_reference_values() {
+        // Reference values from tables of the error function
+        assert_relative_eq!(erf(0.0), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(erf(0.5), 0.520_499_877_813_046_5, max_relative = 1e-14);
+        assert_relative_eq!(erf(1.0), 0.842_700_792_949_714_9, max_relative = 1e-14);
+        assert_relative_eq!(erf(2.0), 0.995_322_265_018_952_7, max_relative = 1e-14);
+        assert_relative_eq!(erf(3.5), 0.999_999_256_901_627_7, max_relative = 1e-14);
+    }
+
+    #[test]
+    fn erf_is_odd() {
+        for &x in &[0.1, 0.7, 1.9, 2.99, 3.01, 4.5] {
+            assert_relative_eq!(erf(-x), -erf(x), epsilon = 1e-15);
+        }
+    }
+
+    #[test]
+    fn erf_is_continuous_across_branches() {
+        // The series and continued fraction must agree at the switch point
+        let below = erf_series(3.0);
+        let above = 1.0 - erfc_continued_fraction(3.0);
+        assert_relative_eq!(below, above, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn erfc_keeps_precision_in_the_tail() {
+        // erfc(5) ≈ 1.5375e-12, far below the resolution of 1 - erf(5)
+        assert_relative_eq!(erfc(5.0), 1.537_459_794_428_035e-12, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn exp_integral_reference_values() {
+        assert_relative_eq!(exp_integral_e1(0.1), 1.822_923_958_419_390_7, max_relative = 1e-13);
+        assert_relative_eq!(exp_integral_e1(0.5), 0.559_773_594_776_160_8, max_relative = 1e-13);
+        assert_relative_eq!(exp_integral_e1(1.0), 0.219_383_934_395_520_3, max_relative = 1e-13);
+        assert_relative_eq!(exp_integral_e1(3.0), 0.013_048_381_094_197_04, max_relative = 1e-12);
+        assert_relative_eq!(exp_integral_e1(10.0), 4.156_968_929_685_324e-6, max_relative = 1e-12);
+    }
+}
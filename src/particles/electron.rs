@@ -100,6 +100,15 @@ pub enum OrbitalType {
     D { n: u32, m: i32 },
 }
 
+impl OrbitalType {
+    /// Principal quantum number n of this orbital
+    pub fn principal(&self) -> u32 {
+        match *self {
+            OrbitalType::S { n } | OrbitalType::P { n, .. } | OrbitalType::D { n, .. } => n,
+        }
+    }
+}
+
 impl ProbabilityCloud {
     /// Create a ground state hydrogen 1s orbital.
     pub fn hydrogen_1s(center: DVec3) -> Self {
@@ -140,6 +149,17 @@ impl ProbabilityCloud {
         }
     }
 
+    /// Width σ of the spherical Gaussian charge distribution that stands in
+    /// for this cloud in electrostatic calculations.
+    ///
+    /// A hydrogenic orbital has ⟨1/r⟩ = 1/(n²a), and a Gaussian has
+    /// ⟨1/r⟩ = √(2/π)/σ. Matching the two gives σ = √(2/π)·n²·a, so the
+    /// potential felt by a nucleus at the cloud centre is exact.
+    pub fn gaussian_width(&self) -> f64 {
+        let n = self.orbital.principal() as f64;
+        (2.0 / std::f64::consts::PI).sqrt() * n * n * self.length_scale
+    }
+
     /// Get the radius at which probability density is some fraction of maximum.
     /// Useful for determining visual cloud extent.
     pub fn extent_radius(&self, fraction: f64) -> f64 {
@@ -246,6 +266,34 @@ mod tests {
         assert!(r_1_percent > r_10_percent, "Smaller fraction should give larger radius");
     }
 
+    #[test]
+    fn gaussian_width_reproduces_potential_at_nucleus() {
+        // A proton at the centre of a 1s cloud has energy -ke²⟨1/r⟩ = -ke²/a₀
+        use crate::physics::constants::COULOMB_CONSTANT;
+        use crate::physics::coulomb::gaussian_coulomb_potential;
+
+        let cloud = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let energy = gaussian_coulomb_potential(
+            ELEMENTARY_CHARGE,
+            -ELEMENTARY_CHARGE,
+            0.0,
+            0.0,
+            cloud.gaussian_width(),
+        );
+        let expected = -COULOMB_CONSTANT * ELEMENTARY_CHARGE.powi(2) / BOHR_RADIUS;
+
+        assert_relative_eq!(energy, expected, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn gaussian_width_grows_as_n_squared() {
+        let mut cloud = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let width_1s = cloud.gaussian_width();
+        cloud.orbital = OrbitalType::S { n: 2 };
+
+        assert_relative_eq!(cloud.gaussian_width(), 4.0 * width_1s, max_relative = 1e-12);
+    }
+
     #[test]
     fn probability_cloud_normalization() {
         // The integral of |ψ|² over all space should equal 1.
@@ -1,4 +1,5 @@
-// Particle types (proton, electron, etc.)
+// Particle types (proton, electron, photon, etc.)
 
 pub mod proton;
 pub mod electron;
+pub mod photon;
@@ -0,0 +1,165 @@
+// Photon particle
+// A massless quantum of light: energy E = hf = hc/λ, moving at c
+
+use bevy::prelude::*;
+use glam::DVec3;
+use crate::physics::constants::SPEED_OF_LIGHT;
+use crate::physics::spectroscopy::{
+    absorption_target, photon_energy, photon_frequency, photon_wavelength, transition_energy,
+    wavelength_to_srgb,
+};
+
+/// A photon particle component.
+/// Photons travel in straight lines at the speed of light and carry
+/// exactly the energy of the transition that created them.
+#[derive(Component, Debug, Clone)]
+pub struct Photon {
+    /// Position in meters
+    pub position: DVec3,
+    /// Unit vector along the direction of travel
+    pub direction: DVec3,
+    /// Photon energy in Joules
+    pub energy: f64,
+}
+
+impl Photon {
+    /// Create a photon with a given energy travelling along `direction`.
+    pub fn new(position: DVec3, direction: DVec3, energy: f64) -> Self {
+        assert!(energy > 0.0, "Photon energy must be positive");
+        Self {
+            position,
+            direction: direction.normalize(),
+            energy,
+        }
+    }
+
+    /// Create a photon from its vacuum wavelength in meters.
+    pub fn with_wavelength(position: DVec3, direction: DVec3, wavelength: f64) -> Self {
+        Self::new(position, direction, photon_energy(wavelength))
+    }
+
+    /// Create the photon released by a hydrogenic `upper → lower` transition.
+    pub fn from_transition(
+        position: DVec3,
+        direction: DVec3,
+        upper: u32,
+        lower: u32,
+        z: u32,
+        nuclear_mass: f64,
+    ) -> Self {
+        Self::new(position, direction, transition_energy(upper, lower, z, nuclear_mass))
+    }
+
+    /// Vacuum wavelength in meters
+    pub fn wavelength(&self) -> f64 {
+        photon_wavelength(self.energy)
+    }
+
+    /// Frequency in Hertz
+    pub fn frequency(&self) -> f64 {
+        photon_frequency(self.energy)
+    }
+
+    /// Momentum p = (E/c) n̂ in kg⋅m/s
+    pub fn momentum(&self) -> DVec3 {
+        self.direction * self.energy / SPEED_OF_LIGHT
+    }
+
+    /// Velocity c n̂ in meters per second
+    pub fn velocity(&self) -> DVec3 {
+        self.direction * SPEED_OF_LIGHT
+    }
+
+    /// Move the photon along its straight path.
+    pub fn advance(&mut self, dt: f64) {
+        self.position += self.velocity() * dt;
+    }
+
+    /// Display colour as sRGB (black outside the visible range)
+    pub fn srgb(&self) -> [f32; 3] {
+        wavelength_to_srgb(self.wavelength())
+    }
+
+    /// Level an atom in `level` would be excited to by absorbing this photon,
+    /// or `None` if the energy matches no gap within `tolerance` (relative).
+    pub fn absorption_level(
+        &self,
+        level: u32,
+        z: u32,
+        nuclear_mass: f64,
+        tolerance: f64,
+        max_level: u32,
+    ) -> Option<u32> {
+        absorption_target(level, self.energy, z, nuclear_mass, tolerance, max_level)
+    }
+}
+
+/// Sent when a bound electron drops to a lower level and releases a photon.
+#[derive(Event, Debug, Clone)]
+pub struct PhotonEmitted {
+    /// The electron (or atom) that emitted
+    pub emitter: Entity,
+    pub photon: Photon,
+    /// Level before emission (0 if the electron was not yet on a level)
+    pub upper: u32,
+    /// Level after emission
+    pub lower: u32,
+}
+
+/// Sent when a photon is absorbed and lifts an electron to a higher level.
+#[derive(Event, Debug, Clone)]
+pub struct PhotonAbsorbed {
+    /// The electron (or atom) that absorbed
+    pub absorber: Entity,
+    /// Photon energy in Joules
+    pub energy: f64,
+    /// Level before absorption
+    pub lower: u32,
+    /// Level after absorption
+    pub upper: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::PROTON_MASS;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn photon_travels_at_light_speed() {
+        let mut photon = Photon::with_wavelength(DVec3::ZERO, DVec3::new(0.0, 2.0, 0.0), 500.0e-9);
+
+        assert_relative_eq!(photon.direction.length(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(photon.velocity().length(), SPEED_OF_LIGHT, max_relative = 1e-12);
+
+        photon.advance(1.0e-15);
+        assert_relative_eq!(photon.position.y, SPEED_OF_LIGHT * 1.0e-15, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn wavelength_and_momentum() {
+        let photon = Photon::with_wavelength(DVec3::ZERO, DVec3::X, 656.0e-9);
+        assert_relative_eq!(photon.wavelength(), 656.0e-9, max_relative = 1e-12);
+
+        // p = h/λ
+        let expected = crate::physics::constants::PLANCK_CONSTANT / 656.0e-9;
+        assert_relative_eq!(photon.momentum().x, expected, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn h_alpha_photon_is_red_and_re_absorbable() {
+        // Emitted on 3 → 2, the same photon can lift another n = 2 atom to n = 3
+        let photon = Photon::from_transition(DVec3::ZERO, DVec3::X, 3, 2, 1, PROTON_MASS);
+        let colour = photon.srgb();
+        assert!(colour[0] > colour[1] && colour[0] > colour[2]);
+
+        assert_eq!(photon.absorption_level(2, 1, PROTON_MASS, 1e-4, 10), Some(3));
+        assert_eq!(photon.absorption_level(1, 1, PROTON_MASS, 1e-4, 10), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn photon_needs_positive_energy() {
+        Photon::new(DVec3::ZERO, DVec3::X, 0.0);
+    }
+}
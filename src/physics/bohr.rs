@@ -0,0 +1,342 @@
+// Quantized "Bohr snap" mode
+// Each electron is bound to its nearest nucleus and snapped onto a circular
+// Bohr orbit of the nearest allowed hydrogenic level:
+//   r_n = n² a₀ (mₑ/μ) / Z,   E_n = -Z² (μ/mₑ) E_h / (2n²)
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{BOHR_RADIUS, COULOMB_CONSTANT, ELEMENTARY_CHARGE, ELECTRON_MASS};
+use super::spectroscopy::{level_energy, reduced_mass_factor};
+use crate::particles::electron::{Electron, OrbitalType, ProbabilityCloud};
+
+/// Settings for the quantized orbit mode.
+#[derive(Resource, Debug, Clone)]
+pub struct BohrSnapConfig {
+    /// Whether electrons are snapped to levels at all
+    pub enabled: bool,
+    /// Highest level an electron can be snapped to
+    pub max_level: u32,
+    /// Seconds an excited level lasts before dropping one step
+    pub relaxation_time: f32,
+    /// Leftover energies below this (Joules) are not worth a photon
+    pub min_emission_energy: f64,
+}
+
+impl Default for BohrSnapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_level: 6,
+            // Long enough to see the excited cloud before it relaxes
+            relaxation_time: 1.5,
+            // 0.01 eV: ignores integration noise, keeps every real transition
+            min_emission_energy: 0.01 * ELEMENTARY_CHARGE,
+        }
+    }
+}
+
+/// Quantized state of an electron in Bohr snap mode.
+#[derive(Component, Debug, Clone, Default)]
+pub struct BohrState {
+    /// Nucleus the electron is currently bound to
+    pub nucleus: Option<Entity>,
+    /// Principal quantum number (0 = not bound to any level)
+    pub level: u32,
+    /// Seconds spent in the current level
+    pub time_in_level: f32,
+}
+
+/// The parts of a nucleus the snap needs.
+#[derive(Debug, Clone, Copy)]
+pub struct BohrNucleus {
+    pub entity: Entity,
+    /// Position in meters
+    pub position: DVec3,
+    /// Velocity in meters per second
+    pub velocity: DVec3,
+    /// Nuclear charge number Z
+    pub z: u32,
+    /// Nuclear mass in kilograms
+    pub mass: f64,
+}
+
+/// What happened to an electron during one snap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapOutcome {
+    /// Nucleus the electron was assigned to
+    pub nucleus: Entity,
+    /// Level before this snap (0 if it was not bound)
+    pub previous_level: u32,
+    /// Level after this snap
+    pub level: u32,
+    /// Energy given up by the snap in Joules (negative if energy was absorbed)
+    pub released_energy: f64,
+}
+
+/// Index of the nucleus closest to `position`.
+pub fn nearest_nucleus(position: DVec3, nuclei: &[BohrNucleus]) -> Option<usize> {
+    nuclei
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            a.position.distance_squared(position).total_cmp(&b.position.distance_squared(position))
+        })
+        .map(|(index, _)| index)
+}
+
+/// Energy of the electron–nucleus pair in its centre-of-mass frame (Joules).
+///
+/// E = ½ μ |v_e - v_N|² - kZe²/r. Negative means bound. The Bohr picture
+/// treats both particles as point charges.
+pub fn bound_energy(electron: &Electron, nucleus: &BohrNucleus) -> f64 {
+    let mu = ELECTRON_MASS * reduced_mass_factor(nucleus.mass);
+    let relative_velocity = electron.velocity - nucleus.velocity;
+    let r = electron.position.distance(nucleus.position);
+
+    let kinetic = 0.5 * mu * relative_velocity.length_squared();
+    let potential = -COULOMB_CONSTANT * nucleus.z as f64 * ELEMENTARY_CHARGE.powi(2) / r;
+    kinetic + potential
+}
+
+/// Allowed level whose energy is closest to `energy`, or `None` if unbound.
+pub fn nearest_level(energy: f64, nucleus: &BohrNucleus, max_level: u32) -> Option<u32> {
+    if energy >= 0.0 {
+        return None;
+    }
+
+    (1..=max_level.max(1)).min_by(|&a, &b| {
+        let ea = (level_energy(a, nucleus.z, nucleus.mass) - energy).abs();
+        let eb = (level_energy(b, nucleus.z, nucleus.mass) - energy).abs();
+        ea.total_cmp(&eb)
+    })
+}
+
+/// Radius of the circular Bohr orbit for level n, r_n = n² a₀ (mₑ/μ) / Z.
+pub fn orbit_radius(n: u32, nucleus: &BohrNucleus) -> f64 {
+    let n = n as f64;
+    n * n * BOHR_RADIUS / (nucleus.z as f64 * reduced_mass_factor(nucleus.mass))
+}
+
+/// Place an electron on the circular Bohr orbit of level n.
+///
+/// Keeps the current direction from the nucleus and the current sense of
+/// rotation, so the snap looks continuous on screen.
+pub fn snap_to_level(electron: &mut Electron, nucleus: &BohrNucleus, n: u32) {
+    let radius = orbit_radius(n, nucleus);
+    let mu = ELECTRON_MASS * reduced_mass_factor(nucleus.mass);
+
+    let offset = electron.position - nucleus.position;
+    let radial = offset.try_normalize().unwrap_or(DVec3::X);
+
+    // Orbit normal from the current angular momentum; default to the screen normal
+    let angular = offset.cross(electron.velocity - nucleus.velocity);
+    let normal = angular.try_normalize().unwrap_or(DVec3::Z);
+    let tangent = normal.cross(radial).try_normalize().unwrap_or(DVec3::Y);
+
+    // Circular orbit: μv²/r = kZe²/r²
+    let speed = (COULOMB_CONSTANT * nucleus.z as f64 * ELEMENTARY_CHARGE.powi(2) / (mu * radius)).sqrt();
+
+    electron.position = nucleus.position + radial * radius;
+    electron.velocity = nucleus.velocity + tangent * speed;
+}
+
+/// Run one snap for an electron: bind it to the nearest nucleus, pick the
+/// nearest level (or relax one step if it has lived long enough), move it
+/// onto that orbit and reshape its cloud.
+///
+/// # Arguments
+/// * `elapsed` - Seconds since the previous snap, for relaxation timing
+///
+/// # Returns
+/// `None` if there are no nuclei or the electron is unbound (its state is cleared).
+pub fn snap_electron(
+    electron: &mut Electron,
+    cloud: &mut ProbabilityCloud,
+    state: &mut BohrState,
+    nuclei: &[BohrNucleus],
+    config: &BohrSnapConfig,
+    elapsed: f32,
+) -> Option<SnapOutcome> {
+    let Some(index) = nearest_nucleus(electron.position, nuclei) else {
+        *state = BohrState::default();
+        return None;
+    };
+    let nucleus = &nuclei[index];
+
+    let energy = bound_energy(electron, nucleus);
+    let Some(mut level) = nearest_level(energy, nucleus, config.max_level) else {
+        *state = BohrState::default();
+        return None;
+    };
+
+    // Same level, same nucleus: let time pass and maybe relax a step
+    let previous_level = if state.nucleus == Some(nucleus.entity) { state.level } else { 0 };
+    if level == previous_level {
+        state.time_in_level += elapsed;
+        if level > 1 && state.time_in_level >= config.relaxation_time {
+            level -= 1;
+        }
+    }
+    if level != previous_level {
+        state.time_in_level = 0.0;
+    }
+
+    snap_to_level(electron, nucleus, level);
+
+    cloud.orbital = OrbitalType::S { n: level };
+    cloud.center = nucleus.position;
+    cloud.length_scale = BOHR_RADIUS / (nucleus.z as f64 * reduced_mass_factor(nucleus.mass));
+
+    state.nucleus = Some(nucleus.entity);
+    state.level = level;
+
+    Some(SnapOutcome {
+        nucleus: nucleus.entity,
+        previous_level,
+        level,
+        released_energy: energy - level_energy(level, nucleus.z, nucleus.mass),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::PROTON_MASS;
+    use approx::assert_relative_eq;
+
+    fn proton_at(position: DVec3, id: u32) -> BohrNucleus {
+        BohrNucleus {
+            entity: Entity::from_raw(id),
+            position,
+            velocity: DVec3::ZERO,
+            z: 1,
+            mass: PROTON_MASS,
+        }
+    }
+
+    /// Electron on a circular orbit of radius r around a fixed proton, scaled in speed
+    fn orbiting_electron(r: f64, speed_factor: f64) -> Electron {
+        let v = (COULOMB_CONSTANT * ELEMENTARY_CHARGE.powi(2) / (ELECTRON_MASS * r)).sqrt();
+        Electron::with_velocity(DVec3::new(r, 0.0, 0.0), DVec3::new(0.0, v * speed_factor, 0.0))
+    }
+
+    #[test]
+    fn electron_binds_to_nearest_nucleus() {
+        let nuclei = [
+            proton_at(DVec3::new(-5.0 * BOHR_RADIUS, 0.0, 0.0), 1),
+            proton_at(DVec3::new(5.0 * BOHR_RADIUS, 0.0, 0.0), 2),
+        ];
+        assert_eq!(nearest_nucleus(DVec3::new(3.0 * BOHR_RADIUS, 0.0, 0.0), &nuclei), Some(1));
+        assert_eq!(nearest_nucleus(DVec3::ZERO, &[]), None);
+    }
+
+    #[test]
+    fn snapped_orbit_has_level_energy() {
+        let nucleus = proton_at(DVec3::new(1.0e-10, 2.0e-10, 0.0), 1);
+
+        for n in 1..=4 {
+            let mut electron = Electron::new(nucleus.position + DVec3::new(0.0, 3.0e-10, 0.0));
+            snap_to_level(&mut electron, &nucleus, n);
+
+            assert_relative_eq!(
+                electron.position.distance(nucleus.position),
+                orbit_radius(n, &nucleus),
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(
+                bound_energy(&electron, &nucleus),
+                level_energy(n, 1, PROTON_MASS),
+                max_relative = 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn nearest_level_picks_closest_energy() {
+        let nucleus = proton_at(DVec3::ZERO, 1);
+        let e1 = level_energy(1, 1, PROTON_MASS);
+        let e2 = level_energy(2, 1, PROTON_MASS);
+
+        assert_eq!(nearest_level(0.9 * e1, &nucleus, 6), Some(1));
+        assert_eq!(nearest_level(0.5 * (e1 + e2) + 0.01 * e2.abs(), &nucleus, 6), Some(2));
+        assert_eq!(nearest_level(1e-30, &nucleus, 6), None);
+        // Loosely bound electrons cap at the highest allowed level
+        assert_eq!(nearest_level(1e-4 * e1, &nucleus, 3), Some(3));
+    }
+
+    #[test]
+    fn snap_releases_leftover_energy() {
+        // A slightly too-fast orbit at a₀ sits between levels and drops to n = 1
+        let nuclei = [proton_at(DVec3::ZERO, 7)];
+        let mut electron = orbiting_electron(BOHR_RADIUS, 1.1);
+        let mut cloud = ProbabilityCloud::hydrogen_1s(DVec3::new(1.0, 1.0, 0.0));
+        let mut state = BohrState::default();
+        let before = bound_energy(&electron, &nuclei[0]);
+
+        let outcome = snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &BohrSnapConfig::default(), 0.0)
+            .expect("electron is bound");
+
+        assert_eq!(outcome.level, 1);
+        assert_eq!(outcome.previous_level, 0);
+        assert_relative_eq!(
+            outcome.released_energy,
+            before - level_energy(1, 1, PROTON_MASS),
+            max_relative = 1e-12
+        );
+
+        // Cloud now describes the bound level, centred on the nucleus
+        assert_eq!(cloud.orbital, OrbitalType::S { n: 1 });
+        assert_eq!(cloud.center, DVec3::ZERO);
+        assert_eq!(state.nucleus, Some(Entity::from_raw(7)));
+    }
+
+    #[test]
+    fn kicked_electron_is_excited_then_relaxes() {
+        let nuclei = [proton_at(DVec3::ZERO, 1)];
+        let config = BohrSnapConfig { relaxation_time: 1.0, ..Default::default() };
+        let mut cloud = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let mut state = BohrState::default();
+
+        // Start in the ground state
+        let mut electron = orbiting_electron(BOHR_RADIUS, 1.0);
+        snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &config, 0.0);
+        assert_eq!(state.level, 1);
+
+        // A kick (as from dragging the proton) pumps in energy: E ≈ E₂
+        let e2 = level_energy(2, 1, PROTON_MASS);
+        let e = bound_energy(&electron, &nuclei[0]);
+        let extra = ((2.0 * (e2 - e) / ELECTRON_MASS) + electron.velocity.length_squared()).sqrt();
+        electron.velocity = electron.velocity.normalize() * extra;
+
+        let excited = snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &config, 0.1).unwrap();
+        assert_eq!(excited.level, 2);
+        assert!(excited.released_energy.abs() < 0.01 * e2.abs());
+        assert_eq!(cloud.orbital, OrbitalType::S { n: 2 });
+
+        // It survives briefly, then drops back and gives up the 2 → 1 gap
+        let holding = snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &config, 0.5).unwrap();
+        assert_eq!(holding.level, 2);
+
+        let relaxed = snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &config, 0.6).unwrap();
+        assert_eq!((relaxed.previous_level, relaxed.level), (2, 1));
+        assert_relative_eq!(
+            relaxed.released_energy,
+            e2 - level_energy(1, 1, PROTON_MASS),
+            max_relative = 1e-8
+        );
+        assert_eq!(cloud.orbital, OrbitalType::S { n: 1 });
+    }
+
+    #[test]
+    fn unbound_electron_clears_state() {
+        let nuclei = [proton_at(DVec3::ZERO, 1)];
+        let mut electron = orbiting_electron(BOHR_RADIUS, 2.0); // above escape speed
+        let mut cloud = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let mut state = BohrState { nucleus: Some(Entity::from_raw(1)), level: 1, time_in_level: 0.0 };
+
+        let outcome = snap_electron(&mut electron, &mut cloud, &mut state, &nuclei, &BohrSnapConfig::default(), 0.0);
+        assert!(outcome.is_none());
+        assert_eq!(state.level, 0);
+        assert!(state.nucleus.is_none());
+    }
+}
@@ -0,0 +1,362 @@
+// Reactive bond-order potential (simplified Tersoff/Brenner) for H, C, N and O
+// Every pair within a short cutoff interacts through a Morse-like pair of
+// exponentials whose attraction is scaled by a bond order:
+//   E = Σᵢ<ⱼ f_c(rᵢⱼ) [V_R(rᵢⱼ) - b̄ᵢⱼ V_A(rᵢⱼ)]
+//   V_R = D/(S-1) e^(-√(2S) β (r - rₑ)),   V_A = D S/(S-1) e^(-√(2/S) β (r - rₑ))
+//   b̄ᵢⱼ = (bᵢⱼ + bⱼᵢ)/2,   bᵢⱼ = (1 + ζᵢⱼⁿ)^(-δ/n),   ζᵢⱼ = Σₖ≠ⱼ f_c(rᵢₖ) / sᵢ
+// With no other neighbours b = 1 and the pair is the free diatomic (depth D
+// at rₑ). Each extra neighbour of atom i weakens all of i's bonds, sharply
+// once it has more than its saturation sᵢ of other partners, so a fifth
+// atom on carbon costs more than it gains and the bonds pop off.
+// Coordination only: there is no angular term. Internally in Å and eV.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+
+/// Diatomic parameters for one element pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairParameters {
+    /// Equilibrium length rₑ of the free diatomic in Å
+    pub length: f64,
+    /// Well depth D of the free diatomic in eV
+    pub dissociation: f64,
+    /// Morse range β in 1/Å
+    pub range: f64,
+}
+
+impl PairParameters {
+    /// Parameters for the pair of nuclear charge numbers (in either order).
+    pub fn for_elements(z1: u32, z2: u32) -> Option<Self> {
+        let (length, dissociation, range) = match (z1.min(z2), z1.max(z2)) {
+            (1, 1) => (0.741, 4.52, 1.94),
+            (1, 6) => (1.120, 3.65, 1.96),
+            (1, 7) => (1.036, 3.47, 2.00),
+            (1, 8) => (0.970, 4.62, 2.29),
+            (6, 6) => (1.315, 6.33, 1.50),
+            (6, 7) => (1.172, 7.76, 2.30),
+            (6, 8) => (1.128, 11.1, 2.30),
+            (7, 7) => (1.098, 9.91, 2.69),
+            (7, 8) => (1.151, 6.61, 2.73),
+            (8, 8) => (1.208, 5.21, 2.65),
+            _ => return None,
+        };
+        Some(Self { length, dissociation, range })
+    }
+
+    /// Inner and outer cutoff radius in Å; the interaction switches off smoothly between them
+    pub fn cutoff(&self) -> (f64, f64) {
+        (self.length + 0.35, self.length + 0.65)
+    }
+
+    /// Smooth cutoff f_c and its derivative at distance r (Å)
+    fn switch(&self, r: f64) -> (f64, f64) {
+        let (inner, outer) = self.cutoff();
+        if r <= inner {
+            (1.0, 0.0)
+        } else if r >= outer {
+            (0.0, 0.0)
+        } else {
+            let width = outer - inner;
+            let x = std::f64::consts::PI * (r - inner) / width;
+            (0.5 * (1.0 + x.cos()), -0.5 * std::f64::consts::PI / width * x.sin())
+        }
+    }
+}
+
+/// How many neighbours besides a bond partner an atom tolerates before its
+/// bonds weaken: one fewer than its valence. Hydrogen is given a fraction
+/// so that a second partner already costs it most of its bond.
+pub fn saturation(z: u32) -> Option<f64> {
+    match z {
+        1 => Some(0.25),
+        6 => Some(3.0),
+        7 => Some(2.0),
+        8 => Some(1.0),
+        _ => None,
+    }
+}
+
+/// An atom taking part in reactive dynamics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReactiveAtom {
+    /// Nuclear charge number Z
+    pub z: u32,
+    /// Position in meters
+    pub position: DVec3,
+}
+
+/// A pair within cutoff and its current bond order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReactiveBond {
+    pub a: usize,
+    pub b: usize,
+    /// Symmetrised bond order b̄ (1 for an isolated diatomic)
+    pub order: f64,
+}
+
+/// Energy, forces and bond orders for one geometry.
+#[derive(Debug, Clone)]
+pub struct ReactiveEvaluation {
+    /// Potential energy in Joules (0 for separated atoms)
+    pub energy: f64,
+    /// Force on each atom in Newtons
+    pub forces: Vec<DVec3>,
+    pub bonds: Vec<ReactiveBond>,
+}
+
+/// Bond-order potential settings.
+#[derive(Resource, Debug, Clone)]
+pub struct ReactiveBondOrder {
+    /// Sharpness n of the onset of over-coordination
+    pub sharpness: f64,
+    /// Decay δ of the bond order once over-coordinated, b ~ ζ^(-δ)
+    pub decay: f64,
+    /// Shape S of the pair term (ratio of repulsive to attractive range)
+    pub shape: f64,
+}
+
+impl Default for ReactiveBondOrder {
+    fn default() -> Self {
+        Self { sharpness: 8.0, decay: 1.5, shape: 1.5 }
+    }
+}
+
+/// A neighbour within cutoff of some atom.
+struct Neighbour {
+    index: usize,
+    distance: f64,
+    /// Unit vector from the atom to the neighbour
+    direction: DVec3,
+    switch: f64,
+    switch_slope: f64,
+}
+
+impl ReactiveBondOrder {
+    /// Repulsive and attractive terms with their derivatives, in eV and eV/Å
+    fn pair_terms(&self, parameters: &PairParameters, r: f64) -> (f64, f64, f64, f64) {
+        let s = self.shape;
+        let (d, beta, x) = (parameters.dissociation, parameters.range, r - parameters.length);
+        let (a_r, a_a) = ((2.0 * s).sqrt() * beta, (2.0 / s).sqrt() * beta);
+        let repulsive = d / (s - 1.0) * (-a_r * x).exp();
+        let attractive = d * s / (s - 1.0) * (-a_a * x).exp();
+        (repulsive, -a_r * repulsive, attractive, -a_a * attractive)
+    }
+
+    /// Bond order bᵢⱼ and db/dζ for coordination sum ζ
+    fn bond_order(&self, zeta: f64) -> (f64, f64) {
+        let (n, delta) = (self.sharpness, self.decay);
+        let base = 1.0 + zeta.powf(n);
+        let order = base.powf(-delta / n);
+        let slope = if zeta > 0.0 { -delta * zeta.powf(n - 1.0) * order / base } else { 0.0 };
+        (order, slope)
+    }
+
+    /// Pair energy at a fixed bond order, in Joules.
+    ///
+    /// # Arguments
+    /// * `z1`, `z2` - Nuclear charge numbers
+    /// * `distance` - Separation in meters
+    /// * `order` - Bond order b̄ (1 for the free diatomic)
+    pub fn pair_energy(&self, z1: u32, z2: u32, distance: f64, order: f64) -> Option<f64> {
+        let parameters = PairParameters::for_elements(z1, z2)?;
+        let r = distance / ANGSTROM;
+        let (switch, _) = parameters.switch(r);
+        let (repulsive, _, attractive, _) = self.pair_terms(&parameters, r);
+        Some(switch * (repulsive - order * attractive) * ELEMENTARY_CHARGE)
+    }
+
+    /// Energy, forces and bond orders of a set of atoms.
+    ///
+    /// # Returns
+    /// `None` if an element other than H, C, N or O is present.
+    pub fn evaluate(&self, atoms: &[ReactiveAtom]) -> Option<ReactiveEvaluation> {
+        let n = atoms.len();
+        let saturations: Vec<f64> = atoms.iter().map(|atom| saturation(atom.z)).collect::<Option<_>>()?;
+        let r: Vec<DVec3> = atoms.iter().map(|atom| atom.position / ANGSTROM).collect();
+
+        // Neighbour lists within the outer cutoff
+        let mut neighbours: Vec<Vec<Neighbour>> = (0..n).map(|_| Vec::new()).collect();
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let parameters = PairParameters::for_elements(atoms[i].z, atoms[j].z)?;
+                let displacement = r[j] - r[i];
+                let distance = displacement.length();
+                if distance >= parameters.cutoff().1 {
+                    continue;
+                }
+                let (switch, switch_slope) = parameters.switch(distance);
+                neighbours[i].push(Neighbour {
+                    index: j,
+                    distance,
+                    direction: displacement / distance,
+                    switch,
+                    switch_slope,
+                });
+            }
+        }
+
+        let mut energy = 0.0;
+        let mut gradient = vec![DVec3::ZERO; n];
+        let mut bonds = Vec::new();
+
+        for i in 0..n {
+            for pair in &neighbours[i] {
+                let j = pair.index;
+                if j < i {
+                    continue;
+                }
+                let parameters = PairParameters::for_elements(atoms[i].z, atoms[j].z)?;
+                let (repulsive, repulsive_slope, attractive, attractive_slope) =
+                    self.pair_terms(&parameters, pair.distance);
+
+                // Coordination of each end, not counting the partner
+                let zeta = |atom: usize, partner: usize| -> f64 {
+                    neighbours[atom].iter().filter(|k| k.index != partner).map(|k| k.switch).sum::<f64>()
+                        / saturations[atom]
+                };
+                let (b_ij, db_ij) = self.bond_order(zeta(i, j));
+                let (b_ji, db_ji) = self.bond_order(zeta(j, i));
+                let order = 0.5 * (b_ij + b_ji);
+
+                energy += pair.switch * (repulsive - order * attractive);
+                bonds.push(ReactiveBond { a: i, b: j, order });
+
+                // Radial part along the i-j bond
+                let slope = pair.switch_slope * (repulsive - order * attractive)
+                    + pair.switch * (repulsive_slope - order * attractive_slope);
+                gradient[j] += slope * pair.direction;
+                gradient[i] -= slope * pair.direction;
+
+                // The bond order depends on every other neighbour of i and of j
+                let weight = -0.5 * pair.switch * attractive;
+                for (atom, partner, db) in [(i, j, db_ij), (j, i, db_ji)] {
+                    if db == 0.0 {
+                        continue;
+                    }
+                    for k in neighbours[atom].iter().filter(|k| k.index != partner) {
+                        let g = weight * db * k.switch_slope / saturations[atom] * k.direction;
+                        gradient[k.index] += g;
+                        gradient[atom] -= g;
+                    }
+                }
+            }
+        }
+
+        Some(ReactiveEvaluation {
+            energy: energy * ELEMENTARY_CHARGE,
+            forces: gradient.iter().map(|g| -*g * ELEMENTARY_CHARGE / ANGSTROM).collect(),
+            bonds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn atom(z: u32, x: f64, y: f64, z_coord: f64) -> ReactiveAtom {
+        ReactiveAtom { z, position: DVec3::new(x, y, z_coord) * ANGSTROM }
+    }
+
+    fn energy_ev(atoms: &[ReactiveAtom]) -> f64 {
+        ReactiveBondOrder::default().evaluate(atoms).unwrap().energy / ELEMENTARY_CHARGE
+    }
+
+    /// Carbon with hydrogens at `length` Å along the given directions.
+    fn carbon_with(directions: &[DVec3], length: f64) -> Vec<ReactiveAtom> {
+        let mut atoms = vec![atom(6, 0.0, 0.0, 0.0)];
+        for d in directions {
+            let p = d.normalize() * length;
+            atoms.push(atom(1, p.x, p.y, p.z));
+        }
+        atoms
+    }
+
+    fn tetrahedron() -> Vec<DVec3> {
+        vec![
+            DVec3::new(1.0, 1.0, 1.0),
+            DVec3::new(1.0, -1.0, -1.0),
+            DVec3::new(-1.0, 1.0, -1.0),
+            DVec3::new(-1.0, -1.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn isolated_pair_is_the_free_diatomic() {
+        let potential = ReactiveBondOrder::default();
+        let h2 = [atom(1, 0.0, 0.0, 0.0), atom(1, 0.741, 0.0, 0.0)];
+        let evaluation = potential.evaluate(&h2).unwrap();
+
+        assert_relative_eq!(evaluation.energy / ELEMENTARY_CHARGE, -4.52, max_relative = 1e-9);
+        assert!(evaluation.forces[0].length() < 1e-20);
+        assert_relative_eq!(evaluation.bonds[0].order, 1.0);
+
+        // Beyond the cutoff the atoms do not see each other
+        let apart = [atom(1, 0.0, 0.0, 0.0), atom(1, 2.0, 0.0, 0.0)];
+        assert_eq!(energy_ev(&apart), 0.0);
+        assert_eq!(potential.pair_energy(1, 1, 2.0 * ANGSTROM, 1.0), Some(0.0));
+    }
+
+    #[test]
+    fn fifth_hydrogen_on_carbon_is_unbound() {
+        let length = 1.12;
+        let methane = carbon_with(&tetrahedron(), length);
+        let mut fifth = tetrahedron();
+        fifth.push(DVec3::new(1.0, 0.0, 0.0));
+        let ch5 = carbon_with(&fifth, length);
+
+        // CH₅ is worse than CH₄ plus a free H atom, but CH₄ beats CH₃ + H
+        assert!(energy_ev(&ch5) > energy_ev(&methane));
+        assert!(energy_ev(&methane) < energy_ev(&methane[..4]));
+
+        // Crowding weakens every C-H bond
+        let potential = ReactiveBondOrder::default();
+        let order = |atoms: &[ReactiveAtom]| potential.evaluate(atoms).unwrap().bonds[0].order;
+        assert!(order(&ch5) < order(&methane));
+    }
+
+    #[test]
+    fn hydrogen_is_monovalent() {
+        // Linear H₃ falls apart into H₂ + H
+        let h2 = [atom(1, 0.0, 0.0, 0.0), atom(1, 0.741, 0.0, 0.0)];
+        let h3 = [atom(1, -0.741, 0.0, 0.0), atom(1, 0.0, 0.0, 0.0), atom(1, 0.741, 0.0, 0.0)];
+        assert!(energy_ev(&h3) > energy_ev(&h2));
+
+        // Water holds both its hydrogens
+        let water = [atom(8, 0.0, 0.0, 0.0), atom(1, 0.97, 0.0, 0.0), atom(1, -0.24, 0.94, 0.0)];
+        assert!(energy_ev(&water) < energy_ev(&water[..2]));
+    }
+
+    #[test]
+    fn forces_are_minus_energy_gradient() {
+        // A crowded CH₅ with a hydrogen and a nearby oxygen inside their switching regions
+        let mut atoms = carbon_with(&tetrahedron(), 1.05);
+        atoms.push(atom(1, 1.62, 0.1, -0.2));
+        atoms.push(atom(8, -0.6, -0.6, 2.05));
+        let potential = ReactiveBondOrder::default();
+        let evaluation = potential.evaluate(&atoms).unwrap();
+        let h = 1e-6 * ANGSTROM;
+
+        for index in 0..atoms.len() {
+            for axis in [DVec3::X, DVec3::Y, DVec3::Z] {
+                let energy_at = |sign: f64| {
+                    let mut moved = atoms.clone();
+                    moved[index].position += sign * h * axis;
+                    potential.evaluate(&moved).unwrap().energy
+                };
+                let slope = (energy_at(1.0) - energy_at(-1.0)) / (2.0 * h);
+                assert_relative_eq!(evaluation.forces[index].dot(axis), -slope, epsilon = 1e-12, max_relative = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn unsupported_elements_are_reported() {
+        assert!(ReactiveBondOrder::default().evaluate(&[atom(11, 0.0, 0.0, 0.0)]).is_none());
+    }
+}
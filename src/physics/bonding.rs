@@ -0,0 +1,298 @@
+// Bond perception and molecule identification
+// Two atoms are bonded when they sit close compared with the sum of their
+// covalent radii. A bond forms below `form_factor`·(rₐ + r_b) and only
+// breaks again beyond the larger `break_factor`·(rₐ + r_b), so a bond
+// vibrating near the threshold does not flicker on and off.
+// Molecules are the connected components of the resulting bond graph.
+
+use std::collections::{BTreeMap, BTreeSet};
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::ANGSTROM;
+use super::ionization::element_symbol;
+
+/// Single-bond covalent radii in Å for Z = 1..=20 (Cordero et al. 2008).
+pub const COVALENT_RADII: [f64; 20] = [
+    0.31, 0.28, 1.28, 0.96, 0.84, 0.76, 0.71, 0.66, 0.57, 0.58,
+    1.66, 1.41, 1.21, 1.11, 1.07, 1.05, 1.02, 1.06, 2.03, 1.76,
+];
+
+/// Covalent radius in meters for nuclear charge number Z, if it is in the table.
+pub fn covalent_radius(z: u32) -> Option<f64> {
+    COVALENT_RADII.get((z as usize).checked_sub(1)?).map(|radius| radius * ANGSTROM)
+}
+
+/// Fired when two atoms become bonded.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BondFormed {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+/// Fired when a bond breaks (or one of its atoms disappears).
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BondBroken {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+/// An atom as seen by bond perception.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerceivedAtom {
+    pub entity: Entity,
+    /// Nuclear charge number Z
+    pub z: u32,
+    /// Position in meters
+    pub position: DVec3,
+}
+
+/// Distance thresholds for bond perception.
+#[derive(Resource, Debug, Clone)]
+pub struct BondPerception {
+    /// A bond forms below this multiple of the summed covalent radii
+    pub form_factor: f64,
+    /// An existing bond breaks beyond this multiple of the summed covalent radii
+    pub break_factor: f64,
+}
+
+impl Default for BondPerception {
+    fn default() -> Self {
+        Self {
+            // H₂ sits at 1.19 (rₐ + r_b), so forming needs some slack
+            form_factor: 1.3,
+            break_factor: 1.5,
+        }
+    }
+}
+
+/// Bonds formed and broken by one perception pass.
+#[derive(Debug, Clone, Default)]
+pub struct BondChanges {
+    pub formed: Vec<BondFormed>,
+    pub broken: Vec<BondBroken>,
+}
+
+/// A connected group of bonded atoms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Molecule {
+    /// Member atoms, in the order they were given
+    pub atoms: Vec<Entity>,
+    /// Hill-order formula, e.g. "CH4" or "H2O"
+    pub formula: String,
+}
+
+/// The current set of bonds between atoms.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BondGraph {
+    bonds: BTreeSet<(Entity, Entity)>,
+}
+
+fn key(a: Entity, b: Entity) -> (Entity, Entity) {
+    (a.min(b), a.max(b))
+}
+
+impl BondGraph {
+    /// Whether atoms a and b are bonded
+    pub fn are_bonded(&self, a: Entity, b: Entity) -> bool {
+        self.bonds.contains(&key(a, b))
+    }
+
+    /// All bonded pairs
+    pub fn bonds(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.bonds.iter().copied()
+    }
+
+    /// Number of bonds
+    pub fn len(&self) -> usize {
+        self.bonds.len()
+    }
+
+    /// Whether there are no bonds
+    pub fn is_empty(&self) -> bool {
+        self.bonds.is_empty()
+    }
+
+    /// Re-perceive bonds for the current positions.
+    ///
+    /// Atoms without a tabulated covalent radius never bond. Bonds to atoms
+    /// missing from `atoms` are broken.
+    pub fn update(&mut self, atoms: &[PerceivedAtom], perception: &BondPerception) -> BondChanges {
+        let mut changes = BondChanges::default();
+        let present: BTreeSet<Entity> = atoms.iter().map(|atom| atom.entity).collect();
+
+        self.bonds.retain(|&(a, b)| {
+            let keep = present.contains(&a) && present.contains(&b);
+            if !keep {
+                changes.broken.push(BondBroken { a, b });
+            }
+            keep
+        });
+
+        for (i, first) in atoms.iter().enumerate() {
+            let Some(r1) = covalent_radius(first.z) else { continue };
+            for second in &atoms[i + 1..] {
+                let Some(r2) = covalent_radius(second.z) else { continue };
+                let (a, b) = key(first.entity, second.entity);
+                let distance = first.position.distance(second.position);
+                let bonded = self.bonds.contains(&(a, b));
+
+                if !bonded && distance < perception.form_factor * (r1 + r2) {
+                    self.bonds.insert((a, b));
+                    changes.formed.push(BondFormed { a, b });
+                } else if bonded && distance > perception.break_factor * (r1 + r2) {
+                    self.bonds.remove(&(a, b));
+                    changes.broken.push(BondBroken { a, b });
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Split the atoms into molecules (connected components of the bond graph).
+    ///
+    /// Molecules are ordered by their first atom; lone atoms are molecules of one.
+    pub fn molecules(&self, atoms: &[PerceivedAtom]) -> Vec<Molecule> {
+        let index: BTreeMap<Entity, usize> = atoms.iter().enumerate().map(|(i, atom)| (atom.entity, i)).collect();
+        let mut neighbours = vec![Vec::new(); atoms.len()];
+        for (a, b) in self.bonds() {
+            if let (Some(&i), Some(&j)) = (index.get(&a), index.get(&b)) {
+                neighbours[i].push(j);
+                neighbours[j].push(i);
+            }
+        }
+
+        let mut seen = vec![false; atoms.len()];
+        let mut molecules = Vec::new();
+        for start in 0..atoms.len() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut members = vec![start];
+            let mut stack = vec![start];
+            while let Some(atom) = stack.pop() {
+                for &next in &neighbours[atom] {
+                    if !seen[next] {
+                        seen[next] = true;
+                        members.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            members.sort_unstable();
+            molecules.push(Molecule {
+                formula: hill_formula(members.iter().map(|&i| atoms[i].z)),
+                atoms: members.iter().map(|&i| atoms[i].entity).collect(),
+            });
+        }
+        molecules
+    }
+}
+
+/// Molecular formula in Hill order.
+///
+/// With carbon present: C first, then H, then the rest alphabetically.
+/// Without carbon every element, H included, is alphabetical.
+pub fn hill_formula(elements: impl IntoIterator<Item = u32>) -> String {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for z in elements {
+        let symbol = element_symbol(z).map(str::to_string).unwrap_or_else(|| format!("Z{z}"));
+        *counts.entry(symbol).or_default() += 1;
+    }
+
+    let mut order: Vec<(String, usize)> = Vec::new();
+    if let Some(carbon) = counts.remove("C") {
+        order.push(("C".to_string(), carbon));
+        if let Some(hydrogen) = counts.remove("H") {
+            order.push(("H".to_string(), hydrogen));
+        }
+    }
+    order.extend(counts);
+
+    order
+        .into_iter()
+        .map(|(symbol, count)| if count == 1 { symbol } else { format!("{symbol}{count}") })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn atom(id: u32, z: u32, x: f64, y: f64) -> PerceivedAtom {
+        PerceivedAtom { entity: Entity::from_raw(id), z, position: DVec3::new(x, y, 0.0) * ANGSTROM }
+    }
+
+    #[test]
+    fn hill_order() {
+        assert_eq!(hill_formula([8, 1, 1]), "H2O");
+        assert_eq!(hill_formula([1, 6, 1, 1, 1]), "CH4");
+        assert_eq!(hill_formula([6, 6, 8, 1, 1, 1, 1, 1, 1]), "C2H6O");
+        assert_eq!(hill_formula([11, 17]), "ClNa");
+        assert_eq!(hill_formula([7, 1, 1, 1]), "H3N");
+    }
+
+    #[test]
+    fn bonds_have_hysteresis() {
+        let perception = BondPerception::default();
+        let mut graph = BondGraph::default();
+        let (a, b) = (Entity::from_raw(0), Entity::from_raw(1));
+
+        // H₂ at its equilibrium length bonds
+        let changes = graph.update(&[atom(0, 1, 0.0, 0.0), atom(1, 1, 0.74, 0.0)], &perception);
+        assert_eq!(changes.formed, vec![BondFormed { a, b }]);
+
+        // Stretched between the thresholds: stays bonded, no events
+        let changes = graph.update(&[atom(0, 1, 0.0, 0.0), atom(1, 1, 0.88, 0.0)], &perception);
+        assert!(changes.formed.is_empty() && changes.broken.is_empty());
+        assert!(graph.are_bonded(b, a));
+
+        // A fresh pair at the same distance does not bond
+        let mut fresh = BondGraph::default();
+        fresh.update(&[atom(0, 1, 0.0, 0.0), atom(1, 1, 0.88, 0.0)], &perception);
+        assert!(fresh.is_empty());
+
+        // Pulled past the break threshold
+        let changes = graph.update(&[atom(0, 1, 0.0, 0.0), atom(1, 1, 1.0, 0.0)], &perception);
+        assert_eq!(changes.broken, vec![BondBroken { a, b }]);
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn molecules_are_connected_components() {
+        let atoms = [
+            // Water
+            atom(0, 8, 0.0, 0.0),
+            atom(1, 1, 0.96, 0.0),
+            atom(2, 1, -0.24, 0.93),
+            // Methane, written flat: only the C-H distances matter
+            atom(3, 6, 10.0, 0.0),
+            atom(4, 1, 11.09, 0.0),
+            atom(5, 1, 8.91, 0.0),
+            atom(6, 1, 10.0, 1.09),
+            atom(7, 1, 10.0, -1.09),
+            // A lone hydrogen atom
+            atom(8, 1, 5.0, 5.0),
+        ];
+        let mut graph = BondGraph::default();
+        let changes = graph.update(&atoms, &BondPerception::default());
+        assert_eq!(changes.formed.len(), 6);
+
+        let formulas: Vec<String> = graph.molecules(&atoms).into_iter().map(|m| m.formula).collect();
+        assert_eq!(formulas, ["H2O", "CH4", "H"]);
+    }
+
+    #[test]
+    fn removed_atoms_break_their_bonds() {
+        let perception = BondPerception::default();
+        let mut graph = BondGraph::default();
+        graph.update(&[atom(0, 8, 0.0, 0.0), atom(1, 1, 0.96, 0.0), atom(2, 1, -0.24, 0.93)], &perception);
+        assert_eq!(graph.len(), 2);
+
+        let changes = graph.update(&[atom(0, 8, 0.0, 0.0), atom(1, 1, 0.96, 0.0)], &perception);
+        assert_eq!(changes.broken.len(), 1);
+        assert_eq!(graph.molecules(&[atom(0, 8, 0.0, 0.0), atom(1, 1, 0.96, 0.0)])[0].formula, "HO");
+    }
+}
@@ -0,0 +1,370 @@
+// Runtime conservation monitoring
+// An isolated scene conserves
+//   E = K + U - W_in    (W_in: work injected by the user's spring or a thermostat)
+//   P = Σ m v,          L = Σ r × m v
+// so drift in any of them means the integrator or a force is misbehaving:
+// a time step too large for a close encounter, a force without its
+// reaction, an energy source nobody accounted for. The monitor compares
+// each reading with the first one and warns once when the deviation
+// crosses a threshold, and again only after it has recovered.
+// External fields and traps exert forces with no reaction, so momentum
+// checks can be switched off for scenes that use them.
+
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use glam::DVec3;
+use super::simulation::Integratable;
+
+/// The conserved quantities at one instant.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ConservationSnapshot {
+    /// Simulation time in seconds
+    pub time: f64,
+    /// Total kinetic energy in Joules
+    pub kinetic: f64,
+    /// Total potential energy in Joules
+    pub potential: f64,
+    /// Work injected from outside since monitoring began, in Joules
+    pub injected: f64,
+    /// Total linear momentum in kg⋅m/s
+    pub momentum: DVec3,
+    /// Total angular momentum about the origin in kg⋅m²/s
+    pub angular_momentum: DVec3,
+    /// Σ m|v|, the scale momentum drift is measured against
+    pub momentum_scale: f64,
+    /// Σ |r × m v|, the scale angular momentum drift is measured against
+    pub angular_scale: f64,
+}
+
+impl ConservationSnapshot {
+    /// Measure kinetic energy and momenta of (mass, position, velocity) triples
+    pub fn from_motion(time: f64, particles: impl IntoIterator<Item = (f64, DVec3, DVec3)>) -> Self {
+        let mut snapshot = Self { time, ..Self::default() };
+        for (mass, position, velocity) in particles {
+            let momentum = mass * velocity;
+            let angular = position.cross(momentum);
+            snapshot.kinetic += 0.5 * mass * velocity.length_squared();
+            snapshot.momentum += momentum;
+            snapshot.angular_momentum += angular;
+            snapshot.momentum_scale += momentum.length();
+            snapshot.angular_scale += angular.length();
+        }
+        snapshot
+    }
+
+    /// Measure a set of integrable particles
+    pub fn of<T: Integratable>(time: f64, particles: &[T]) -> Self {
+        Self::from_motion(time, particles.iter().map(|p| (p.mass(), p.position(), p.velocity())))
+    }
+
+    /// Set the potential energy in Joules
+    pub fn with_potential(mut self, potential: f64) -> Self {
+        self.potential = potential;
+        self
+    }
+
+    /// Set the injected work in Joules
+    pub fn with_injected(mut self, injected: f64) -> Self {
+        self.injected = injected;
+        self
+    }
+
+    /// K + U
+    pub fn total_energy(&self) -> f64 {
+        self.kinetic + self.potential
+    }
+
+    /// K + U - W_in: constant in a well-behaved run
+    pub fn energy_balance(&self) -> f64 {
+        self.total_energy() - self.injected
+    }
+}
+
+/// Which conservation law was broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConservedQuantity {
+    Energy,
+    Momentum,
+    AngularMomentum,
+}
+
+impl ConservedQuantity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConservedQuantity::Energy => "energy",
+            ConservedQuantity::Momentum => "momentum",
+            ConservedQuantity::AngularMomentum => "angular momentum",
+        }
+    }
+}
+
+/// Fired when a conserved quantity drifts past its threshold.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ConservationWarning {
+    pub quantity: ConservedQuantity,
+    /// Deviation from the first reading relative to its scale
+    pub relative_drift: f64,
+    /// Simulation time of the reading, in seconds
+    pub time: f64,
+}
+
+/// Relative drift thresholds; None switches a check off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConservationThresholds {
+    /// Allowed |ΔE| relative to the largest of |E|, K and W_in at the start and now
+    pub energy: Option<f64>,
+    /// Allowed |ΔP| relative to Σ m|v|
+    pub momentum: Option<f64>,
+    /// Allowed |ΔL| relative to Σ |r × m v|
+    pub angular_momentum: Option<f64>,
+}
+
+impl Default for ConservationThresholds {
+    fn default() -> Self {
+        Self {
+            energy: Some(0.01),
+            momentum: Some(0.01),
+            angular_momentum: Some(0.01),
+        }
+    }
+}
+
+/// Tracks conserved quantities over a run and flags drift.
+#[derive(Resource, Debug, Clone)]
+pub struct ConservationMonitor {
+    pub thresholds: ConservationThresholds,
+    /// Number of recent readings kept for drift rates
+    pub window: usize,
+    baseline: Option<ConservationSnapshot>,
+    history: VecDeque<ConservationSnapshot>,
+    injected: f64,
+    /// Quantities currently past their threshold (warned once until they recover)
+    tripped: [bool; 3],
+}
+
+impl Default for ConservationMonitor {
+    fn default() -> Self {
+        Self {
+            thresholds: ConservationThresholds::default(),
+            window: 120,
+            baseline: None,
+            history: VecDeque::new(),
+            injected: 0.0,
+            tripped: [false; 3],
+        }
+    }
+}
+
+/// Least-squares slope of y against t (0 with fewer than two distinct times)
+fn slope(points: impl Iterator<Item = (f64, f64)> + Clone) -> f64 {
+    let n = points.clone().count() as f64;
+    if n < 2.0 {
+        return 0.0;
+    }
+    let mean_t = points.clone().map(|(t, _)| t).sum::<f64>() / n;
+    let mean_y = points.clone().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = points.clone().map(|(t, y)| (t - mean_t) * (y - mean_y)).sum();
+    let variance: f64 = points.map(|(t, _)| (t - mean_t).powi(2)).sum();
+    if variance > 0.0 { covariance / variance } else { 0.0 }
+}
+
+impl ConservationMonitor {
+    /// Add work done on the system from outside (Joules; negative when energy is removed)
+    pub fn inject(&mut self, work: f64) {
+        self.injected += work;
+    }
+
+    /// Total work injected since monitoring began, in Joules
+    pub fn injected(&self) -> f64 {
+        self.injected
+    }
+
+    /// Forget the baseline and history, e.g. after the scene is edited
+    pub fn reset(&mut self) {
+        *self = Self { thresholds: self.thresholds, window: self.window, ..Self::default() };
+    }
+
+    /// The first reading, which drift is measured from
+    pub fn baseline(&self) -> Option<&ConservationSnapshot> {
+        self.baseline.as_ref()
+    }
+
+    /// The most recent reading
+    pub fn latest(&self) -> Option<&ConservationSnapshot> {
+        self.history.back()
+    }
+
+    /// Relative deviation of each quantity from the baseline, in the order
+    /// energy, momentum, angular momentum
+    pub fn relative_drift(&self) -> Option<[f64; 3]> {
+        let (first, last) = (self.baseline?, *self.latest()?);
+        let relative = |change: f64, scale: f64| if scale > 0.0 { change / scale } else { 0.0 };
+        let energy_scale = [first.total_energy().abs(), first.kinetic, last.total_energy().abs(), last.kinetic, last.injected.abs()]
+            .into_iter()
+            .fold(0.0, f64::max);
+        Some([
+            relative((last.energy_balance() - first.energy_balance()).abs(), energy_scale),
+            relative((last.momentum - first.momentum).length(), first.momentum_scale.max(last.momentum_scale)),
+            relative((last.angular_momentum - first.angular_momentum).length(), first.angular_scale.max(last.angular_scale)),
+        ])
+    }
+
+    /// Rate of change of K + U - W_in over the recent window, in J/s
+    pub fn energy_drift_rate(&self) -> f64 {
+        slope(self.history.iter().map(|s| (s.time, s.energy_balance())))
+    }
+
+    /// Magnitude of the rate of change of P over the recent window, in N
+    pub fn momentum_drift_rate(&self) -> f64 {
+        let rate = |axis: usize| slope(self.history.iter().map(move |s| (s.time, s.momentum[axis])));
+        DVec3::new(rate(0), rate(1), rate(2)).length()
+    }
+
+    /// Magnitude of the rate of change of L over the recent window, in N⋅m
+    pub fn angular_drift_rate(&self) -> f64 {
+        let rate = |axis: usize| slope(self.history.iter().map(move |s| (s.time, s.angular_momentum[axis])));
+        DVec3::new(rate(0), rate(1), rate(2)).length()
+    }
+
+    /// Take a reading.
+    ///
+    /// The snapshot's `injected` is replaced by the work passed to `inject`.
+    ///
+    /// # Returns
+    /// Warnings for quantities that have just crossed their threshold.
+    pub fn record(&mut self, snapshot: ConservationSnapshot) -> Vec<ConservationWarning> {
+        let snapshot = snapshot.with_injected(self.injected);
+        self.baseline.get_or_insert(snapshot);
+        self.history.push_back(snapshot);
+        while self.history.len() > self.window.max(2) {
+            self.history.pop_front();
+        }
+
+        let Some(drift) = self.relative_drift() else {
+            return Vec::new();
+        };
+        let limits = [self.thresholds.energy, self.thresholds.momentum, self.thresholds.angular_momentum];
+        let quantities = [ConservedQuantity::Energy, ConservedQuantity::Momentum, ConservedQuantity::AngularMomentum];
+
+        let mut warnings = Vec::new();
+        for i in 0..3 {
+            let exceeded = limits[i].is_some_and(|limit| drift[i] > limit);
+            if exceeded && !self.tripped[i] {
+                warnings.push(ConservationWarning { quantity: quantities[i], relative_drift: drift[i], time: snapshot.time });
+            }
+            self.tripped[i] = exceeded;
+        }
+        warnings
+    }
+
+    /// One line per quantity for an on-screen debug overlay
+    pub fn overlay_lines(&self) -> Vec<String> {
+        let (Some(latest), Some(drift)) = (self.latest(), self.relative_drift()) else {
+            return vec![String::from("Conservation: no readings")];
+        };
+        vec![
+            format!(
+                "K {:.3e} J  U {:.3e} J  W_in {:.3e} J  drift {:.2}%  ({:+.2e} J/s)",
+                latest.kinetic, latest.potential, latest.injected, 100.0 * drift[0], self.energy_drift_rate(),
+            ),
+            format!("|P| {:.3e} kg⋅m/s  drift {:.2}%  ({:.2e} N)", latest.momentum.length(), 100.0 * drift[1], self.momentum_drift_rate()),
+            format!("|L| {:.3e} kg⋅m²/s  drift {:.2}%  ({:.2e} N⋅m)", latest.angular_momentum.length(), 100.0 * drift[2], self.angular_drift_rate()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::particles::proton::Proton;
+    use crate::physics::constants::{BOHR_RADIUS, COULOMB_CONSTANT, ELEMENTARY_CHARGE, PROTON_MASS};
+    use crate::physics::coulomb::coulomb_force;
+    use crate::physics::simulation::{verlet_position_step, verlet_velocity_step};
+    use approx::assert_relative_eq;
+
+    /// Two protons flying apart from rest, integrated with Velocity Verlet
+    fn repelling_pair(steps: usize, dt: f64, monitor: &mut ConservationMonitor) -> Vec<ConservationWarning> {
+        let mut pair = [Proton::new(DVec3::ZERO), Proton::new(DVec3::new(2.0 * BOHR_RADIUS, BOHR_RADIUS, 0.0))];
+        let measure = |pair: &[Proton; 2], time: f64| {
+            let distance = pair[0].position.distance(pair[1].position);
+            ConservationSnapshot::of(time, pair).with_potential(COULOMB_CONSTANT * ELEMENTARY_CHARGE.powi(2) / distance)
+        };
+        let apply_forces = |pair: &mut [Proton; 2]| {
+            let force = coulomb_force(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, pair[0].position, pair[1].position);
+            pair[0].force = force;
+            pair[1].force = -force;
+        };
+
+        let mut warnings = monitor.record(measure(&pair, 0.0));
+        apply_forces(&mut pair);
+        for step in 1..=steps {
+            let accelerations: Vec<DVec3> = pair.iter_mut().map(|p| verlet_position_step(p, dt)).collect();
+            apply_forces(&mut pair);
+            for (p, a) in pair.iter_mut().zip(accelerations) {
+                verlet_velocity_step(p, a, dt);
+            }
+            warnings.extend(monitor.record(measure(&pair, step as f64 * dt)));
+        }
+        warnings
+    }
+
+    #[test]
+    fn snapshot_sums() {
+        let mut a = Proton::new(DVec3::X);
+        let mut b = Proton::new(-DVec3::X);
+        a.velocity = DVec3::Y * 10.0;
+        b.velocity = -DVec3::Y * 10.0;
+        let snapshot = ConservationSnapshot::of(0.0, &[a, b]).with_potential(-1.0e-26);
+
+        assert_eq!(snapshot.momentum, DVec3::ZERO);
+        // Both orbit the origin the same way round
+        assert_relative_eq!(snapshot.angular_momentum.z, 2.0 * PROTON_MASS * 10.0, max_relative = 1e-12);
+        assert_relative_eq!(snapshot.kinetic, PROTON_MASS * 100.0, max_relative = 1e-12);
+        assert_relative_eq!(snapshot.total_energy(), PROTON_MASS * 100.0 - 1.0e-26, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn careful_integration_raises_no_warnings() {
+        let mut monitor = ConservationMonitor::default();
+        let warnings = repelling_pair(2000, 1.0e-18, &mut monitor);
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let drift = monitor.relative_drift().unwrap();
+        assert!(drift.iter().all(|&d| d < 1e-4), "{drift:?}");
+        // Potential energy has turned into kinetic energy
+        assert!(monitor.latest().unwrap().kinetic > 0.1 * monitor.baseline().unwrap().potential);
+    }
+
+    #[test]
+    fn a_coarse_time_step_is_flagged_once() {
+        let mut monitor = ConservationMonitor::default();
+        let warnings = repelling_pair(40, 1.0e-15, &mut monitor);
+
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert_eq!(warnings[0].quantity, ConservedQuantity::Energy);
+        assert!(warnings[0].relative_drift > 0.01);
+        assert!(monitor.energy_drift_rate().abs() > 0.0);
+    }
+
+    #[test]
+    fn injected_work_is_accounted_for() {
+        let mut monitor = ConservationMonitor::default();
+        let mut particle = Proton::new(DVec3::ZERO);
+        monitor.record(ConservationSnapshot::of(0.0, std::slice::from_ref(&particle)));
+
+        // A push from outside adds kinetic energy that the monitor was told about
+        particle.velocity = DVec3::X * 1000.0;
+        monitor.inject(0.5 * PROTON_MASS * 1.0e6);
+        let mut warnings = monitor.record(ConservationSnapshot::of(1.0, std::slice::from_ref(&particle)));
+        assert!(warnings.iter().all(|w| w.quantity != ConservedQuantity::Energy), "{warnings:?}");
+
+        // An unreported push is an energy and momentum violation
+        particle.velocity *= 2.0;
+        warnings = monitor.record(ConservationSnapshot::of(2.0, std::slice::from_ref(&particle)));
+        assert!(warnings.iter().any(|w| w.quantity == ConservedQuantity::Energy));
+        assert_eq!(monitor.overlay_lines().len(), 3);
+
+        monitor.reset();
+        assert!(monitor.baseline().is_none() && monitor.injected() == 0.0);
+    }
+}
@@ -32,9 +32,31 @@ pub const HBAR: f64 = 1.054_571_817e-34;
 /// Speed of light in vacuum (meters per second)
 pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
 
+/// Hartree energy (Joules)
+/// The atomic unit of energy, E_h = ke²/a₀ (twice the hydrogen ionization energy)
+pub const HARTREE_ENERGY: f64 = 4.359_744_722_207_1e-18;
+
 /// One Ångström in meters (convenient for atomic scales)
 pub const ANGSTROM: f64 = 1.0e-10;
 
+/// Boltzmann constant (Joules per Kelvin)
+pub const BOLTZMANN_CONSTANT: f64 = 1.380_649e-23;
+
+/// Avogadro constant (per mole)
+pub const AVOGADRO_CONSTANT: f64 = 6.022_140_76e23;
+
+/// Atomic mass unit (kilograms)
+/// One twelfth of the mass of a carbon-12 atom
+pub const ATOMIC_MASS_UNIT: f64 = 1.660_539_066_60e-27;
+
+/// One kilocalorie per mole, per molecule (Joules)
+/// The energy unit of most force-field parameter tables
+pub const KILOCALORIE_PER_MOLE: f64 = 4184.0 / AVOGADRO_CONSTANT;
+
+/// One Debye in Coulomb-meters (unit of molecular dipole moment)
+/// 1 D = 10⁻²¹/c C⋅m ≈ 0.2082 e⋅Å
+pub const DEBYE: f64 = 3.335_640_952e-30;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +76,12 @@ mod tests {
         let relative_error = (derived_hbar - HBAR).abs() / HBAR;
         assert!(relative_error < 1e-9, "ℏ derivation error: {}", relative_error);
     }
+
+    #[test]
+    fn hartree_derived_correctly() {
+        // E_h = k e² / a₀
+        let derived = COULOMB_CONSTANT * ELEMENTARY_CHARGE.powi(2) / BOHR_RADIUS;
+        let relative_error = (derived - HARTREE_ENERGY).abs() / HARTREE_ENERGY;
+        assert!(relative_error < 1e-9, "Hartree derivation error: {}", relative_error);
+    }
 }
@@ -1,8 +1,10 @@
 // Coulomb force calculation
 // F = k * q1 * q2 / r²
+// Gaussian charge clouds: V = k * q1 * q2 * erf(r / √(2(σ₁² + σ₂²))) / r
 
 use glam::DVec3;
 use super::constants::COULOMB_CONSTANT;
+use crate::math::special::erf;
 
 /// Calculate the Coulomb force between two point charges.
 ///
@@ -48,6 +50,184 @@ pub fn coulomb_force_magnitude(q1: f64, q2: f64, distance: f64) -> f64 {
     COULOMB_CONSTANT * q1 * q2 / (distance * distance)
 }
 
+/// Calculate the Coulomb force between two spherical Gaussian charge distributions.
+///
+/// Each charge is spread as ρ(r) ∝ e^(-r²/2σ²). A point charge (such as a
+/// nucleus) is the limit `width = 0`. Overlapping clouds interact through
+/// V(r) = k q1 q2 erf(αr)/r with α = 1/√(2(σ₁² + σ₂²)), which is finite at
+/// r = 0, so the force smoothly goes to zero instead of diverging.
+///
+/// # Arguments
+/// * `q1` - First charge in Coulombs
+/// * `q2` - Second charge in Coulombs
+/// * `r1` - Centre of first charge in meters
+/// * `r2` - Centre of second charge in meters
+/// * `width1` - Gaussian width σ of the first charge in meters
+/// * `width2` - Gaussian width σ of the second charge in meters
+///
+/// # Returns
+/// Force vector on charge 1 due to charge 2, in Newtons.
+/// Zero when the centres coincide.
+pub fn gaussian_coulomb_force(
+    q1: f64,
+    q2: f64,
+    r1: DVec3,
+    r2: DVec3,
+    width1: f64,
+    width2: f64,
+) -> DVec3 {
+    let displacement = r1 - r2;
+    let distance = displacement.length();
+
+    if distance == 0.0 {
+        // By symmetry the overlapping clouds pull equally in every direction
+        return DVec3::ZERO;
+    }
+
+    let magnitude = gaussian_coulomb_force_magnitude(q1, q2, distance, width1, width2);
+    let direction = displacement / distance; // unit vector
+
+    direction * magnitude
+}
+
+/// Calculate the magnitude of the Coulomb force between two Gaussian charges.
+///
+/// F(r) = -dV/dr = k q1 q2 [erf(αr)/r² - (2α/√π) e^(-α²r²)/r]
+///
+/// Falls back to the point-charge law when both widths are zero.
+///
+/// # Returns
+/// Magnitude of force in Newtons. Positive for repulsion, negative for attraction.
+pub fn gaussian_coulomb_force_magnitude(
+    q1: f64,
+    q2: f64,
+    distance: f64,
+    width1: f64,
+    width2: f64,
+) -> f64 {
+    let combined_width = (width1 * width1 + width2 * width2).sqrt();
+    if combined_width == 0.0 {
+        return coulomb_force_magnitude(q1, q2, distance);
+    }
+
+    let alpha = 1.0 / (std::f64::consts::SQRT_2 * combined_width);
+
+    // Substituting x = αr: F = k q1 q2 α² [erf(x)/x² - (2/√π) e^(-x²)/x]
+    COULOMB_CONSTANT * q1 * q2 * alpha * alpha * gaussian_force_shape(alpha * distance)
+}
+
+/// Calculate the electrostatic potential energy of two Gaussian charges.
+///
+/// V(r) = k q1 q2 erf(αr)/r, with the finite limit V(0) = k q1 q2 2α/√π.
+///
+/// # Returns
+/// Potential energy in Joules. Positive for repulsion, negative for attraction.
+pub fn gaussian_coulomb_potential(
+    q1: f64,
+    q2: f64,
+    distance: f64,
+    width1: f64,
+    width2: f64,
+) -> f64 {
+    let combined_width = (width1 * width1 + width2 * width2).sqrt();
+    if combined_width == 0.0 {
+        assert!(distance > 0.0, "Cannot calculate Coulomb potential at zero distance (singularity)");
+        return COULOMB_CONSTANT * q1 * q2 / distance;
+    }
+
+    let alpha = 1.0 / (std::f64::consts::SQRT_2 * combined_width);
+
+    if distance == 0.0 {
+        return COULOMB_CONSTANT * q1 * q2 * 2.0 * alpha / std::f64::consts::PI.sqrt();
+    }
+
+    COULOMB_CONSTANT * q1 * q2 * erf(alpha * distance) / distance
+}
+
+/// A charged particle as a source of electric field.
+///
+/// Point charges have `width = 0`; electron clouds use their Gaussian width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SourceCharge {
+    /// Centre of the charge in meters
+    pub position: DVec3,
+    /// Charge in Coulombs
+    pub charge: f64,
+    /// Gaussian width σ in meters (0 for a point charge)
+    pub width: f64,
+}
+
+impl SourceCharge {
+    /// A point charge
+    pub fn point(position: DVec3, charge: f64) -> Self {
+        Self { position, charge, width: 0.0 }
+    }
+
+    /// A spherical Gaussian charge cloud
+    pub fn gaussian(position: DVec3, charge: f64, width: f64) -> Self {
+        Self { position, charge, width }
+    }
+}
+
+/// Calculate the electric field at a point due to all source charges.
+///
+/// E(r) = Σ k qᵢ (r - rᵢ)/|r - rᵢ|³, smoothed inside Gaussian clouds.
+/// A point charge contributes nothing at its own position.
+///
+/// # Returns
+/// Field vector in V/m (N/C).
+pub fn electric_field(point: DVec3, charges: &[SourceCharge]) -> DVec3 {
+    charges
+        .iter()
+        .map(|source| gaussian_coulomb_force(1.0, source.charge, point, source.position, 0.0, source.width))
+        .sum()
+}
+
+/// Calculate the electric potential at a point due to all source charges.
+///
+/// φ(r) = Σ k qᵢ erf(αᵢ|r - rᵢ|)/|r - rᵢ|, which is k qᵢ/|r - rᵢ| for point charges.
+///
+/// # Returns
+/// Potential in Volts; infinite on top of a point charge.
+pub fn electric_potential(point: DVec3, charges: &[SourceCharge]) -> f64 {
+    charges
+        .iter()
+        .map(|source| {
+            let distance = point.distance(source.position);
+            if distance == 0.0 && source.width == 0.0 {
+                return source.charge.signum() * f64::INFINITY;
+            }
+            gaussian_coulomb_potential(1.0, source.charge, distance, 0.0, source.width)
+        })
+        .sum()
+}
+
+/// Dimensionless force shape f(x) = erf(x)/x² - (2/√π) e^(-x²)/x.
+///
+/// The two terms cancel as x → 0, so small arguments use the series
+/// f(x) = (2/√π) Σ (-1)ⁿ⁺¹ 2n x²ⁿ⁻¹ / (n!(2n+1)) = (2/√π)(2x/3 - 2x³/5 + ...).
+fn gaussian_force_shape(x: f64) -> f64 {
+    let two_over_sqrt_pi = std::f64::consts::FRAC_2_SQRT_PI;
+
+    if x < 0.5 {
+        let x2 = x * x;
+        let mut sum = 0.0;
+        let mut power = x; // x^(2n-1)
+        let mut factorial = 1.0; // n!
+        let mut sign = 1.0;
+        for n in 1..=10 {
+            let n = n as f64;
+            factorial *= n;
+            sum += sign * 2.0 * n * power / (factorial * (2.0 * n + 1.0));
+            power *= x2;
+            sign = -sign;
+        }
+        return two_over_sqrt_pi * sum;
+    }
+
+    erf(x) / (x * x) - two_over_sqrt_pi * (-x * x).exp() / x
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +323,139 @@ mod tests {
         // Singularity at r=0 should panic
         coulomb_force_magnitude(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, 0.0);
     }
+
+    #[test]
+    fn gaussian_force_matches_point_charge_far_away() {
+        // Beyond a few widths the clouds look like point charges
+        let width = 0.1 * ANGSTROM;
+        let distance = 2.0 * ANGSTROM;
+
+        let point = coulomb_force_magnitude(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, distance);
+        let smeared = gaussian_coulomb_force_magnitude(
+            ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, distance, 0.0, width,
+        );
+
+        assert_relative_eq!(smeared, point, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn gaussian_force_vanishes_at_zero_separation() {
+        // No singularity: a nucleus sitting at the cloud centre feels no net force
+        let force = gaussian_coulomb_force(
+            ELEMENTARY_CHARGE,
+            -ELEMENTARY_CHARGE,
+            DVec3::ZERO,
+            DVec3::ZERO,
+            0.0,
+            ANGSTROM,
+        );
+        assert_eq!(force, DVec3::ZERO);
+
+        let near = gaussian_coulomb_force_magnitude(
+            ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, 1e-6 * ANGSTROM, 0.0, ANGSTROM,
+        );
+        assert!(near.is_finite());
+        assert!(near.abs() < 1e-14, "Force should vanish near the centre, got {}", near);
+    }
+
+    #[test]
+    fn gaussian_force_is_linear_inside_the_cloud() {
+        // Near the centre, F ≈ k q1 q2 (4α³ / 3√π) r, like a uniform ball of charge
+        let width = ANGSTROM;
+        let alpha = 1.0 / (std::f64::consts::SQRT_2 * width);
+        let r = 0.01 * ANGSTROM;
+
+        let expected = COULOMB_CONSTANT * ELEMENTARY_CHARGE * ELEMENTARY_CHARGE
+            * 4.0 * alpha.powi(3) / (3.0 * std::f64::consts::PI.sqrt()) * r;
+        let actual = gaussian_coulomb_force_magnitude(
+            ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, r, width, 0.0,
+        );
+
+        assert_relative_eq!(actual, expected, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn gaussian_force_is_negative_gradient_of_potential() {
+        // Check F = -dV/dr with a central difference, across the series/erf switch
+        let (w1, w2) = (0.6 * ANGSTROM, 0.8 * ANGSTROM);
+        let q1 = -ELEMENTARY_CHARGE;
+        let q2 = -ELEMENTARY_CHARGE;
+
+        for &r in &[0.2 * ANGSTROM, 0.7 * ANGSTROM, 1.5 * ANGSTROM, 4.0 * ANGSTROM] {
+            let h = 1e-5 * ANGSTROM;
+            let dv = gaussian_coulomb_potential(q1, q2, r + h, w1, w2)
+                - gaussian_coulomb_potential(q1, q2, r - h, w1, w2);
+            let numeric = -dv / (2.0 * h);
+            let analytic = gaussian_coulomb_force_magnitude(q1, q2, r, w1, w2);
+            assert_relative_eq!(analytic, numeric, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn gaussian_potential_is_finite_at_overlap() {
+        // V(0) = k q1 q2 · 2α/√π
+        let width = ANGSTROM;
+        let alpha = 1.0 / (std::f64::consts::SQRT_2 * width);
+        let expected = -COULOMB_CONSTANT * ELEMENTARY_CHARGE * ELEMENTARY_CHARGE
+            * 2.0 * alpha / std::f64::consts::PI.sqrt();
+
+        let at_zero = gaussian_coulomb_potential(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, 0.0, 0.0, width);
+        let nearby = gaussian_coulomb_potential(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, 1e-8 * ANGSTROM, 0.0, width);
+
+        assert_relative_eq!(at_zero, expected, max_relative = 1e-12);
+        assert_relative_eq!(nearby, at_zero, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn gaussian_forces_obey_newtons_third_law() {
+        let a = DVec3::new(0.3, -0.2, 0.1) * ANGSTROM;
+        let b = DVec3::new(-0.4, 0.5, 0.0) * ANGSTROM;
+        let (wa, wb) = (0.5 * ANGSTROM, 0.9 * ANGSTROM);
+
+        let on_a = gaussian_coulomb_force(-ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, a, b, wa, wb);
+        let on_b = gaussian_coulomb_force(-ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, b, a, wb, wa);
+
+        assert_relative_eq!(on_a.x, -on_b.x, max_relative = 1e-12);
+        assert_relative_eq!(on_a.y, -on_b.y, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn zero_width_reduces_to_point_charges() {
+        let r1 = DVec3::new(ANGSTROM, 0.0, 0.0);
+        let point = coulomb_force(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, r1, DVec3::ZERO);
+        let smeared = gaussian_coulomb_force(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, r1, DVec3::ZERO, 0.0, 0.0);
+        assert_eq!(point, smeared);
+    }
+
+    #[test]
+    fn field_and_potential_of_point_charge() {
+        let proton = SourceCharge::point(DVec3::ZERO, ELEMENTARY_CHARGE);
+        let point = DVec3::new(0.0, ANGSTROM, 0.0);
+
+        // |E| = ke/r² ≈ 1.44e11 V/m, pointing away from the proton
+        let field = electric_field(point, &[proton]);
+        assert_relative_eq!(field.y, COULOMB_CONSTANT * ELEMENTARY_CHARGE / (ANGSTROM * ANGSTROM), max_relative = 1e-12);
+        assert_eq!(field.x, 0.0);
+
+        // φ = ke/r ≈ 14.4 V
+        assert_relative_eq!(electric_potential(point, &[proton]), 14.399_645, max_relative = 1e-6);
+        assert_eq!(electric_potential(DVec3::ZERO, &[proton]), f64::INFINITY);
+    }
+
+    #[test]
+    fn field_superposes_and_cancels() {
+        let pair = [
+            SourceCharge::point(DVec3::new(-ANGSTROM, 0.0, 0.0), ELEMENTARY_CHARGE),
+            SourceCharge::point(DVec3::new(ANGSTROM, 0.0, 0.0), ELEMENTARY_CHARGE),
+        ];
+        assert_relative_eq!(electric_field(DVec3::ZERO, &pair).length(), 0.0, epsilon = 1e-3);
+
+        // Field is minus the gradient of the potential
+        let cloud = [SourceCharge::gaussian(DVec3::ZERO, -ELEMENTARY_CHARGE, 0.5 * ANGSTROM), pair[0]];
+        let point = DVec3::new(0.3 * ANGSTROM, 0.4 * ANGSTROM, 0.0);
+        let h = 1e-4 * ANGSTROM;
+        let gradient_x = (electric_potential(point + DVec3::X * h, &cloud)
+            - electric_potential(point - DVec3::X * h, &cloud)) / (2.0 * h);
+        assert_relative_eq!(electric_field(point, &cloud).x, -gradient_x, max_relative = 1e-6);
+    }
 }
@@ -0,0 +1,362 @@
+// Potential energy curves
+// Samples V(r) and F(r) = -dV/dr of any pair interaction over a distance
+// range for plotting. Sampling starts on a uniform grid and bisects every
+// interval whose midpoint strays from the straight line between its ends,
+// so steep walls and tight wells get more points than the flat tail.
+// The curve is annotated with its wells (F changes from + to - going
+// outward), barriers (F changes from - to +) and zero crossings of V,
+// each refined by bisection.
+
+use super::coulomb::{gaussian_coulomb_force_magnitude, gaussian_coulomb_potential};
+
+/// A pair interaction as a function of separation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PairPotential {
+    /// Point or Gaussian charges (widths in meters, 0 for point charges)
+    Coulomb { q1: f64, q2: f64, width1: f64, width2: f64 },
+    /// V = 4ε [(σ/r)¹² - (σ/r)⁶], σ in meters, ε in Joules
+    LennardJones { sigma: f64, epsilon: f64 },
+    /// V = D [(1 - e^(-a(r - rₑ)))² - 1], zero at infinity;
+    /// depth D in Joules, length rₑ in meters, range a in 1/m
+    Morse { depth: f64, length: f64, range: f64 },
+    /// Sum of several interactions
+    Composite(Vec<PairPotential>),
+}
+
+impl PairPotential {
+    /// Two point charges in Coulombs
+    pub fn coulomb(q1: f64, q2: f64) -> Self {
+        PairPotential::Coulomb { q1, q2, width1: 0.0, width2: 0.0 }
+    }
+
+    /// Potential energy in Joules at separation r (meters)
+    pub fn energy(&self, r: f64) -> f64 {
+        match self {
+            PairPotential::Coulomb { q1, q2, width1, width2 } => {
+                gaussian_coulomb_potential(*q1, *q2, r, *width1, *width2)
+            }
+            PairPotential::LennardJones { sigma, epsilon } => {
+                let six = (sigma / r).powi(6);
+                4.0 * epsilon * (six * six - six)
+            }
+            PairPotential::Morse { depth, length, range } => {
+                let decay = (-range * (r - length)).exp();
+                depth * ((1.0 - decay).powi(2) - 1.0)
+            }
+            PairPotential::Composite(terms) => terms.iter().map(|term| term.energy(r)).sum(),
+        }
+    }
+
+    /// Force F = -dV/dr in Newtons; positive for repulsion, negative for attraction
+    pub fn force(&self, r: f64) -> f64 {
+        match self {
+            PairPotential::Coulomb { q1, q2, width1, width2 } => {
+                gaussian_coulomb_force_magnitude(*q1, *q2, r, *width1, *width2)
+            }
+            PairPotential::LennardJones { sigma, epsilon } => {
+                let six = (sigma / r).powi(6);
+                24.0 * epsilon * (2.0 * six * six - six) / r
+            }
+            PairPotential::Morse { depth, length, range } => {
+                let decay = (-range * (r - length)).exp();
+                -2.0 * depth * range * decay * (1.0 - decay)
+            }
+            PairPotential::Composite(terms) => terms.iter().map(|term| term.force(r)).sum(),
+        }
+    }
+}
+
+/// Sampling settings.
+#[derive(Debug, Clone)]
+pub struct CurveConfig {
+    /// Points on the starting uniform grid
+    pub initial_samples: usize,
+    /// Allowed deviation from linear interpolation, as a fraction of the
+    /// energy span of the starting grid
+    pub tolerance: f64,
+    /// Maximum number of times an interval may be halved
+    pub max_depth: u32,
+}
+
+impl Default for CurveConfig {
+    fn default() -> Self {
+        Self {
+            initial_samples: 33,
+            tolerance: 1e-3,
+            max_depth: 8,
+        }
+    }
+}
+
+/// One point of a sampled curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveSample {
+    /// Separation in meters
+    pub distance: f64,
+    /// V(r) in Joules
+    pub energy: f64,
+    /// F(r) in Newtons
+    pub force: f64,
+}
+
+/// Kind of annotated point on a curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureKind {
+    /// Local minimum of V (a well; a bond length)
+    Minimum,
+    /// Local maximum of V (a barrier)
+    Barrier,
+    /// V = 0
+    ZeroCrossing,
+}
+
+/// An annotated point on a curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveFeature {
+    pub kind: FeatureKind,
+    /// Separation in meters
+    pub distance: f64,
+    /// V at that separation in Joules
+    pub energy: f64,
+}
+
+/// What a pointer over the plot is touching.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveHit {
+    /// An annotated feature, by index into `features`
+    Feature(usize),
+    /// The curve itself, at the nearest sampled point
+    Curve(CurveSample),
+}
+
+/// A sampled, annotated potential energy curve.
+#[derive(Debug, Clone)]
+pub struct PotentialCurve {
+    /// Samples in order of increasing distance
+    pub samples: Vec<CurveSample>,
+    /// Features in order of increasing distance
+    pub features: Vec<CurveFeature>,
+}
+
+/// Bisect a sign change of f between a and b (f(a) and f(b) of opposite sign).
+fn bisect(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64 {
+    let mut fa = f(a);
+    for _ in 0..100 {
+        let mid = 0.5 * (a + b);
+        if mid <= a || mid >= b {
+            break;
+        }
+        let fm = f(mid);
+        if (fm > 0.0) == (fa > 0.0) {
+            (a, fa) = (mid, fm);
+        } else {
+            b = mid;
+        }
+    }
+    0.5 * (a + b)
+}
+
+/// Sample a pair potential between two separations.
+///
+/// # Arguments
+/// * `potential` - The interaction
+/// * `r_min`, `r_max` - Distance range in meters (r_min > 0 for point charges)
+/// * `config` - Resolution settings
+pub fn sample_curve(potential: &PairPotential, r_min: f64, r_max: f64, config: &CurveConfig) -> PotentialCurve {
+    assert!(r_min < r_max && config.initial_samples >= 2, "Need a range and at least two samples");
+    let sample = |distance: f64| CurveSample {
+        distance,
+        energy: potential.energy(distance),
+        force: potential.force(distance),
+    };
+
+    let count = config.initial_samples;
+    let grid: Vec<CurveSample> = (0..count)
+        .map(|i| sample(r_min + (r_max - r_min) * i as f64 / (count - 1) as f64))
+        .collect();
+    let (low, high) = grid.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), s| {
+        (lo.min(s.energy), hi.max(s.energy))
+    });
+    let tolerance = config.tolerance * (high - low).max(f64::MIN_POSITIVE);
+
+    // Refine each starting interval depth-first so samples stay ordered
+    let mut samples = vec![grid[0]];
+    for pair in grid.windows(2) {
+        let mut stack = vec![(pair[0], pair[1], 0)];
+        while let Some((left, right, depth)) = stack.pop() {
+            let middle = sample(0.5 * (left.distance + right.distance));
+            let linear = 0.5 * (left.energy + right.energy);
+            if depth < config.max_depth && (middle.energy - linear).abs() > tolerance {
+                stack.push((middle, right, depth + 1));
+                stack.push((left, middle, depth + 1));
+            } else {
+                samples.push(right);
+            }
+        }
+    }
+
+    let mut features = Vec::new();
+    for pair in samples.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if a.force > 0.0 && b.force <= 0.0 {
+            let r = bisect(|r| potential.force(r), a.distance, b.distance);
+            features.push(CurveFeature { kind: FeatureKind::Minimum, distance: r, energy: potential.energy(r) });
+        } else if a.force < 0.0 && b.force >= 0.0 {
+            let r = bisect(|r| potential.force(r), a.distance, b.distance);
+            features.push(CurveFeature { kind: FeatureKind::Barrier, distance: r, energy: potential.energy(r) });
+        }
+        if (a.energy > 0.0) != (b.energy > 0.0) {
+            let r = bisect(|r| potential.energy(r), a.distance, b.distance);
+            features.push(CurveFeature { kind: FeatureKind::ZeroCrossing, distance: r, energy: 0.0 });
+        }
+    }
+    features.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+    PotentialCurve { samples, features }
+}
+
+impl PotentialCurve {
+    /// The deepest well, if the curve has one
+    pub fn minimum(&self) -> Option<CurveFeature> {
+        self.features
+            .iter()
+            .filter(|f| f.kind == FeatureKind::Minimum)
+            .min_by(|a, b| a.energy.total_cmp(&b.energy))
+            .copied()
+    }
+
+    /// The highest barrier, if the curve has one
+    pub fn barrier(&self) -> Option<CurveFeature> {
+        self.features
+            .iter()
+            .filter(|f| f.kind == FeatureKind::Barrier)
+            .max_by(|a, b| a.energy.total_cmp(&b.energy))
+            .copied()
+    }
+
+    /// The first zero crossing of V, if any
+    pub fn zero_crossing(&self) -> Option<CurveFeature> {
+        self.features.iter().find(|f| f.kind == FeatureKind::ZeroCrossing).copied()
+    }
+
+    /// V at a separation by linear interpolation between samples (None outside the range)
+    pub fn energy_at(&self, distance: f64) -> Option<f64> {
+        let index = self.samples.partition_point(|s| s.distance < distance);
+        if index == 0 {
+            return (self.samples.first()?.distance == distance).then(|| self.samples[0].energy);
+        }
+        let (a, b) = (self.samples[index - 1], *self.samples.get(index)?);
+        let t = (distance - a.distance) / (b.distance - a.distance);
+        Some(a.energy + t * (b.energy - a.energy))
+    }
+
+    /// Find what a pointer at (distance, energy) is over.
+    ///
+    /// Features win over the plain curve. A point counts as touching when it
+    /// is within `distance_tolerance` and `energy_tolerance` of the target
+    /// in both directions, which the UI derives from its pixel scale.
+    pub fn hit_test(&self, distance: f64, energy: f64, distance_tolerance: f64, energy_tolerance: f64) -> Option<CurveHit> {
+        let near = |r: f64, e: f64| {
+            let (dr, de) = ((r - distance) / distance_tolerance, (e - energy) / energy_tolerance);
+            (dr.abs() <= 1.0 && de.abs() <= 1.0).then_some(dr * dr + de * de)
+        };
+
+        let feature = self
+            .features
+            .iter()
+            .enumerate()
+            .filter_map(|(i, f)| near(f.distance, f.energy).map(|d| (i, d)))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((index, _)) = feature {
+            return Some(CurveHit::Feature(index));
+        }
+
+        self.samples
+            .iter()
+            .filter_map(|s| near(s.distance, s.energy).map(|d| (*s, d)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(sample, _)| CurveHit::Curve(sample))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+    use approx::assert_relative_eq;
+
+    const EV: f64 = ELEMENTARY_CHARGE;
+
+    fn argon() -> PairPotential {
+        PairPotential::LennardJones { sigma: 3.4 * ANGSTROM, epsilon: 0.0104 * EV }
+    }
+
+    #[test]
+    fn lennard_jones_features() {
+        let curve = sample_curve(&argon(), 3.0 * ANGSTROM, 10.0 * ANGSTROM, &CurveConfig::default());
+        let minimum = curve.minimum().unwrap();
+
+        assert_relative_eq!(minimum.distance, 2.0_f64.powf(1.0 / 6.0) * 3.4 * ANGSTROM, max_relative = 1e-9);
+        assert_relative_eq!(minimum.energy, -0.0104 * EV, max_relative = 1e-9);
+        assert_relative_eq!(curve.zero_crossing().unwrap().distance, 3.4 * ANGSTROM, max_relative = 1e-9);
+        assert!(curve.barrier().is_none());
+    }
+
+    #[test]
+    fn morse_features() {
+        let (depth, length, range) = (4.5 * EV, 0.74 * ANGSTROM, 1.9 / ANGSTROM);
+        let morse = PairPotential::Morse { depth, length, range };
+        let curve = sample_curve(&morse, 0.3 * ANGSTROM, 4.0 * ANGSTROM, &CurveConfig::default());
+
+        let minimum = curve.minimum().unwrap();
+        assert_relative_eq!(minimum.distance, length, max_relative = 1e-9);
+        assert_relative_eq!(minimum.energy, -depth, max_relative = 1e-9);
+        // V = 0 where e^(-a(r - rₑ)) = 2
+        assert_relative_eq!(curve.zero_crossing().unwrap().distance, length - 2.0_f64.ln() / range, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn composite_well_behind_a_barrier() {
+        // A bond between two like-charged ions: metastable behind a Coulomb barrier
+        let dication = PairPotential::Composite(vec![
+            PairPotential::Morse { depth: EV, length: ANGSTROM, range: 2.0 / ANGSTROM },
+            PairPotential::coulomb(0.3 * ELEMENTARY_CHARGE, 0.3 * ELEMENTARY_CHARGE),
+        ]);
+        let curve = sample_curve(&dication, 0.5 * ANGSTROM, 6.0 * ANGSTROM, &CurveConfig::default());
+        let (minimum, barrier) = (curve.minimum().unwrap(), curve.barrier().unwrap());
+
+        assert!(minimum.distance < barrier.distance);
+        assert!(minimum.energy < barrier.energy);
+        for feature in [minimum, barrier] {
+            assert!(dication.force(feature.distance).abs() < 1e-6 * EV / ANGSTROM);
+        }
+        // Still repulsive at long range: no zero crossing beyond the barrier
+        assert!(curve.energy_at(6.0 * ANGSTROM).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn sampling_concentrates_on_the_steep_wall() {
+        let config = CurveConfig::default();
+        let curve = sample_curve(&argon(), 3.0 * ANGSTROM, 10.0 * ANGSTROM, &config);
+        assert!(curve.samples.len() > config.initial_samples);
+        assert!(curve.samples.windows(2).all(|pair| pair[0].distance < pair[1].distance));
+
+        // The first tenth of the range holds more than a tenth of the points
+        let wall = curve.samples.iter().filter(|s| s.distance < 3.7 * ANGSTROM).count();
+        assert!(wall * 10 > 2 * curve.samples.len());
+    }
+
+    #[test]
+    fn hit_testing() {
+        let curve = sample_curve(&argon(), 3.0 * ANGSTROM, 10.0 * ANGSTROM, &CurveConfig::default());
+        let minimum = curve.minimum().unwrap();
+        let (dr, de) = (0.05 * ANGSTROM, 0.001 * EV);
+
+        let index = curve.features.iter().position(|f| f.kind == FeatureKind::Minimum).unwrap();
+        assert_eq!(curve.hit_test(minimum.distance + 0.02 * ANGSTROM, minimum.energy, dr, de), Some(CurveHit::Feature(index)));
+
+        let far_tail = curve.energy_at(8.0 * ANGSTROM).unwrap();
+        assert!(matches!(curve.hit_test(8.0 * ANGSTROM, far_tail, 0.2 * ANGSTROM, de), Some(CurveHit::Curve(_))));
+        assert_eq!(curve.hit_test(8.0 * ANGSTROM, 0.01 * EV, dr, de), None);
+    }
+}
@@ -0,0 +1,276 @@
+// Mean-square displacement and diffusion
+// MSD(t) = ⟨|r(t) - r(0)|²⟩ over particles. At long times a diffusing
+// particle obeys the Einstein relation MSD = 2 d D t, so D is the slope
+// of the MSD divided by 2d. Under periodic boundaries wrapped coordinates
+// jump by a box length when a particle crosses a wall; the tracker keeps
+// unwrapped positions by accumulating minimum-image displacements, which
+// is exact as long as nothing moves half a box between two records.
+
+use bevy::prelude::*;
+use glam::DVec3;
+
+/// An orthorhombic periodic box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeriodicBox {
+    /// Edge lengths in meters; 0 leaves that axis open
+    pub size: DVec3,
+}
+
+impl PeriodicBox {
+    /// Open boundaries on every axis
+    pub fn open() -> Self {
+        Self { size: DVec3::ZERO }
+    }
+
+    /// Cube of edge length `side` meters
+    pub fn cube(side: f64) -> Self {
+        Self { size: DVec3::splat(side) }
+    }
+
+    /// Map a position into [0, L) on every periodic axis
+    pub fn wrap(&self, position: DVec3) -> DVec3 {
+        let mut wrapped = position;
+        for axis in 0..3 {
+            let length = self.size[axis];
+            if length > 0.0 {
+                wrapped[axis] = position[axis].rem_euclid(length);
+            }
+        }
+        wrapped
+    }
+
+    /// Shortest periodic image of a displacement
+    pub fn minimum_image(&self, displacement: DVec3) -> DVec3 {
+        let mut image = displacement;
+        for axis in 0..3 {
+            let length = self.size[axis];
+            if length > 0.0 {
+                image[axis] -= length * (displacement[axis] / length).round();
+            }
+        }
+        image
+    }
+}
+
+/// One point of an MSD curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MsdSample {
+    /// Time since the reference positions, in seconds
+    pub time: f64,
+    /// Mean-square displacement in m²
+    pub msd: f64,
+}
+
+/// Follows particles over time and records their mean-square displacement.
+///
+/// Long runs are thinned: once `max_samples` is reached every other sample
+/// is dropped and recording slows down by half, so the curve always spans
+/// the whole run with a bounded number of points.
+#[derive(Resource, Debug, Clone)]
+pub struct MsdTracker {
+    pub boundary: PeriodicBox,
+    /// Largest number of stored samples
+    pub max_samples: usize,
+    origin: Vec<DVec3>,
+    unwrapped: Vec<DVec3>,
+    last_wrapped: Vec<DVec3>,
+    time: f64,
+    stride: usize,
+    records: usize,
+    samples: Vec<MsdSample>,
+}
+
+impl Default for MsdTracker {
+    fn default() -> Self {
+        Self::new(&[], PeriodicBox::open())
+    }
+}
+
+impl MsdTracker {
+    /// Start tracking from the given (possibly wrapped) positions
+    pub fn new(positions: &[DVec3], boundary: PeriodicBox) -> Self {
+        Self {
+            boundary,
+            max_samples: 512,
+            origin: positions.to_vec(),
+            unwrapped: positions.to_vec(),
+            last_wrapped: positions.to_vec(),
+            time: 0.0,
+            stride: 1,
+            records: 0,
+            samples: vec![MsdSample { time: 0.0, msd: 0.0 }],
+        }
+    }
+
+    /// Restart from new reference positions, keeping the settings
+    pub fn reset(&mut self, positions: &[DVec3]) {
+        *self = Self { max_samples: self.max_samples, ..Self::new(positions, self.boundary) };
+    }
+
+    /// Number of particles being followed
+    pub fn len(&self) -> usize {
+        self.origin.len()
+    }
+
+    /// Whether no particles are being followed
+    pub fn is_empty(&self) -> bool {
+        self.origin.is_empty()
+    }
+
+    /// Time since the reference positions, in seconds
+    pub fn elapsed(&self) -> f64 {
+        self.time
+    }
+
+    /// Unwrapped positions of the particles
+    pub fn unwrapped(&self) -> &[DVec3] {
+        &self.unwrapped
+    }
+
+    /// Recorded MSD curve, oldest first
+    pub fn samples(&self) -> &[MsdSample] {
+        &self.samples
+    }
+
+    /// Current mean-square displacement in m²
+    pub fn msd(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self.unwrapped.iter().zip(&self.origin).map(|(r, r0)| r.distance_squared(*r0)).sum();
+        total / self.len() as f64
+    }
+
+    /// Feed the positions after `dt` seconds more.
+    ///
+    /// A change in particle count restarts tracking from these positions.
+    pub fn record(&mut self, positions: &[DVec3], dt: f64) {
+        if positions.len() != self.len() {
+            self.reset(positions);
+            return;
+        }
+        for ((unwrapped, last), &position) in self.unwrapped.iter_mut().zip(&mut self.last_wrapped).zip(positions) {
+            *unwrapped += self.boundary.minimum_image(position - *last);
+            *last = position;
+        }
+        self.time += dt;
+
+        self.records += 1;
+        if self.records.is_multiple_of(self.stride) {
+            self.samples.push(MsdSample { time: self.time, msd: self.msd() });
+            if self.samples.len() > self.max_samples {
+                let mut keep = false;
+                self.samples.retain(|_| {
+                    keep = !keep;
+                    keep
+                });
+                self.stride *= 2;
+            }
+        }
+    }
+
+    /// Diffusion coefficient in m²/s from the slope of the MSD.
+    ///
+    /// Only samples after `skip` of the run are fitted, leaving out the
+    /// early ballistic stretch where MSD grows as t². Returns None with
+    /// fewer than two samples to fit.
+    pub fn diffusion_coefficient(&self, dimensions: usize, skip: f64) -> Option<f64> {
+        let start = skip * self.time;
+        let fitted: Vec<&MsdSample> = self.samples.iter().filter(|s| s.time >= start).collect();
+        if fitted.len() < 2 {
+            return None;
+        }
+
+        let n = fitted.len() as f64;
+        let mean_t = fitted.iter().map(|s| s.time).sum::<f64>() / n;
+        let mean_msd = fitted.iter().map(|s| s.msd).sum::<f64>() / n;
+        let covariance: f64 = fitted.iter().map(|s| (s.time - mean_t) * (s.msd - mean_msd)).sum();
+        let variance: f64 = fitted.iter().map(|s| (s.time - mean_t).powi(2)).sum();
+        (variance > 0.0).then(|| covariance / variance / (2.0 * dimensions as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::random::Random;
+    use crate::particles::proton::Proton;
+    use crate::physics::constants::{BOLTZMANN_CONSTANT, PROTON_MASS};
+    use crate::physics::simulation::langevin_step;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn periodic_box_geometry() {
+        let cell = PeriodicBox::cube(10.0);
+        assert_eq!(cell.wrap(DVec3::new(12.0, -1.0, 5.0)), DVec3::new(2.0, 9.0, 5.0));
+        assert_eq!(cell.minimum_image(DVec3::new(9.0, -6.0, 1.0)), DVec3::new(-1.0, 4.0, 1.0));
+
+        let open = PeriodicBox::open();
+        assert_eq!(open.wrap(DVec3::splat(-3.0)), DVec3::splat(-3.0));
+    }
+
+    #[test]
+    fn ballistic_motion() {
+        // Free flight at 2 m/s: MSD = v² t²
+        let mut tracker = MsdTracker::new(&[DVec3::ZERO, DVec3::X], PeriodicBox::open());
+        for step in 1..=10 {
+            let t = step as f64;
+            tracker.record(&[DVec3::Y * 2.0 * t, DVec3::X + DVec3::Z * 2.0 * t], 1.0);
+        }
+        assert_relative_eq!(tracker.msd(), 400.0, max_relative = 1e-12);
+        assert_eq!(tracker.samples().len(), 11);
+    }
+
+    #[test]
+    fn unwrapping_undoes_the_walls() {
+        // Walk steadily to the right through a small box
+        let cell = PeriodicBox::cube(1.0);
+        let mut tracker = MsdTracker::new(&[DVec3::splat(0.5)], cell);
+        let mut position = DVec3::splat(0.5);
+        for _ in 0..100 {
+            position.x += 0.3;
+            tracker.record(&[cell.wrap(position)], 1.0);
+        }
+        assert_relative_eq!(tracker.unwrapped()[0].x, 30.5, max_relative = 1e-12);
+        assert_relative_eq!(tracker.msd(), 900.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn thinning_keeps_the_whole_run() {
+        let mut tracker = MsdTracker { max_samples: 16, ..MsdTracker::new(&[DVec3::ZERO], PeriodicBox::open()) };
+        for step in 1..=1000 {
+            tracker.record(&[DVec3::X * step as f64], 1.0);
+        }
+        let samples = tracker.samples();
+        assert!(samples.len() <= 16);
+        assert!(samples.last().unwrap().time > 900.0);
+        assert!(samples.windows(2).all(|pair| pair[0].time < pair[1].time));
+    }
+
+    #[test]
+    fn langevin_particles_recover_einstein_diffusion() {
+        let (temperature, friction, dt) = (300.0, 1.0e13, 5.0e-15);
+        let expected = BOLTZMANN_CONSTANT * temperature / (PROTON_MASS * friction);
+
+        // A box a few diffusion lengths wide, so particles wrap many times
+        let cell = PeriodicBox::cube(2.0e-9);
+        let mut random = Random::new(21);
+        let mut particles: Vec<Proton> = (0..400)
+            .map(|_| Proton::new(DVec3::new(random.uniform(), random.uniform(), random.uniform()) * 2.0e-9))
+            .collect();
+        let wrapped = |particles: &[Proton]| particles.iter().map(|p| cell.wrap(p.position)).collect::<Vec<_>>();
+
+        let mut tracker = MsdTracker::new(&wrapped(&particles), cell);
+        for _ in 0..4000 {
+            for particle in particles.iter_mut() {
+                langevin_step(particle, friction, temperature, dt, &mut random);
+            }
+            tracker.record(&wrapped(&particles), dt);
+        }
+
+        // Well past the velocity relaxation time 1/γ = 20 steps
+        let measured = tracker.diffusion_coefficient(3, 0.1).unwrap();
+        assert_relative_eq!(measured, expected, max_relative = 0.1);
+        assert!(tracker.msd().sqrt() > 2.0e-9, "Particles should cross the box");
+    }
+}
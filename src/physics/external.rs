@@ -0,0 +1,312 @@
+// External field sources
+// Forces that do not come from other particles:
+//   uniform field:   F = qE              (capacitor plates, electrolysis drift)
+//   harmonic trap:   F = -k (r - r₀)     (optical/ion trap, acts on every particle)
+//   painted grid:    F = -∇U(x, y)       (user-drawn potential energy landscape)
+
+use bevy::prelude::*;
+use glam::{DVec2, DVec3};
+
+/// A uniform electric field, as between the plates of a capacitor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformField {
+    /// Field vector in V/m
+    pub field: DVec3,
+}
+
+impl UniformField {
+    /// Field of an ideal parallel-plate capacitor, E = V/d, pointing along `direction`.
+    pub fn capacitor(voltage: f64, plate_gap: f64, direction: DVec3) -> Self {
+        Self { field: direction.normalize() * voltage / plate_gap }
+    }
+}
+
+/// A point harmonic trap pulling every particle toward its centre.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarmonicTrap {
+    /// Trap centre in meters
+    pub center: DVec3,
+    /// Spring constant in N/m
+    pub stiffness: f64,
+}
+
+/// A potential energy landscape sampled on a regular grid in the xy plane.
+///
+/// Values are potential energies in Joules felt by any particle, so the
+/// user can paint hills and wells directly. Between grid points the
+/// landscape is bilinear; outside the grid it is flat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PotentialGrid {
+    /// Position of grid point (0, 0) in meters
+    pub origin: DVec2,
+    /// Distance between grid points in meters
+    pub spacing: f64,
+    /// Grid points along x
+    pub nx: usize,
+    /// Grid points along y
+    pub ny: usize,
+    /// Row-major energies, `values[j * nx + i]` at (i, j)
+    pub values: Vec<f64>,
+}
+
+impl PotentialGrid {
+    /// A flat grid centred on `center` covering `size` (meters) with the given spacing.
+    pub fn flat(center: DVec2, size: DVec2, spacing: f64) -> Self {
+        assert!(spacing > 0.0, "Grid spacing must be positive");
+        let nx = (size.x / spacing).ceil() as usize + 1;
+        let ny = (size.y / spacing).ceil() as usize + 1;
+        let origin = center - 0.5 * DVec2::new((nx - 1) as f64, (ny - 1) as f64) * spacing;
+        Self { origin, spacing, nx, ny, values: vec![0.0; nx * ny] }
+    }
+
+    /// Position of grid point (i, j) in meters
+    pub fn point(&self, i: usize, j: usize) -> DVec2 {
+        self.origin + DVec2::new(i as f64, j as f64) * self.spacing
+    }
+
+    /// Cell containing a point and the fractional position inside it,
+    /// or `None` outside the grid.
+    fn locate(&self, position: DVec3) -> Option<(usize, usize, f64, f64)> {
+        let u = (position.x - self.origin.x) / self.spacing;
+        let v = (position.y - self.origin.y) / self.spacing;
+        let (max_u, max_v) = ((self.nx - 1) as f64, (self.ny - 1) as f64);
+        if self.nx < 2 || self.ny < 2 || !(0.0..=max_u).contains(&u) || !(0.0..=max_v).contains(&v) {
+            return None;
+        }
+
+        let i = (u.floor() as usize).min(self.nx - 2);
+        let j = (v.floor() as usize).min(self.ny - 2);
+        Some((i, j, u - i as f64, v - j as f64))
+    }
+
+    /// The four corner values of cell (i, j): (v00, v10, v01, v11)
+    fn corners(&self, i: usize, j: usize) -> (f64, f64, f64, f64) {
+        let at = |i: usize, j: usize| self.values[j * self.nx + i];
+        (at(i, j), at(i + 1, j), at(i, j + 1), at(i + 1, j + 1))
+    }
+
+    /// Bilinearly interpolated potential energy at a point in Joules.
+    pub fn energy(&self, position: DVec3) -> f64 {
+        let Some((i, j, fx, fy)) = self.locate(position) else {
+            return 0.0;
+        };
+        let (v00, v10, v01, v11) = self.corners(i, j);
+        (1.0 - fy) * ((1.0 - fx) * v00 + fx * v10) + fy * ((1.0 - fx) * v01 + fx * v11)
+    }
+
+    /// Force -∇U at a point in Newtons (in-plane; zero outside the grid).
+    pub fn force(&self, position: DVec3) -> DVec3 {
+        let Some((i, j, fx, fy)) = self.locate(position) else {
+            return DVec3::ZERO;
+        };
+        let (v00, v10, v01, v11) = self.corners(i, j);
+        let dx = ((1.0 - fy) * (v10 - v00) + fy * (v11 - v01)) / self.spacing;
+        let dy = ((1.0 - fx) * (v01 - v00) + fx * (v11 - v10)) / self.spacing;
+        -DVec3::new(dx, dy, 0.0)
+    }
+
+    /// Paint a smooth bump of `amount` Joules (negative digs a well)
+    /// with Gaussian radius `radius` around `center`.
+    pub fn paint(&mut self, center: DVec3, radius: f64, amount: f64) {
+        // Beyond three radii the bump is below 1% and not worth touching
+        let reach = 3.0 * radius;
+        for j in 0..self.ny {
+            for i in 0..self.nx {
+                let offset = self.point(i, j) - center.truncate();
+                if offset.x.abs() > reach || offset.y.abs() > reach {
+                    continue;
+                }
+                let weight = (-offset.length_squared() / (2.0 * radius * radius)).exp();
+                self.values[j * self.nx + i] += amount * weight;
+            }
+        }
+    }
+
+    /// Flatten the whole landscape back to zero
+    pub fn clear(&mut self) {
+        self.values.fill(0.0);
+    }
+}
+
+/// The different kinds of external source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourceKind {
+    Uniform(UniformField),
+    Trap(HarmonicTrap),
+    Custom(PotentialGrid),
+}
+
+/// One external source that can be switched on and off.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalSource {
+    pub kind: SourceKind,
+    /// Inactive sources exert no force
+    pub active: bool,
+}
+
+/// All external fields acting on the scene.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ExternalField {
+    pub sources: Vec<ExternalSource>,
+}
+
+impl ExternalField {
+    /// Add an active source, returning its index.
+    pub fn add(&mut self, kind: SourceKind) -> usize {
+        self.sources.push(ExternalSource { kind, active: true });
+        self.sources.len() - 1
+    }
+
+    /// Add an active uniform field
+    pub fn with_uniform(mut self, field: DVec3) -> Self {
+        self.add(SourceKind::Uniform(UniformField { field }));
+        self
+    }
+
+    /// Add an active harmonic trap
+    pub fn with_trap(mut self, center: DVec3, stiffness: f64) -> Self {
+        self.add(SourceKind::Trap(HarmonicTrap { center, stiffness }));
+        self
+    }
+
+    /// Add an active custom potential grid
+    pub fn with_potential(mut self, grid: PotentialGrid) -> Self {
+        self.add(SourceKind::Custom(grid));
+        self
+    }
+
+    /// Switch a source on or off (ignored for unknown indices).
+    pub fn set_active(&mut self, index: usize, active: bool) {
+        if let Some(source) = self.sources.get_mut(index) {
+            source.active = active;
+        }
+    }
+
+    /// Toggle a source, returning its new state.
+    pub fn toggle(&mut self, index: usize) -> Option<bool> {
+        let source = self.sources.get_mut(index)?;
+        source.active = !source.active;
+        Some(source.active)
+    }
+
+    /// First custom potential grid, for painting.
+    pub fn paintable_mut(&mut self) -> Option<&mut PotentialGrid> {
+        self.sources.iter_mut().find_map(|source| match &mut source.kind {
+            SourceKind::Custom(grid) => Some(grid),
+            _ => None,
+        })
+    }
+
+    fn active(&self) -> impl Iterator<Item = &SourceKind> {
+        self.sources.iter().filter(|s| s.active).map(|s| &s.kind)
+    }
+
+    /// Total external force on a particle in Newtons.
+    ///
+    /// # Arguments
+    /// * `position` - Particle position in meters
+    /// * `charge` - Particle charge in Coulombs (only the uniform field uses it)
+    pub fn force(&self, position: DVec3, charge: f64) -> DVec3 {
+        self.active()
+            .map(|kind| match kind {
+                SourceKind::Uniform(uniform) => charge * uniform.field,
+                SourceKind::Trap(trap) => -trap.stiffness * (position - trap.center),
+                SourceKind::Custom(grid) => grid.force(position),
+            })
+            .sum()
+    }
+
+    /// Total external potential energy of a particle in Joules.
+    ///
+    /// The uniform field's potential is zero at the origin: U = -q E · r.
+    pub fn potential_energy(&self, position: DVec3, charge: f64) -> f64 {
+        self.active()
+            .map(|kind| match kind {
+                SourceKind::Uniform(uniform) => -charge * uniform.field.dot(position),
+                SourceKind::Trap(trap) => 0.5 * trap.stiffness * position.distance_squared(trap.center),
+                SourceKind::Custom(grid) => grid.energy(position),
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn uniform_field_pushes_charges_oppositely() {
+        let field = ExternalField::default()
+            .with_uniform(UniformField::capacitor(1.0, 1e-9, DVec3::X).field);
+
+        let on_proton = field.force(DVec3::ZERO, ELEMENTARY_CHARGE);
+        let on_electron = field.force(DVec3::ZERO, -ELEMENTARY_CHARGE);
+        // 1 V across 1 nm: E = 1e9 V/m, F = eE
+        assert_relative_eq!(on_proton.x, ELEMENTARY_CHARGE * 1e9, max_relative = 1e-12);
+        assert_relative_eq!(on_electron.x, -on_proton.x);
+    }
+
+    #[test]
+    fn trap_restores_toward_centre() {
+        let center = DVec3::new(ANGSTROM, 0.0, 0.0);
+        let field = ExternalField::default().with_trap(center, 2.0);
+        let position = center + DVec3::new(0.0, 3.0 * ANGSTROM, 0.0);
+
+        assert_relative_eq!(field.force(position, 0.0).y, -6.0 * ANGSTROM);
+        assert_relative_eq!(field.potential_energy(position, 0.0), 9.0 * ANGSTROM * ANGSTROM);
+    }
+
+    #[test]
+    fn inactive_sources_are_ignored() {
+        let mut field = ExternalField::default().with_trap(DVec3::ZERO, 1.0).with_uniform(DVec3::Y);
+        field.set_active(0, false);
+        assert_eq!(field.force(DVec3::X, 0.0), DVec3::ZERO);
+
+        assert_eq!(field.toggle(0), Some(true));
+        assert_eq!(field.force(DVec3::X, 0.0), -DVec3::X);
+        assert_eq!(field.toggle(9), None);
+    }
+
+    #[test]
+    fn painted_grid_force_is_minus_gradient() {
+        let mut grid = PotentialGrid::flat(DVec2::ZERO, DVec2::splat(10.0 * ANGSTROM), 0.1 * ANGSTROM);
+        grid.paint(DVec3::ZERO, ANGSTROM, 1e-19);
+
+        let mut field = ExternalField::default().with_potential(grid);
+        let position = DVec3::new(0.73 * ANGSTROM, -0.41 * ANGSTROM, 0.0);
+
+        // Hill at the origin pushes particles away from it
+        let force = field.force(position, 0.0);
+        assert!(force.x > 0.0 && force.y < 0.0);
+
+        // Matches a finite difference of the interpolated energy inside one cell
+        let h = 1e-4 * ANGSTROM;
+        let slope = (field.potential_energy(position + DVec3::X * h, 0.0)
+            - field.potential_energy(position - DVec3::X * h, 0.0)) / (2.0 * h);
+        assert_relative_eq!(force.x, -slope, max_relative = 1e-6);
+
+        // Outside the grid nothing happens
+        assert_eq!(field.force(DVec3::new(20.0 * ANGSTROM, 0.0, 0.0), 0.0), DVec3::ZERO);
+
+        // Painting a matching well flattens it again
+        let grid = field.paintable_mut().unwrap();
+        grid.paint(DVec3::ZERO, ANGSTROM, -1e-19);
+        assert_relative_eq!(grid.energy(position), 0.0, epsilon = 1e-30);
+        grid.clear();
+        assert!(grid.values.iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn grid_interpolates_its_nodes() {
+        let mut grid = PotentialGrid::flat(DVec2::ZERO, DVec2::splat(2.0), 1.0);
+        assert_eq!((grid.nx, grid.ny), (3, 3));
+        grid.values[4] = 8.0; // centre node
+
+        assert_relative_eq!(grid.energy(DVec3::ZERO), 8.0);
+        assert_relative_eq!(grid.energy(DVec3::new(0.5, 0.0, 0.0)), 4.0);
+        assert_relative_eq!(grid.energy(DVec3::new(0.5, 0.5, 0.0)), 2.0);
+        assert_relative_eq!(grid.energy(DVec3::new(1.0, 1.0, 0.0)), 0.0);
+    }
+}
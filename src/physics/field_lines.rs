@@ -0,0 +1,295 @@
+// Electric field-line tracing
+// A field line solves dr/ds = E(r)/|E(r)|, integrated in arc length s
+// with RK4. The step is adapted by step doubling (one full step vs two
+// half steps) and never exceeds a fraction of the distance to the
+// nearest charge, so lines curve smoothly into the charges they end on.
+
+use glam::DVec3;
+use super::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+use super::coulomb::SourceCharge;
+use super::medium::Medium;
+
+/// Settings for tracing field lines.
+#[derive(Debug, Clone)]
+pub struct FieldLineConfig {
+    /// Lines leaving each charge per elementary charge
+    pub lines_per_charge: usize,
+    /// Distance from a charge at which lines start (meters)
+    pub start_radius: f64,
+    /// Lines ending within this distance of a charge stop on it (meters)
+    pub capture_radius: f64,
+    /// Smallest allowed step (meters)
+    pub min_step: f64,
+    /// Largest allowed step (meters)
+    pub max_step: f64,
+    /// Allowed position error per step (meters)
+    pub tolerance: f64,
+    /// Lines further than this from every charge are considered escaped (meters)
+    pub max_distance: f64,
+    /// Hard limit on steps per line
+    pub max_steps: usize,
+    /// Medium the charges sit in (screening bends lines toward nearby charges)
+    pub medium: Medium,
+}
+
+impl Default for FieldLineConfig {
+    fn default() -> Self {
+        Self {
+            lines_per_charge: 12,
+            start_radius: 0.05 * ANGSTROM,
+            capture_radius: 0.05 * ANGSTROM,
+            min_step: 1e-4 * ANGSTROM,
+            max_step: 2.0 * ANGSTROM,
+            tolerance: 1e-4 * ANGSTROM,
+            // Dipole lines leaving near the axis loop out to ~d/sin²θ before returning
+            max_distance: 200.0 * ANGSTROM,
+            max_steps: 20_000,
+            medium: Medium::vacuum(),
+        }
+    }
+}
+
+/// How a field line stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldLineEnd {
+    /// Reached the charge with this index in the source list
+    Charge(usize),
+    /// Left the region around the charges
+    Escaped,
+    /// Field vanished (a null point)
+    NullPoint,
+    /// Ran out of steps
+    StepLimit,
+}
+
+/// A traced field line, as a polyline the renderer can draw.
+#[derive(Debug, Clone)]
+pub struct FieldLine {
+    /// Points along the line in meters, from start to end
+    pub points: Vec<DVec3>,
+    /// Index of the charge the line started from, if any
+    pub start: Option<usize>,
+    /// Why the line stopped
+    pub end: FieldLineEnd,
+}
+
+impl FieldLine {
+    /// Total length of the polyline in meters
+    pub fn length(&self) -> f64 {
+        self.points.windows(2).map(|pair| pair[0].distance(pair[1])).sum()
+    }
+}
+
+/// Unit field direction at a point (zero at null points), flipped for backward tracing.
+fn direction(point: DVec3, charges: &[SourceCharge], medium: &Medium, sense: f64) -> DVec3 {
+    medium.electric_field(point, charges).normalize_or_zero() * sense
+}
+
+/// One RK4 step of length h along the field direction.
+fn rk4_step(point: DVec3, h: f64, charges: &[SourceCharge], medium: &Medium, sense: f64) -> DVec3 {
+    let k1 = direction(point, charges, medium, sense);
+    let k2 = direction(point + 0.5 * h * k1, charges, medium, sense);
+    let k3 = direction(point + 0.5 * h * k2, charges, medium, sense);
+    let k4 = direction(point + h * k3, charges, medium, sense);
+    point + h / 6.0 * (k1 + 2.0 * k2 + 2.0 * k3 + k4)
+}
+
+/// Nearest charge to a point: (index, distance).
+fn nearest_charge(point: DVec3, charges: &[SourceCharge]) -> Option<(usize, f64)> {
+    charges
+        .iter()
+        .map(|c| c.position.distance(point))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Trace one field line from `start`.
+///
+/// # Arguments
+/// * `start` - Starting point in meters
+/// * `charges` - Source charges
+/// * `forward` - Follow E (from + to -) if true, -E otherwise
+/// * `config` - Step and stopping settings
+pub fn trace_field_line(
+    start: DVec3,
+    charges: &[SourceCharge],
+    forward: bool,
+    config: &FieldLineConfig,
+) -> FieldLine {
+    let sense = if forward { 1.0 } else { -1.0 };
+    let medium = &config.medium;
+    let mut points = vec![start];
+    let mut point = start;
+    let mut h = config.max_step.min(config.start_radius).max(config.min_step);
+    let mut end = FieldLineEnd::StepLimit;
+
+    for _ in 0..config.max_steps {
+        let Some((nearest, distance)) = nearest_charge(point, charges) else {
+            end = FieldLineEnd::Escaped;
+            break;
+        };
+
+        // Arrived on a charge the line flows into (not the one it left)
+        let sinks_here = charges[nearest].charge * sense < 0.0;
+        if sinks_here && distance <= config.capture_radius {
+            points.push(charges[nearest].position);
+            end = FieldLineEnd::Charge(nearest);
+            break;
+        }
+        if distance > config.max_distance {
+            end = FieldLineEnd::Escaped;
+            break;
+        }
+        if direction(point, charges, medium, sense) == DVec3::ZERO {
+            end = FieldLineEnd::NullPoint;
+            break;
+        }
+
+        // Never step further than a fraction of the way to the nearest charge
+        let limit = (0.25 * distance).clamp(config.min_step, config.max_step);
+        h = h.min(limit);
+
+        // Step doubling: the difference estimates the local error (RK4 is 5th order locally)
+        let full = rk4_step(point, h, charges, medium, sense);
+        let half = rk4_step(point, 0.5 * h, charges, medium, sense);
+        let double = rk4_step(half, 0.5 * h, charges, medium, sense);
+        let error = full.distance(double);
+
+        if error > config.tolerance && h > config.min_step {
+            h = (0.5 * h).max(config.min_step);
+            continue;
+        }
+
+        point = double;
+        points.push(point);
+
+        // Grow gently when the error is comfortably small
+        if error < 0.1 * config.tolerance {
+            h = (2.0 * h).min(config.max_step);
+        }
+    }
+
+    FieldLine { points, start: None, end }
+}
+
+/// Trace field lines leaving every positive charge.
+///
+/// Lines start evenly spaced on a circle in the xy plane (the plane the
+/// scene is drawn in), offset by half a spacing so none leaves exactly
+/// along a symmetry axis. If there are no positive charges, lines are
+/// traced backward out of the negative charges instead.
+pub fn trace_field_lines(charges: &[SourceCharge], config: &FieldLineConfig) -> Vec<FieldLine> {
+    let forward = charges.iter().any(|c| c.charge > 0.0);
+    let mut lines = Vec::new();
+
+    for (index, source) in charges.iter().enumerate() {
+        if (source.charge > 0.0) != forward || source.charge == 0.0 {
+            continue;
+        }
+
+        let count = ((source.charge.abs() / ELEMENTARY_CHARGE) * config.lines_per_charge as f64)
+            .round()
+            .max(1.0) as usize;
+
+        for k in 0..count {
+            let angle = (k as f64 + 0.5) * std::f64::consts::TAU / count as f64;
+            let offset = DVec3::new(angle.cos(), angle.sin(), 0.0) * config.start_radius;
+
+            let mut line = trace_field_line(source.position + offset, charges, forward, config);
+            line.points.insert(0, source.position);
+            line.start = Some(index);
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn dipole() -> [SourceCharge; 2] {
+        [
+            SourceCharge::point(DVec3::new(-ANGSTROM, 0.0, 0.0), ELEMENTARY_CHARGE),
+            SourceCharge::point(DVec3::new(ANGSTROM, 0.0, 0.0), -ELEMENTARY_CHARGE),
+        ]
+    }
+
+    #[test]
+    fn dipole_lines_end_on_the_negative_charge() {
+        let charges = dipole();
+        let config = FieldLineConfig::default();
+        let lines = trace_field_lines(&charges, &config);
+
+        assert_eq!(lines.len(), config.lines_per_charge);
+        for line in &lines {
+            assert_eq!(line.start, Some(0));
+            assert_eq!(line.end, FieldLineEnd::Charge(1), "Line stopped early after {} points", line.points.len());
+            assert_eq!(*line.points.last().unwrap(), charges[1].position);
+            assert_eq!(line.points[0], charges[0].position);
+        }
+    }
+
+    #[test]
+    fn axial_line_is_straight() {
+        // Start just right of the positive charge, on the axis toward the negative one
+        let charges = dipole();
+        let config = FieldLineConfig::default();
+        let start = charges[0].position + DVec3::X * config.start_radius;
+        let line = trace_field_line(start, &charges, true, &config);
+
+        assert_eq!(line.end, FieldLineEnd::Charge(1));
+        assert!(line.points.iter().all(|p| p.y.abs() < 1e-6 * ANGSTROM));
+        assert_relative_eq!(line.length(), 2.0 * ANGSTROM - config.start_radius, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn screening_lets_back_lines_escape() {
+        // Behind the positive charge its own field wins by e^(-κd) and the
+        // lines wander off instead of looping round to the negative charge
+        let charges = dipole();
+        let config = FieldLineConfig {
+            lines_per_charge: 6,
+            medium: Medium::saline(1.0),
+            ..Default::default()
+        };
+        let lines = trace_field_lines(&charges, &config);
+
+        for line in &lines {
+            let facing = line.points[1].x > line.points[0].x;
+            if facing {
+                assert_eq!(line.end, FieldLineEnd::Charge(1));
+            }
+        }
+        assert!(lines.iter().any(|line| line.end == FieldLineEnd::Escaped));
+    }
+
+    #[test]
+    fn lone_charge_lines_escape() {
+        let charges = [SourceCharge::point(DVec3::ZERO, ELEMENTARY_CHARGE)];
+        let config = FieldLineConfig { lines_per_charge: 4, ..Default::default() };
+        let lines = trace_field_lines(&charges, &config);
+
+        assert_eq!(lines.len(), 4);
+        for line in &lines {
+            assert_eq!(line.end, FieldLineEnd::Escaped);
+            // Radial: every point lies along the starting direction
+            let direction = (line.points[1] - line.points[0]).normalize();
+            let last = *line.points.last().unwrap();
+            assert_relative_eq!(last.normalize().dot(direction), 1.0, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn negative_only_lines_run_backward() {
+        let charges = [SourceCharge::point(DVec3::ZERO, -2.0 * ELEMENTARY_CHARGE)];
+        let config = FieldLineConfig { lines_per_charge: 3, ..Default::default() };
+        let lines = trace_field_lines(&charges, &config);
+
+        // Twice the charge, twice the lines
+        assert_eq!(lines.len(), 6);
+        assert!(lines.iter().all(|line| line.end == FieldLineEnd::Escaped));
+    }
+}
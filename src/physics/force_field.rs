@@ -0,0 +1,742 @@
+// Molecular-mechanics force field
+// Bonded molecules are held together by terms on their bond graph:
+//   bonds       k (r - r₀)²   or Morse  D (1 - e^(-a(r - r₀)))²,  a = √(k/D)
+//   angles      k (θ - θ₀)²
+//   dihedrals   V [1 + cos(nφ - γ)]   around every central bond
+//   impropers   V [1 + cos(2φ - π)]   keeping sp² centres planar
+// plus Lennard-Jones and Coulomb between atoms more than two bonds apart,
+// scaled down for 1-4 pairs (atoms three bonds apart).
+// Parameters follow AMBER conventions; internally in kcal/mol and Ångström.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{ANGSTROM, COULOMB_CONSTANT, ELEMENTARY_CHARGE, KILOCALORIE_PER_MOLE};
+
+/// Force-field atom types for common organic molecules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AtomType {
+    /// Hydrogen bonded to carbon
+    HydrogenOnCarbon,
+    /// Hydrogen bonded to oxygen (alcohols, acids, water)
+    HydrogenOnOxygen,
+    /// Hydrogen bonded to nitrogen
+    HydrogenOnNitrogen,
+    /// sp³ carbon
+    CarbonSp3,
+    /// sp² carbon of alkenes and carbonyls
+    CarbonSp2,
+    /// Aromatic carbon
+    CarbonAromatic,
+    /// Hydroxyl (or ether) oxygen
+    OxygenHydroxyl,
+    /// Carbonyl oxygen
+    OxygenCarbonyl,
+    /// sp³ amine nitrogen
+    NitrogenAmine,
+}
+
+impl AtomType {
+    /// Nuclear charge number Z of the element
+    pub fn element(&self) -> u32 {
+        match self {
+            AtomType::HydrogenOnCarbon | AtomType::HydrogenOnOxygen | AtomType::HydrogenOnNitrogen => 1,
+            AtomType::CarbonSp3 | AtomType::CarbonSp2 | AtomType::CarbonAromatic => 6,
+            AtomType::NitrogenAmine => 7,
+            AtomType::OxygenHydroxyl | AtomType::OxygenCarbonyl => 8,
+        }
+    }
+
+    /// Short type name as used in parameter tables
+    pub fn name(&self) -> &'static str {
+        match self {
+            AtomType::HydrogenOnCarbon => "hc",
+            AtomType::HydrogenOnOxygen => "ho",
+            AtomType::HydrogenOnNitrogen => "hn",
+            AtomType::CarbonSp3 => "c3",
+            AtomType::CarbonSp2 => "c2",
+            AtomType::CarbonAromatic => "ca",
+            AtomType::OxygenHydroxyl => "oh",
+            AtomType::OxygenCarbonyl => "o",
+            AtomType::NitrogenAmine => "n3",
+        }
+    }
+
+    /// Lennard-Jones parameters: (R_min/2 in Å, well depth ε in kcal/mol)
+    pub fn lennard_jones(&self) -> (f64, f64) {
+        match self {
+            AtomType::HydrogenOnCarbon => (1.4870, 0.0157),
+            // Hidden inside the oxygen's radius, as in AMBER and TIP3P
+            AtomType::HydrogenOnOxygen => (0.0, 0.0),
+            AtomType::HydrogenOnNitrogen => (0.6000, 0.0157),
+            AtomType::CarbonSp3 => (1.9080, 0.1094),
+            AtomType::CarbonSp2 | AtomType::CarbonAromatic => (1.9080, 0.0860),
+            AtomType::OxygenHydroxyl => (1.7210, 0.2104),
+            AtomType::OxygenCarbonyl => (1.6612, 0.2100),
+            AtomType::NitrogenAmine => (1.8240, 0.1700),
+        }
+    }
+}
+
+/// Bond stretch parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BondParameters {
+    /// Force constant k in kcal/(mol⋅Å²), E = k (r - r₀)²
+    pub force_constant: f64,
+    /// Equilibrium length r₀ in Å
+    pub length: f64,
+    /// Dissociation energy D in kcal/mol (Morse well depth)
+    pub dissociation: f64,
+}
+
+impl BondParameters {
+    /// Parameters for a bond between two atom types (in either order).
+    pub fn for_types(a: AtomType, b: AtomType) -> Option<Self> {
+        use AtomType::*;
+        let (force_constant, length, dissociation) = match (a.min(b), a.max(b)) {
+            (HydrogenOnCarbon, CarbonSp3) => (340.0, 1.090, 99.0),
+            (HydrogenOnCarbon, CarbonSp2) => (367.0, 1.080, 111.0),
+            (HydrogenOnCarbon, CarbonAromatic) => (367.0, 1.080, 113.0),
+            (HydrogenOnOxygen, OxygenHydroxyl) => (553.0, 0.960, 110.0),
+            (HydrogenOnNitrogen, NitrogenAmine) => (434.0, 1.010, 93.0),
+            (CarbonSp3, CarbonSp3) => (310.0, 1.526, 88.0),
+            (CarbonSp3, CarbonSp2) => (317.0, 1.522, 95.0),
+            (CarbonSp3, CarbonAromatic) => (317.0, 1.510, 100.0),
+            (CarbonSp3, OxygenHydroxyl) => (320.0, 1.410, 86.0),
+            (CarbonSp3, NitrogenAmine) => (367.0, 1.471, 80.0),
+            (CarbonSp2, CarbonSp2) => (549.0, 1.350, 146.0),
+            (CarbonSp2, OxygenHydroxyl) => (450.0, 1.364, 105.0),
+            (CarbonSp2, OxygenCarbonyl) => (570.0, 1.229, 178.0),
+            (CarbonAromatic, CarbonAromatic) => (469.0, 1.400, 120.0),
+            (CarbonAromatic, OxygenHydroxyl) => (450.0, 1.364, 110.0),
+            _ => return None,
+        };
+        Some(Self { force_constant, length, dissociation })
+    }
+
+    /// Morse range parameter a = √(k/D) in 1/Å, matching the harmonic curvature at r₀
+    pub fn morse_range(&self) -> f64 {
+        (self.force_constant / self.dissociation).sqrt()
+    }
+}
+
+/// Angle bend parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AngleParameters {
+    /// Force constant k in kcal/(mol⋅rad²), E = k (θ - θ₀)²
+    pub force_constant: f64,
+    /// Equilibrium angle θ₀ in radians
+    pub angle: f64,
+}
+
+impl AngleParameters {
+    /// Parameters for the angle a-centre-c, chosen by the centre and its partners.
+    pub fn for_types(a: AtomType, centre: AtomType, c: AtomType) -> Option<Self> {
+        use AtomType::*;
+        let hydrogens = [a, c].iter().filter(|t| t.element() == 1).count();
+        let carbonyl = a == OxygenCarbonyl || c == OxygenCarbonyl;
+        let (force_constant, degrees) = match centre {
+            CarbonSp3 => match hydrogens {
+                0 => (40.0, 109.5),
+                1 => (50.0, 109.5),
+                _ => (35.0, 109.5),
+            },
+            CarbonSp2 if carbonyl => (80.0, 120.0),
+            CarbonSp2 => match hydrogens {
+                0 => (70.0, 120.0),
+                1 => (50.0, 120.0),
+                _ => (35.0, 120.0),
+            },
+            CarbonAromatic if hydrogens > 0 => (50.0, 120.0),
+            CarbonAromatic => (63.0, 120.0),
+            OxygenHydroxyl => match hydrogens {
+                0 => (60.0, 109.5),
+                1 => (55.0, 108.5),
+                _ => (100.0, 104.52),
+            },
+            NitrogenAmine if hydrogens == 2 => (35.0, 106.4),
+            NitrogenAmine => (50.0, 109.5),
+            // Hydrogens and carbonyl oxygens are terminal
+            _ => return None,
+        };
+        Some(Self { force_constant, angle: f64::to_radians(degrees) })
+    }
+}
+
+/// Torsion parameters for proper and improper dihedrals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TorsionParameters {
+    /// Barrier V in kcal/mol, E = V [1 + cos(nφ - γ)]
+    pub barrier: f64,
+    /// Periodicity n
+    pub periodicity: u32,
+    /// Phase γ in radians
+    pub phase: f64,
+}
+
+impl TorsionParameters {
+    fn new(barrier: f64, periodicity: u32, phase_degrees: f64) -> Self {
+        Self { barrier, periodicity, phase: phase_degrees.to_radians() }
+    }
+
+    /// Parameters for any dihedral around a central bond b-c (X-b-c-X).
+    ///
+    /// # Returns
+    /// `None` where the rotation is free; such dihedrals contribute nothing.
+    pub fn proper(b: AtomType, c: AtomType) -> Option<Self> {
+        use AtomType::*;
+        // Barriers per dihedral: the AMBER value divided among all X-b-c-X paths
+        let (barrier, periodicity, phase) = match (b.min(c), b.max(c)) {
+            (CarbonSp3, CarbonSp3) => (1.40 / 9.0, 3, 0.0),
+            (CarbonSp3, OxygenHydroxyl) => (0.50 / 3.0, 3, 0.0),
+            (CarbonSp3, NitrogenAmine) => (1.80 / 6.0, 3, 0.0),
+            (CarbonSp2, CarbonSp2) => (26.6 / 4.0, 2, 180.0),
+            (CarbonSp2, OxygenHydroxyl) => (2.30 / 2.0, 2, 180.0),
+            (CarbonAromatic, CarbonAromatic) => (14.5 / 4.0, 2, 180.0),
+            (CarbonAromatic, OxygenHydroxyl) => (1.80 / 2.0, 2, 180.0),
+            _ => return None,
+        };
+        Some(Self::new(barrier, periodicity, phase))
+    }
+
+    /// Out-of-plane parameters for a three-coordinate centre.
+    ///
+    /// # Returns
+    /// `None` unless the centre is sp² or aromatic.
+    pub fn improper(centre: AtomType, neighbours: [AtomType; 3]) -> Option<Self> {
+        let barrier = match centre {
+            AtomType::CarbonSp2 if neighbours.contains(&AtomType::OxygenCarbonyl) => 10.5,
+            AtomType::CarbonSp2 | AtomType::CarbonAromatic => 1.1,
+            _ => return None,
+        };
+        Some(Self::new(barrier, 2, 180.0))
+    }
+
+    /// Energy in kcal/mol and its derivative dE/dφ at dihedral angle φ
+    fn energy(&self, phi: f64) -> (f64, f64) {
+        let n = self.periodicity as f64;
+        let argument = n * phi - self.phase;
+        (self.barrier * (1.0 + argument.cos()), -self.barrier * n * argument.sin())
+    }
+}
+
+/// How two atoms of a molecule interact without a direct term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairKind {
+    /// One or two bonds apart: covered by bond and angle terms
+    Excluded,
+    /// Three bonds apart: scaled non-bonded interaction
+    OneFour,
+    /// Further apart or in different fragments: full non-bonded interaction
+    Full,
+}
+
+/// The bond graph of a molecule.
+///
+/// Atom i is the i-th particle of the molecule; positions are passed to
+/// [`ForceField::evaluate`] in the same order.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Topology {
+    pub atoms: Vec<AtomType>,
+    /// Partial charge of each atom in units of e
+    pub charges: Vec<f64>,
+    /// Bonded atom pairs
+    pub bonds: Vec<(usize, usize)>,
+}
+
+impl Topology {
+    /// An empty topology.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an uncharged atom.
+    pub fn with_atom(self, atom_type: AtomType) -> Self {
+        self.with_charged_atom(atom_type, 0.0)
+    }
+
+    /// Add an atom carrying a partial charge (units of e).
+    pub fn with_charged_atom(mut self, atom_type: AtomType, charge: f64) -> Self {
+        self.atoms.push(atom_type);
+        self.charges.push(charge);
+        self
+    }
+
+    /// Bond two atoms by index.
+    pub fn with_bond(mut self, a: usize, b: usize) -> Self {
+        assert!(a != b && a < self.atoms.len() && b < self.atoms.len(), "Bond between two existing atoms");
+        self.bonds.push((a, b));
+        self
+    }
+
+    /// Replace the partial charges, e.g. with a QEq solution.
+    pub fn set_charges(&mut self, charges: &[f64]) {
+        assert_eq!(charges.len(), self.atoms.len(), "One charge per atom");
+        self.charges = charges.to_vec();
+    }
+
+    /// Number of atoms
+    pub fn len(&self) -> usize {
+        self.atoms.len()
+    }
+
+    /// Whether the topology has no atoms
+    pub fn is_empty(&self) -> bool {
+        self.atoms.is_empty()
+    }
+
+    /// Indices of the atoms bonded to atom i
+    pub fn neighbours(&self, i: usize) -> Vec<usize> {
+        self.bonds
+            .iter()
+            .filter_map(|&(a, b)| if a == i { Some(b) } else if b == i { Some(a) } else { None })
+            .collect()
+    }
+
+    /// Every angle a-b-c, with b the central atom.
+    pub fn angles(&self) -> Vec<[usize; 3]> {
+        let mut angles = Vec::new();
+        for centre in 0..self.len() {
+            let neighbours = self.neighbours(centre);
+            for (k, &a) in neighbours.iter().enumerate() {
+                for &c in &neighbours[k + 1..] {
+                    angles.push([a, centre, c]);
+                }
+            }
+        }
+        angles
+    }
+
+    /// Every proper dihedral a-b-c-d around a bond b-c.
+    pub fn dihedrals(&self) -> Vec<[usize; 4]> {
+        let mut dihedrals = Vec::new();
+        for &(b, c) in &self.bonds {
+            for a in self.neighbours(b).into_iter().filter(|&a| a != c) {
+                for d in self.neighbours(c).into_iter().filter(|&d| d != b && d != a) {
+                    dihedrals.push([a, b, c, d]);
+                }
+            }
+        }
+        dihedrals
+    }
+
+    /// Every improper dihedral a-b-centre-c around a three-coordinate centre.
+    pub fn impropers(&self) -> Vec<[usize; 4]> {
+        (0..self.len())
+            .filter_map(|centre| match self.neighbours(centre)[..] {
+                [a, b, c] => Some([a, b, centre, c]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Bond-graph distance between every pair, counted up to three bonds.
+    fn separations(&self) -> Vec<Vec<Option<usize>>> {
+        let n = self.len();
+        let neighbours: Vec<Vec<usize>> = (0..n).map(|i| self.neighbours(i)).collect();
+        let mut separations = vec![vec![None; n]; n];
+
+        for (start, row) in separations.iter_mut().enumerate() {
+            row[start] = Some(0);
+            let mut frontier = vec![start];
+            for depth in 1..=3 {
+                let mut next = Vec::new();
+                for &atom in &frontier {
+                    for &neighbour in &neighbours[atom] {
+                        if row[neighbour].is_none() {
+                            row[neighbour] = Some(depth);
+                            next.push(neighbour);
+                        }
+                    }
+                }
+                frontier = next;
+            }
+        }
+        separations
+    }
+
+    /// How atoms i and j interact through space.
+    pub fn pair_kind(&self, i: usize, j: usize) -> PairKind {
+        classify(self.separations()[i][j])
+    }
+}
+
+fn classify(separation: Option<usize>) -> PairKind {
+    match separation {
+        Some(0..=2) => PairKind::Excluded,
+        Some(3) => PairKind::OneFour,
+        _ => PairKind::Full,
+    }
+}
+
+/// Functional form of the bond stretch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BondModel {
+    /// Parabola around r₀; bonds can never break
+    #[default]
+    Harmonic,
+    /// Morse well that levels off at the dissociation energy
+    Morse,
+}
+
+/// Molecular-mechanics settings.
+#[derive(Resource, Debug, Clone)]
+pub struct ForceField {
+    pub bond_model: BondModel,
+    /// Scale on Lennard-Jones between 1-4 pairs (AMBER: 1/2)
+    pub lennard_jones_14: f64,
+    /// Scale on Coulomb between 1-4 pairs (AMBER: 1/1.2)
+    pub coulomb_14: f64,
+}
+
+impl Default for ForceField {
+    fn default() -> Self {
+        Self {
+            bond_model: BondModel::Harmonic,
+            lennard_jones_14: 0.5,
+            coulomb_14: 1.0 / 1.2,
+        }
+    }
+}
+
+/// Energy of each kind of term, in Joules.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MmEnergy {
+    pub bond: f64,
+    pub angle: f64,
+    pub dihedral: f64,
+    pub improper: f64,
+    pub lennard_jones: f64,
+    pub coulomb: f64,
+}
+
+impl MmEnergy {
+    /// Sum of all terms in Joules
+    pub fn total(&self) -> f64 {
+        self.bond + self.angle + self.dihedral + self.improper + self.lennard_jones + self.coulomb
+    }
+}
+
+/// Energies and forces of a molecule at one geometry.
+#[derive(Debug, Clone)]
+pub struct MmEvaluation {
+    pub energy: MmEnergy,
+    /// Force on each atom in Newtons
+    pub forces: Vec<DVec3>,
+}
+
+/// Coulomb constant in kcal/mol⋅Å/e²
+fn coulomb_kcal_angstrom() -> f64 {
+    COULOMB_CONSTANT * ELEMENTARY_CHARGE.powi(2) / (ANGSTROM * KILOCALORIE_PER_MOLE)
+}
+
+/// Angle i-j-k and its gradient with respect to each position.
+fn angle_gradient(ri: DVec3, rj: DVec3, rk: DVec3) -> (f64, [DVec3; 3]) {
+    let (u, v) = (ri - rj, rk - rj);
+    let (lu, lv) = (u.length(), v.length());
+    let cos = (u.dot(v) / (lu * lv)).clamp(-1.0, 1.0);
+    let theta = cos.acos();
+    let sin = (1.0 - cos * cos).sqrt();
+    if sin < 1e-8 {
+        // Collinear: the bend direction is undefined
+        return (theta, [DVec3::ZERO; 3]);
+    }
+
+    let gi = -(v / lv - cos * u / lu) / (lu * sin);
+    let gk = -(u / lu - cos * v / lv) / (lv * sin);
+    (theta, [gi, -gi - gk, gk])
+}
+
+/// Dihedral angle i-j-k-l (IUPAC sign convention) and its gradient.
+fn dihedral_gradient(ri: DVec3, rj: DVec3, rk: DVec3, rl: DVec3) -> (f64, [DVec3; 4]) {
+    let (b1, b2, b3) = (rj - ri, rk - rj, rl - rk);
+    let (n1, n2) = (b1.cross(b2), b2.cross(b3));
+    let length = b2.length();
+    let phi = (length * b1.dot(n2)).atan2(n1.dot(n2));
+    if n1.length_squared() < 1e-16 || n2.length_squared() < 1e-16 {
+        return (phi, [DVec3::ZERO; 4]);
+    }
+
+    let gi = -length / n1.length_squared() * n1;
+    let gl = length / n2.length_squared() * n2;
+    let p = b1.dot(b2) / (length * length);
+    let q = b3.dot(b2) / (length * length);
+    let gj = q * gl - (1.0 + p) * gi;
+    let gk = p * gi - (1.0 + q) * gl;
+    (phi, [gi, gj, gk, gl])
+}
+
+impl ForceField {
+    /// Energy and forces of a molecule.
+    ///
+    /// # Arguments
+    /// * `topology` - Atom types, charges and bonds
+    /// * `positions` - Atom positions in meters, in topology order
+    ///
+    /// # Returns
+    /// `None` if a bond or angle has no parameters.
+    pub fn evaluate(&self, topology: &Topology, positions: &[DVec3]) -> Option<MmEvaluation> {
+        assert_eq!(positions.len(), topology.len(), "One position per atom");
+        let r: Vec<DVec3> = positions.iter().map(|p| *p / ANGSTROM).collect();
+        let types = &topology.atoms;
+        let mut energy = MmEnergy::default();
+        // Gradient in kcal/(mol⋅Å)
+        let mut gradient = vec![DVec3::ZERO; r.len()];
+
+        for &(a, b) in &topology.bonds {
+            let parameters = BondParameters::for_types(types[a], types[b])?;
+            let displacement = r[a] - r[b];
+            let distance = displacement.length();
+            let stretch = distance - parameters.length;
+            let (e, slope) = match self.bond_model {
+                BondModel::Harmonic => (
+                    parameters.force_constant * stretch * stretch,
+                    2.0 * parameters.force_constant * stretch,
+                ),
+                BondModel::Morse => {
+                    let alpha = parameters.morse_range();
+                    let decay = (-alpha * stretch).exp();
+                    (
+                        parameters.dissociation * (1.0 - decay).powi(2),
+                        2.0 * parameters.dissociation * alpha * decay * (1.0 - decay),
+                    )
+                }
+            };
+            energy.bond += e;
+            let direction = displacement / distance;
+            gradient[a] += slope * direction;
+            gradient[b] -= slope * direction;
+        }
+
+        for [a, b, c] in topology.angles() {
+            let parameters = AngleParameters::for_types(types[a], types[b], types[c])?;
+            let (theta, derivative) = angle_gradient(r[a], r[b], r[c]);
+            let bend = theta - parameters.angle;
+            energy.angle += parameters.force_constant * bend * bend;
+            for (atom, d) in [a, b, c].into_iter().zip(derivative) {
+                gradient[atom] += 2.0 * parameters.force_constant * bend * d;
+            }
+        }
+
+        for [a, b, c, d] in topology.dihedrals() {
+            let Some(parameters) = TorsionParameters::proper(types[b], types[c]) else { continue };
+            let (phi, derivative) = dihedral_gradient(r[a], r[b], r[c], r[d]);
+            let (e, slope) = parameters.energy(phi);
+            energy.dihedral += e;
+            for (atom, g) in [a, b, c, d].into_iter().zip(derivative) {
+                gradient[atom] += slope * g;
+            }
+        }
+
+        for [a, b, centre, c] in topology.impropers() {
+            let neighbours = [types[a], types[b], types[c]];
+            let Some(parameters) = TorsionParameters::improper(types[centre], neighbours) else { continue };
+            let (phi, derivative) = dihedral_gradient(r[a], r[b], r[centre], r[c]);
+            let (e, slope) = parameters.energy(phi);
+            energy.improper += e;
+            for (atom, g) in [a, b, centre, c].into_iter().zip(derivative) {
+                gradient[atom] += slope * g;
+            }
+        }
+
+        let separations = topology.separations();
+        let k = coulomb_kcal_angstrom();
+        for i in 0..r.len() {
+            for j in i + 1..r.len() {
+                let (lj_scale, coulomb_scale) = match classify(separations[i][j]) {
+                    PairKind::Excluded => continue,
+                    PairKind::OneFour => (self.lennard_jones_14, self.coulomb_14),
+                    PairKind::Full => (1.0, 1.0),
+                };
+                let displacement = r[i] - r[j];
+                let distance = displacement.length();
+
+                // Lorentz-Berthelot mixing, E = ε [(R/r)¹² - 2 (R/r)⁶]
+                let ((ri, ei), (rj, ej)) = (types[i].lennard_jones(), types[j].lennard_jones());
+                let (rmin, epsilon) = (ri + rj, (ei * ej).sqrt() * lj_scale);
+                let six = (rmin / distance).powi(6);
+                let lj = epsilon * (six * six - 2.0 * six);
+                let lj_slope = -12.0 * epsilon * (six * six - six) / distance;
+
+                let qq = k * topology.charges[i] * topology.charges[j] * coulomb_scale;
+                let coulomb = qq / distance;
+                let coulomb_slope = -qq / (distance * distance);
+
+                energy.lennard_jones += lj;
+                energy.coulomb += coulomb;
+                let g = (lj_slope + coulomb_slope) * displacement / distance;
+                gradient[i] += g;
+                gradient[j] -= g;
+            }
+        }
+
+        for e in [
+            &mut energy.bond,
+            &mut energy.angle,
+            &mut energy.dihedral,
+            &mut energy.improper,
+            &mut energy.lennard_jones,
+            &mut energy.coulomb,
+        ] {
+            *e *= KILOCALORIE_PER_MOLE;
+        }
+        let forces = gradient.iter().map(|g| -*g * KILOCALORIE_PER_MOLE / ANGSTROM).collect();
+
+        Some(MmEvaluation { energy, forces })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use AtomType::*;
+
+    /// Ethane with the far methyl group rotated by `twist` degrees (60 = staggered).
+    fn ethane(twist: f64) -> (Topology, Vec<DVec3>) {
+        let mut topology = Topology::new().with_atom(CarbonSp3).with_atom(CarbonSp3).with_bond(0, 1);
+        let half = 0.763;
+        let mut positions = vec![DVec3::new(0.0, 0.0, -half), DVec3::new(0.0, 0.0, half)];
+        let tilt = (180.0_f64 - 109.47).to_radians();
+
+        for (carbon, side, offset) in [(0, -1.0, 0.0), (1, 1.0, twist)] {
+            for k in 0..3 {
+                let azimuth = (120.0 * k as f64 + offset).to_radians();
+                let direction = DVec3::new(tilt.sin() * azimuth.cos(), tilt.sin() * azimuth.sin(), side * tilt.cos());
+                positions.push(positions[carbon] + 1.09 * direction);
+                topology = topology.with_atom(HydrogenOnCarbon).with_bond(carbon, positions.len() - 1);
+            }
+        }
+        (topology, positions.into_iter().map(|p| p * ANGSTROM).collect())
+    }
+
+    #[test]
+    fn ethane_topology() {
+        let (topology, _) = ethane(60.0);
+        assert_eq!(topology.bonds.len(), 7);
+        assert_eq!(topology.angles().len(), 12);
+        assert_eq!(topology.dihedrals().len(), 9);
+        assert!(topology.impropers().is_empty());
+
+        // H-C-C-H are 1-4, H-C-H are excluded
+        assert_eq!(topology.pair_kind(2, 5), PairKind::OneFour);
+        assert_eq!(topology.pair_kind(2, 3), PairKind::Excluded);
+        assert_eq!(topology.pair_kind(0, 5), PairKind::Excluded);
+    }
+
+    #[test]
+    fn ethane_rotation_barrier() {
+        let field = ForceField::default();
+        let energy = |twist| {
+            let (topology, positions) = ethane(twist);
+            field.evaluate(&topology, &positions).unwrap().energy.dihedral / KILOCALORIE_PER_MOLE
+        };
+
+        // Staggered is the torsional minimum; eclipsed is ~2.8 kcal/mol higher
+        assert_relative_eq!(energy(60.0), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(energy(0.0), 2.8, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn morse_matches_harmonic_near_equilibrium_and_dissociates() {
+        let topology = Topology::new().with_atom(CarbonSp3).with_atom(CarbonSp3).with_bond(0, 1);
+        let bond_energy = |model, length: f64| {
+            let field = ForceField { bond_model: model, ..Default::default() };
+            let positions = [DVec3::ZERO, DVec3::new(length * ANGSTROM, 0.0, 0.0)];
+            field.evaluate(&topology, &positions).unwrap().energy.bond / KILOCALORIE_PER_MOLE
+        };
+
+        let near = 1.526 + 0.002;
+        assert_relative_eq!(bond_energy(BondModel::Morse, near), bond_energy(BondModel::Harmonic, near), max_relative = 0.01);
+        // Pulled far apart the Morse bond costs D, the harmonic one grows without bound
+        assert_relative_eq!(bond_energy(BondModel::Morse, 10.0), 88.0, max_relative = 1e-6);
+        assert!(bond_energy(BondModel::Harmonic, 10.0) > 10_000.0);
+    }
+
+    #[test]
+    fn improper_keeps_carbonyl_planar() {
+        // Formaldehyde
+        let topology = Topology::new()
+            .with_atom(CarbonSp2)
+            .with_atom(OxygenCarbonyl)
+            .with_atom(HydrogenOnCarbon)
+            .with_atom(HydrogenOnCarbon)
+            .with_bond(0, 1)
+            .with_bond(0, 2)
+            .with_bond(0, 3);
+        let mut positions = vec![
+            DVec3::ZERO,
+            DVec3::new(0.0, 1.229, 0.0),
+            DVec3::new(0.935, -0.54, 0.0),
+            DVec3::new(-0.935, -0.54, 0.0),
+        ];
+        let field = ForceField::default();
+        let improper = |positions: &[DVec3]| {
+            let positions: Vec<DVec3> = positions.iter().map(|p| *p * ANGSTROM).collect();
+            field.evaluate(&topology, &positions).unwrap().energy.improper
+        };
+
+        assert_relative_eq!(improper(&positions), 0.0, epsilon = 1e-30);
+        positions[0].z = 0.2;
+        assert!(improper(&positions) > 0.0);
+    }
+
+    #[test]
+    fn forces_are_minus_energy_gradient() {
+        // Acetic acid in a distorted geometry with partial charges
+        let topology = Topology::new()
+            .with_charged_atom(CarbonSp3, -0.2)
+            .with_charged_atom(HydrogenOnCarbon, 0.08)
+            .with_charged_atom(HydrogenOnCarbon, 0.08)
+            .with_charged_atom(HydrogenOnCarbon, 0.08)
+            .with_charged_atom(CarbonSp2, 0.6)
+            .with_charged_atom(OxygenCarbonyl, -0.5)
+            .with_charged_atom(OxygenHydroxyl, -0.6)
+            .with_charged_atom(HydrogenOnOxygen, 0.46)
+            .with_bond(0, 1)
+            .with_bond(0, 2)
+            .with_bond(0, 3)
+            .with_bond(0, 4)
+            .with_bond(4, 5)
+            .with_bond(4, 6)
+            .with_bond(6, 7);
+        let positions: Vec<DVec3> = [
+            (0.00, 0.00, 0.00),
+            (-0.40, 1.00, 0.10),
+            (-0.35, -0.50, 0.90),
+            (-0.38, -0.55, -0.85),
+            (1.50, 0.05, 0.02),
+            (2.15, 1.05, -0.10),
+            (2.10, -1.15, 0.12),
+            (3.05, -1.05, 0.25),
+        ]
+        .iter()
+        .map(|&(x, y, z)| DVec3::new(x, y, z) * ANGSTROM)
+        .collect();
+
+        for bond_model in [BondModel::Harmonic, BondModel::Morse] {
+            let field = ForceField { bond_model, ..Default::default() };
+            let evaluation = field.evaluate(&topology, &positions).unwrap();
+            let h = 1e-6 * ANGSTROM;
+
+            for atom in 0..positions.len() {
+                for axis in [DVec3::X, DVec3::Y, DVec3::Z] {
+                    let energy_at = |sign: f64| {
+                        let mut moved = positions.clone();
+                        moved[atom] += sign * h * axis;
+                        field.evaluate(&topology, &moved).unwrap().energy.total()
+                    };
+                    let slope = (energy_at(1.0) - energy_at(-1.0)) / (2.0 * h);
+                    let force = evaluation.forces[atom].dot(axis);
+                    assert_relative_eq!(force, -slope, epsilon = 1e-12, max_relative = 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn missing_parameters_are_reported() {
+        let topology = Topology::new().with_atom(OxygenCarbonyl).with_atom(NitrogenAmine).with_bond(0, 1);
+        let positions = [DVec3::ZERO, DVec3::X * ANGSTROM];
+        assert!(ForceField::default().evaluate(&topology, &positions).is_none());
+    }
+}
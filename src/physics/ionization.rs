@@ -0,0 +1,354 @@
+// Ionization detection and measurement
+// An electron is ionized once it is unbound from every nucleus:
+//   E = ½ μ |v_e - v_N|² - kZe²/r ≥ 0 for each nucleus N
+// The measuring tool integrates the work W = ∫ F_spring · dx done by the
+// user's drag spring while pulling an electron off, and compares it with
+// tabulated first ionization energies.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::bohr::{bound_energy, BohrNucleus};
+use super::constants::ELEMENTARY_CHARGE;
+use crate::particles::electron::Electron;
+
+/// First ionization energies in eV for Z = 1..=20 (NIST ASD).
+pub const FIRST_IONIZATION_ENERGIES: [(&str, f64); 20] = [
+    ("H", 13.598),
+    ("He", 24.587),
+    ("Li", 5.392),
+    ("Be", 9.323),
+    ("B", 8.298),
+    ("C", 11.260),
+    ("N", 14.534),
+    ("O", 13.618),
+    ("F", 17.423),
+    ("Ne", 21.565),
+    ("Na", 5.139),
+    ("Mg", 7.646),
+    ("Al", 5.986),
+    ("Si", 8.152),
+    ("P", 10.487),
+    ("S", 10.360),
+    ("Cl", 12.968),
+    ("Ar", 15.760),
+    ("K", 4.341),
+    ("Ca", 6.113),
+];
+
+/// Element symbol for nuclear charge number Z, if it is in the table.
+pub fn element_symbol(z: u32) -> Option<&'static str> {
+    FIRST_IONIZATION_ENERGIES.get((z as usize).checked_sub(1)?).map(|(symbol, _)| *symbol)
+}
+
+/// Tabulated first ionization energy in eV for nuclear charge number Z.
+pub fn first_ionization_energy(z: u32) -> Option<f64> {
+    FIRST_IONIZATION_ENERGIES.get((z as usize).checked_sub(1)?).map(|(_, energy)| *energy)
+}
+
+/// Fired when an electron stops being bound to any nucleus.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Ionized {
+    /// The electron that left
+    pub electron: Entity,
+    /// The nucleus it was last bound to
+    pub nucleus: Entity,
+    /// Energy of the electron relative to that nucleus at detection (Joules, ≥ 0)
+    pub excess_energy: f64,
+}
+
+/// Which nucleus, if any, an electron is currently bound to.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Binding {
+    pub nucleus: Option<Entity>,
+}
+
+/// Charge bookkeeping for a nucleus and the electrons bound to it.
+#[derive(Component, Debug, Clone)]
+pub struct NetCharge {
+    /// Nuclear charge number Z
+    pub z: u32,
+    /// Electrons currently bound to this nucleus
+    pub bound_electrons: u32,
+}
+
+impl NetCharge {
+    /// A neutral atom with Z electrons.
+    pub fn neutral(z: u32) -> Self {
+        Self { z, bound_electrons: z }
+    }
+
+    /// Net charge in units of e (positive for cations)
+    pub fn charge_number(&self) -> i32 {
+        self.z as i32 - self.bound_electrons as i32
+    }
+
+    /// Net charge in Coulombs
+    pub fn charge(&self) -> f64 {
+        self.charge_number() as f64 * ELEMENTARY_CHARGE
+    }
+
+    /// Label like "H", "Na⁺" or "O²⁻".
+    pub fn label(&self) -> String {
+        let symbol = element_symbol(self.z).map(str::to_string).unwrap_or_else(|| format!("Z{}", self.z));
+        let charge = self.charge_number();
+        let sign = if charge > 0 { '⁺' } else { '⁻' };
+
+        match charge.unsigned_abs() {
+            0 => symbol,
+            1 => format!("{symbol}{sign}"),
+            n => format!("{symbol}{}{sign}", superscript(n)),
+        }
+    }
+}
+
+fn superscript(n: u32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    n.to_string().chars().map(|c| DIGITS[c.to_digit(10).unwrap_or(0) as usize]).collect()
+}
+
+/// Index of the nucleus holding the electron most tightly, or `None` if
+/// the electron is unbound from every nucleus.
+pub fn binding_nucleus(electron: &Electron, nuclei: &[BohrNucleus]) -> Option<usize> {
+    nuclei
+        .iter()
+        .map(|nucleus| bound_energy(electron, nucleus))
+        .enumerate()
+        .filter(|(_, energy)| *energy < 0.0)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// Re-evaluate which nucleus an electron is bound to.
+///
+/// # Returns
+/// An `Ionized` event if the electron was bound and no longer is.
+pub fn update_binding(
+    entity: Entity,
+    electron: &Electron,
+    binding: &mut Binding,
+    nuclei: &[BohrNucleus],
+) -> Option<Ionized> {
+    let previous = binding.nucleus;
+    binding.nucleus = binding_nucleus(electron, nuclei).map(|index| nuclei[index].entity);
+
+    if binding.nucleus.is_some() {
+        return None;
+    }
+
+    let nucleus = previous?;
+    let excess_energy = nuclei
+        .iter()
+        .find(|n| n.entity == nucleus)
+        .map(|n| bound_energy(electron, n))
+        .unwrap_or(0.0);
+
+    Some(Ionized { electron: entity, nucleus, excess_energy })
+}
+
+/// Number of electrons whose binding points at `nucleus`.
+pub fn count_bound<'a>(nucleus: Entity, bindings: impl IntoIterator<Item = &'a Binding>) -> u32 {
+    bindings.into_iter().filter(|b| b.nucleus == Some(nucleus)).count() as u32
+}
+
+/// Result of one ionization measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IonizationReading {
+    /// Nuclear charge number of the atom the electron was pulled from
+    pub z: u32,
+    /// Work done by the drag spring in eV
+    pub measured: f64,
+    /// Tabulated first ionization energy in eV, if known
+    pub reference: Option<f64>,
+}
+
+impl IonizationReading {
+    /// (measured - reference) / reference
+    pub fn relative_error(&self) -> Option<f64> {
+        self.reference.map(|reference| (self.measured - reference) / reference)
+    }
+
+    /// One-line report, e.g. "Measured 13.2 eV (H table: 13.60 eV, -3%)".
+    pub fn summary(&self) -> String {
+        let symbol = element_symbol(self.z).map(str::to_string).unwrap_or_else(|| format!("Z{}", self.z));
+        match (self.reference, self.relative_error()) {
+            (Some(reference), Some(error)) => format!(
+                "Measured {:.1} eV ({} table: {:.2} eV, {:+.0}%)",
+                self.measured, symbol, reference, error * 100.0
+            ),
+            _ => format!("Measured {:.1} eV ({symbol}: no table value)", self.measured),
+        }
+    }
+}
+
+/// Tool that measures how much work the drag spring does to pull an
+/// electron off its atom.
+///
+/// Each frame the spring force is recorded with `spring_applied` and the
+/// electron's new position with `electron_moved`; the product of the force
+/// and the displacement is added to the running work.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct IonizationMeter {
+    /// Electron being measured
+    pub electron: Option<Entity>,
+    /// Nuclear charge number of the atom it started on
+    pub z: u32,
+    /// Work done so far in Joules
+    pub work: f64,
+    /// Most recent completed measurement
+    pub reading: Option<IonizationReading>,
+    /// Spring force and electron position at the start of the current step
+    pending: Option<(DVec3, DVec3)>,
+}
+
+impl IonizationMeter {
+    /// Start measuring a drag on `electron`, bound to an atom of charge number `z`.
+    pub fn start(&mut self, electron: Entity, z: u32) {
+        self.electron = Some(electron);
+        self.z = z;
+        self.work = 0.0;
+        self.pending = None;
+    }
+
+    /// Whether a measurement is in progress
+    pub fn is_measuring(&self) -> bool {
+        self.electron.is_some()
+    }
+
+    /// Record the spring force about to act on the electron at `position`.
+    pub fn spring_applied(&mut self, force: DVec3, position: DVec3) {
+        if self.is_measuring() {
+            self.pending = Some((force, position));
+        }
+    }
+
+    /// Record where the electron ended up after the step, adding F · Δx.
+    pub fn electron_moved(&mut self, position: DVec3) {
+        if let Some((force, start)) = self.pending.take() {
+            self.work += force.dot(position - start);
+        }
+    }
+
+    /// Work done so far in eV
+    pub fn work_ev(&self) -> f64 {
+        self.work / ELEMENTARY_CHARGE
+    }
+
+    /// Finish the measurement (the electron has ionized) and store the reading.
+    pub fn finish(&mut self) -> Option<IonizationReading> {
+        self.electron?;
+
+        let reading = IonizationReading {
+            z: self.z,
+            measured: self.work_ev(),
+            reference: first_ionization_energy(self.z),
+        };
+        self.reading = Some(reading);
+        self.cancel();
+        Some(reading)
+    }
+
+    /// Abandon the measurement without a reading (the drag ended early).
+    pub fn cancel(&mut self) {
+        self.electron = None;
+        self.work = 0.0;
+        self.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{BOHR_RADIUS, PROTON_MASS};
+    use approx::assert_relative_eq;
+
+    fn hydrogen(entity: Entity, position: DVec3) -> BohrNucleus {
+        BohrNucleus { entity, position, velocity: DVec3::ZERO, z: 1, mass: PROTON_MASS }
+    }
+
+    #[test]
+    fn table_lookup() {
+        assert_eq!(element_symbol(11), Some("Na"));
+        assert_relative_eq!(first_ionization_energy(1).unwrap(), 13.598);
+        assert!(first_ionization_energy(9).unwrap() > 3.0 * first_ionization_energy(11).unwrap());
+        assert_eq!(first_ionization_energy(0), None);
+        assert_eq!(element_symbol(99), None);
+    }
+
+    #[test]
+    fn net_charge_labels() {
+        let mut sodium = NetCharge::neutral(11);
+        assert_eq!(sodium.charge_number(), 0);
+        assert_eq!(sodium.label(), "Na");
+
+        sodium.bound_electrons = 10;
+        assert_eq!(sodium.label(), "Na⁺");
+        assert_relative_eq!(sodium.charge(), ELEMENTARY_CHARGE);
+
+        let oxide = NetCharge { z: 8, bound_electrons: 10 };
+        assert_eq!(oxide.label(), "O²⁻");
+    }
+
+    #[test]
+    fn ionization_fires_once_when_unbound() {
+        let proton = Entity::from_raw(1);
+        let electron_entity = Entity::from_raw(2);
+        let nuclei = [hydrogen(proton, DVec3::ZERO)];
+        let mut binding = Binding::default();
+
+        // Resting at one Bohr radius: bound
+        let mut electron = Electron::new(DVec3::new(BOHR_RADIUS, 0.0, 0.0));
+        assert!(update_binding(electron_entity, &electron, &mut binding, &nuclei).is_none());
+        assert_eq!(binding.nucleus, Some(proton));
+        assert_eq!(count_bound(proton, [&binding]), 1);
+
+        // Fast enough to escape
+        electron.velocity = DVec3::new(5.0e6, 0.0, 0.0);
+        let event = update_binding(electron_entity, &electron, &mut binding, &nuclei).unwrap();
+        assert_eq!(event.nucleus, proton);
+        assert!(event.excess_energy >= 0.0);
+        assert_eq!(count_bound(proton, [&binding]), 0);
+
+        // Already free: no second event
+        assert!(update_binding(electron_entity, &electron, &mut binding, &nuclei).is_none());
+    }
+
+    #[test]
+    fn binds_to_the_tightest_nucleus() {
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        let nuclei = [
+            hydrogen(far, DVec3::new(10.0 * BOHR_RADIUS, 0.0, 0.0)),
+            hydrogen(near, DVec3::ZERO),
+        ];
+
+        let electron = Electron::new(DVec3::new(BOHR_RADIUS, 0.0, 0.0));
+        assert_eq!(binding_nucleus(&electron, &nuclei), Some(1));
+    }
+
+    #[test]
+    fn meter_integrates_force_along_path() {
+        let mut meter = IonizationMeter::default();
+        let electron = Entity::from_raw(3);
+
+        // Not measuring yet: nothing accumulates
+        meter.spring_applied(DVec3::X, DVec3::ZERO);
+        meter.electron_moved(DVec3::X);
+        assert_eq!(meter.work, 0.0);
+
+        meter.start(electron, 1);
+        let force = DVec3::new(ELEMENTARY_CHARGE, 0.0, 0.0);
+        for step in 0..10 {
+            let x = step as f64;
+            meter.spring_applied(force, DVec3::new(x, 0.0, 0.0));
+            meter.electron_moved(DVec3::new(x + 1.0, 0.5, 0.0));
+        }
+        // Only the component along the force does work: 10 × 1 eV
+        assert_relative_eq!(meter.work_ev(), 10.0, epsilon = 1e-12);
+
+        let reading = meter.finish().unwrap();
+        assert!(!meter.is_measuring());
+        assert_relative_eq!(reading.measured, 10.0, epsilon = 1e-12);
+        assert_relative_eq!(reading.relative_error().unwrap(), (10.0 - 13.598) / 13.598, epsilon = 1e-12);
+        assert!(reading.summary().starts_with("Measured 10.0 eV (H table: 13.60 eV"));
+    }
+}
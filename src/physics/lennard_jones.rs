@@ -0,0 +1,102 @@
+// Tunable Lennard-Jones pair interaction
+//   V(r) = 4ε [(σ/r)¹² - (σ/r)⁶]
+// The well bottom sits at r_m = 2^(1/6) σ with depth -ε, so the two
+// parameters map directly onto the position and depth of the well on a
+// potential curve. Inside `core_fraction`·σ the force is held at its value
+// there: a stiff but finite wall the integrator can survive.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+use super::curves::PairPotential;
+
+/// Ratio of the well position to σ, 2^(1/6)
+pub const WELL_TO_SIGMA: f64 = 1.122_462_048_309_373;
+
+/// Lennard-Jones interaction between every pair of particles in the scene.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct LennardJones {
+    /// Distance where V = 0, in meters
+    pub sigma: f64,
+    /// Well depth in Joules
+    pub epsilon: f64,
+    /// Fraction of σ below which the force stops growing
+    pub core_fraction: f64,
+}
+
+impl Default for LennardJones {
+    fn default() -> Self {
+        Self {
+            sigma: 0.5 * ANGSTROM,
+            epsilon: ELEMENTARY_CHARGE,
+            core_fraction: 0.7,
+        }
+    }
+}
+
+impl LennardJones {
+    /// Separation of the well bottom in meters
+    pub fn well_distance(&self) -> f64 {
+        WELL_TO_SIGMA * self.sigma
+    }
+
+    /// Place the well bottom at a separation (meters) and depth (Joules, positive)
+    pub fn set_well(&mut self, distance: f64, depth: f64) {
+        self.sigma = distance / WELL_TO_SIGMA;
+        self.epsilon = depth;
+    }
+
+    /// Builder form of `set_well`
+    pub fn with_well(mut self, distance: f64, depth: f64) -> Self {
+        self.set_well(distance, depth);
+        self
+    }
+
+    /// The interaction as a plottable pair potential
+    pub fn potential(&self) -> PairPotential {
+        PairPotential::LennardJones { sigma: self.sigma, epsilon: self.epsilon }
+    }
+
+    /// Force on particle 1 due to particle 2 in Newtons (zero when they coincide).
+    pub fn force(&self, r1: DVec3, r2: DVec3) -> DVec3 {
+        let displacement = r1 - r2;
+        let distance = displacement.length();
+        if distance == 0.0 {
+            return DVec3::ZERO;
+        }
+        let clamped = distance.max(self.core_fraction * self.sigma);
+        displacement / distance * self.potential().force(clamped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn well_follows_the_parameters() {
+        let lj = LennardJones::default().with_well(1.5 * ANGSTROM, 0.2 * ELEMENTARY_CHARGE);
+        assert_relative_eq!(lj.well_distance(), 1.5 * ANGSTROM, max_relative = 1e-12);
+        assert_relative_eq!(lj.potential().energy(lj.well_distance()), -0.2 * ELEMENTARY_CHARGE, max_relative = 1e-12);
+        assert!(lj.force(DVec3::X * lj.well_distance(), DVec3::ZERO).length() < 1e-20);
+    }
+
+    #[test]
+    fn force_directions() {
+        let lj = LennardJones::default();
+        let inside = lj.force(DVec3::X * 0.9 * lj.sigma, DVec3::ZERO);
+        let outside = lj.force(DVec3::X * 2.0 * lj.sigma, DVec3::ZERO);
+        assert!(inside.x > 0.0, "Repulsive inside σ");
+        assert!(outside.x < 0.0, "Attractive beyond the well");
+        assert_eq!(lj.force(DVec3::ONE, DVec3::ONE), DVec3::ZERO);
+    }
+
+    #[test]
+    fn core_caps_the_wall() {
+        let lj = LennardJones::default();
+        let at_core = lj.force(DVec3::X * lj.core_fraction * lj.sigma, DVec3::ZERO);
+        let deep = lj.force(DVec3::X * 0.1 * lj.sigma, DVec3::ZERO);
+        assert_relative_eq!(deep.x, at_core.x, max_relative = 1e-12);
+    }
+}
@@ -0,0 +1,135 @@
+// Magnetic field sources and the Lorentz force
+// F = q (E + v × B). The magnetic part is perpendicular to v and does no
+// work; particles moving in it are integrated with `boris_step`.
+
+use bevy::prelude::*;
+use glam::DVec3;
+
+/// A source of magnetic field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MagneticSource {
+    /// Same field everywhere
+    Uniform {
+        /// Field in Tesla
+        field: DVec3,
+    },
+    /// Uniform field inside an axis-aligned box, zero outside (a sector magnet)
+    Region {
+        /// Lower corner in meters
+        min: DVec3,
+        /// Upper corner in meters
+        max: DVec3,
+        /// Field in Tesla
+        field: DVec3,
+    },
+}
+
+impl MagneticSource {
+    /// Field of this source at a point in Tesla
+    pub fn field_at(&self, position: DVec3) -> DVec3 {
+        match *self {
+            MagneticSource::Uniform { field } => field,
+            MagneticSource::Region { min, max, field } => {
+                let inside = position.cmpge(min).all() && position.cmple(max).all();
+                if inside { field } else { DVec3::ZERO }
+            }
+        }
+    }
+}
+
+/// All magnetic fields acting on the scene.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MagneticField {
+    pub sources: Vec<MagneticSource>,
+}
+
+impl MagneticField {
+    /// Add a uniform field in Tesla
+    pub fn with_uniform(mut self, field: DVec3) -> Self {
+        self.sources.push(MagneticSource::Uniform { field });
+        self
+    }
+
+    /// Add a field confined to a box
+    pub fn with_region(mut self, min: DVec3, max: DVec3, field: DVec3) -> Self {
+        self.sources.push(MagneticSource::Region { min, max, field });
+        self
+    }
+
+    /// Total field at a point in Tesla
+    pub fn field_at(&self, position: DVec3) -> DVec3 {
+        self.sources.iter().map(|source| source.field_at(position)).sum()
+    }
+
+    /// Whether there is no field anywhere
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// Calculate the Lorentz force on a moving charge.
+///
+/// # Arguments
+/// * `charge` - Charge in Coulombs
+/// * `velocity` - Velocity in meters per second
+/// * `electric_field` - E in V/m
+/// * `magnetic_field` - B in Tesla
+///
+/// # Returns
+/// Force q(E + v×B) in Newtons.
+pub fn lorentz_force(charge: f64, velocity: DVec3, electric_field: DVec3, magnetic_field: DVec3) -> DVec3 {
+    charge * (electric_field + velocity.cross(magnetic_field))
+}
+
+/// Radius of circular motion perpendicular to a uniform field, r = mv/(|q|B).
+///
+/// # Arguments
+/// * `mass` - Mass in kilograms
+/// * `speed` - Speed perpendicular to B in meters per second
+/// * `charge` - Charge in Coulombs
+/// * `field` - Field strength in Tesla
+pub fn cyclotron_radius(mass: f64, speed: f64, charge: f64, field: f64) -> f64 {
+    mass * speed / (charge.abs() * field)
+}
+
+/// Angular frequency of circular motion in a uniform field, ω = |q|B/m (rad/s).
+pub fn cyclotron_frequency(mass: f64, charge: f64, field: f64) -> f64 {
+    charge.abs() * field / mass
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ELEMENTARY_CHARGE, PROTON_MASS};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn magnetic_force_is_perpendicular_to_velocity() {
+        let velocity = DVec3::new(3.0, 4.0, 0.0);
+        let force = lorentz_force(ELEMENTARY_CHARGE, velocity, DVec3::ZERO, DVec3::Z);
+
+        assert_relative_eq!(force.dot(velocity), 0.0, epsilon = 1e-30);
+        assert_relative_eq!(force.length(), 5.0 * ELEMENTARY_CHARGE, max_relative = 1e-12);
+        // v × B for v = x, B = z points along -y
+        assert!(lorentz_force(1.0, DVec3::X, DVec3::ZERO, DVec3::Z).y < 0.0);
+    }
+
+    #[test]
+    fn region_field_is_confined() {
+        let field = MagneticField::default()
+            .with_region(DVec3::ZERO, DVec3::ONE, DVec3::Z)
+            .with_uniform(DVec3::new(0.0, 0.0, 0.5));
+
+        assert_eq!(field.field_at(DVec3::splat(0.5)).z, 1.5);
+        assert_eq!(field.field_at(DVec3::splat(2.0)).z, 0.5);
+    }
+
+    #[test]
+    fn cyclotron_radius_and_frequency_agree() {
+        let (speed, b) = (2.0e5, 0.5);
+        let radius = cyclotron_radius(PROTON_MASS, speed, ELEMENTARY_CHARGE, b);
+        let omega = cyclotron_frequency(PROTON_MASS, ELEMENTARY_CHARGE, b);
+        // v = ωr
+        assert_relative_eq!(omega * radius, speed, max_relative = 1e-12);
+    }
+}
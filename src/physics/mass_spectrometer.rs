@@ -0,0 +1,219 @@
+// Magnetic-sector mass spectrometer
+// Ions are accelerated through a voltage V, enter a uniform field B and
+// bend on a half circle onto the detector:
+//   ½mv² = |q|V,   r = mv/(|q|B) = √(2V (m/|q|)) / B
+// so the landing point depends only on m/z and heavy isotopes land further out.
+
+use glam::DVec3;
+use super::constants::{ATOMIC_MASS_UNIT, ELEMENTARY_CHARGE};
+use super::magnetic::{cyclotron_frequency, cyclotron_radius, MagneticField};
+use super::simulation::{boris_step, Integratable};
+
+/// An ion to be sorted by the spectrometer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ion {
+    /// Name shown in the spectrum, e.g. "²⁰Ne⁺"
+    pub label: String,
+    /// Mass in kilograms
+    pub mass: f64,
+    /// Charge in Coulombs
+    pub charge: f64,
+}
+
+impl Ion {
+    /// An isotope of given mass (in u) and charge number z.
+    pub fn isotope(label: &str, mass_u: f64, charge_number: i32) -> Self {
+        assert!(charge_number != 0, "Only ions are deflected");
+        Self {
+            label: label.to_string(),
+            mass: mass_u * ATOMIC_MASS_UNIT,
+            charge: charge_number as f64 * ELEMENTARY_CHARGE,
+        }
+    }
+
+    /// Mass-to-charge ratio m/z in u per elementary charge
+    pub fn mass_to_charge(&self) -> f64 {
+        (self.mass / ATOMIC_MASS_UNIT) / (self.charge.abs() / ELEMENTARY_CHARGE)
+    }
+}
+
+/// Point-like ion state for integration.
+struct IonParticle {
+    position: DVec3,
+    velocity: DVec3,
+    mass: f64,
+}
+
+impl Integratable for IonParticle {
+    fn position(&self) -> DVec3 { self.position }
+    fn velocity(&self) -> DVec3 { self.velocity }
+    fn force(&self) -> DVec3 { DVec3::ZERO }
+    fn mass(&self) -> f64 { self.mass }
+
+    fn set_position(&mut self, pos: DVec3) { self.position = pos; }
+    fn set_velocity(&mut self, vel: DVec3) { self.velocity = vel; }
+    fn clear_forces(&mut self) {}
+}
+
+/// The path of one ion through the magnet.
+#[derive(Debug, Clone)]
+pub struct Flight {
+    pub label: String,
+    /// m/z in u per elementary charge
+    pub mass_to_charge: f64,
+    /// Trajectory in meters, from the entrance slit to the detector
+    pub points: Vec<DVec3>,
+    /// Landing position along the detector (x axis) in meters;
+    /// positive ions land at +x, negative ions at -x
+    pub landing: f64,
+}
+
+/// A magnetic-sector spectrometer.
+///
+/// Ions enter at the origin moving along +y into a field along +z that
+/// fills the half-space y ≥ 0; the detector lies along the x axis.
+#[derive(Debug, Clone)]
+pub struct MassSpectrometer {
+    /// Accelerating voltage in Volts
+    pub accelerating_voltage: f64,
+    /// Magnet field strength in Tesla
+    pub field: f64,
+    /// Integration steps per full cyclotron turn
+    pub steps_per_turn: usize,
+}
+
+impl Default for MassSpectrometer {
+    fn default() -> Self {
+        Self {
+            accelerating_voltage: 1000.0,
+            field: 0.5,
+            steps_per_turn: 720,
+        }
+    }
+}
+
+impl MassSpectrometer {
+    /// Speed after the accelerating gap, v = √(2|q|V/m)
+    pub fn entry_speed(&self, ion: &Ion) -> f64 {
+        (2.0 * ion.charge.abs() * self.accelerating_voltage / ion.mass).sqrt()
+    }
+
+    /// Radius of the ion's half circle in the magnet
+    pub fn expected_radius(&self, ion: &Ion) -> f64 {
+        cyclotron_radius(ion.mass, self.entry_speed(ion), ion.charge, self.field)
+    }
+
+    /// The magnet as a field region (large enough for any practical orbit)
+    pub fn magnet(&self) -> MagneticField {
+        MagneticField::default().with_region(
+            DVec3::new(-10.0, 0.0, -10.0),
+            DVec3::new(10.0, 10.0, 10.0),
+            DVec3::new(0.0, 0.0, self.field),
+        )
+    }
+
+    /// Fly one ion through the magnet with the Boris integrator.
+    pub fn fly(&self, ion: &Ion) -> Flight {
+        let magnet = self.magnet();
+        let period = std::f64::consts::TAU / cyclotron_frequency(ion.mass, ion.charge, self.field);
+        let dt = period / self.steps_per_turn as f64;
+
+        let mut particle = IonParticle {
+            position: DVec3::ZERO,
+            velocity: DVec3::new(0.0, self.entry_speed(ion), 0.0),
+            mass: ion.mass,
+        };
+        // Boris keeps velocities half a step behind the positions, so start
+        // from v(-dt/2) to keep the orbit tangent to +y at the slit
+        boris_step(&mut particle, ion.charge, magnet.field_at(DVec3::ZERO), -0.5 * dt);
+        particle.position = DVec3::ZERO;
+
+        let mut points = vec![particle.position];
+
+        // A half turn, with margin, brings it back to the detector plane
+        for _ in 0..self.steps_per_turn {
+            let previous = particle.position;
+            let b = magnet.field_at(previous);
+            boris_step(&mut particle, ion.charge, b, dt);
+
+            if particle.position.y < 0.0 {
+                // Interpolate to where the path crosses y = 0
+                let fraction = previous.y / (previous.y - particle.position.y);
+                let landing = previous.lerp(particle.position, fraction);
+                points.push(landing);
+                break;
+            }
+            points.push(particle.position);
+        }
+
+        Flight {
+            label: ion.label.clone(),
+            mass_to_charge: ion.mass_to_charge(),
+            landing: points.last().map_or(0.0, |p| p.x),
+            points,
+        }
+    }
+
+    /// Fly every ion and return the flights sorted by landing distance.
+    pub fn spectrum(&self, ions: &[Ion]) -> Vec<Flight> {
+        let mut flights: Vec<Flight> = ions.iter().map(|ion| self.fly(ion)).collect();
+        flights.sort_by(|a, b| a.landing.abs().total_cmp(&b.landing.abs()));
+        flights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn landing_is_twice_the_cyclotron_radius() {
+        let spectrometer = MassSpectrometer::default();
+        let neon = Ion::isotope("²⁰Ne⁺", 19.992, 1);
+        let flight = spectrometer.fly(&neon);
+
+        // r = mv/(qB), landing at 2r
+        let expected = neon.mass * spectrometer.entry_speed(&neon) / (neon.charge * spectrometer.field);
+        assert_relative_eq!(spectrometer.expected_radius(&neon), expected, max_relative = 1e-12);
+        assert_relative_eq!(flight.landing, 2.0 * expected, max_relative = 1e-3);
+
+        // Every point stays on the circle through the origin centred at (r, 0)
+        let centre = DVec3::new(expected, 0.0, 0.0);
+        for point in &flight.points {
+            assert_relative_eq!(point.distance(centre), expected, max_relative = 1e-3);
+        }
+    }
+
+    #[test]
+    fn isotopes_separate_by_mass_to_charge() {
+        let spectrometer = MassSpectrometer::default();
+        let ions = [
+            Ion::isotope("²²Ne⁺", 21.991, 1),
+            Ion::isotope("²⁰Ne⁺", 19.992, 1),
+            // Twice the mass and twice the charge: almost the same m/z as ²⁰Ne⁺
+            Ion::isotope("⁴⁰Ar²⁺", 39.962, 2),
+        ];
+        let spectrum = spectrometer.spectrum(&ions);
+        let landing = |label: &str| spectrum.iter().find(|f| f.label == label).unwrap().landing;
+
+        assert_eq!(spectrum[2].label, "²²Ne⁺");
+        // r ∝ √(m/z)
+        let ratio = landing("²²Ne⁺") / landing("²⁰Ne⁺");
+        assert_relative_eq!(ratio, (21.991_f64 / 19.992).sqrt(), max_relative = 1e-4);
+
+        let ratio = landing("⁴⁰Ar²⁺") / landing("²⁰Ne⁺");
+        assert_relative_eq!(ratio, (19.981_f64 / 19.992).sqrt(), max_relative = 1e-4);
+        assert_relative_eq!(spectrum[0].mass_to_charge, 19.981, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn negative_ions_bend_the_other_way() {
+        let spectrometer = MassSpectrometer::default();
+        let chloride = Ion::isotope("³⁵Cl⁻", 34.969, -1);
+        let flight = spectrometer.fly(&chloride);
+
+        assert!(flight.landing < 0.0);
+        assert_relative_eq!(-flight.landing, 2.0 * spectrometer.expected_radius(&chloride), max_relative = 1e-3);
+    }
+}
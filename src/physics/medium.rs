@@ -0,0 +1,202 @@
+// Dielectric medium and Debye–Hückel implicit solvent
+// In a medium of relative permittivity ε_r with dissolved ions, the bare
+// Coulomb interaction is weakened and screened:
+//   V(r) = k q1 q2 e^(-κr) / (ε_r r),   κ² = 2 N_A e² I / (ε_r ε₀ k_B T)
+// where I is the ionic strength. Gaussian clouds keep their smoothed
+// vacuum shape and are screened by the same factor.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{AVOGADRO_CONSTANT, BOLTZMANN_CONSTANT, ELEMENTARY_CHARGE, VACUUM_PERMITTIVITY};
+use super::coulomb::{gaussian_coulomb_force_magnitude, gaussian_coulomb_potential, SourceCharge};
+
+/// The medium a scene takes place in.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct Medium {
+    /// Relative permittivity ε_r (1 for vacuum, ≈78.4 for water at 25 °C)
+    pub relative_permittivity: f64,
+    /// Ionic strength of dissolved salt in mol/L (0 = no screening)
+    pub ionic_strength: f64,
+    /// Temperature in Kelvin (sets the Debye length)
+    pub temperature: f64,
+}
+
+impl Default for Medium {
+    fn default() -> Self {
+        Self::vacuum()
+    }
+}
+
+impl Medium {
+    /// Empty space: the raw Coulomb law
+    pub fn vacuum() -> Self {
+        Self {
+            relative_permittivity: 1.0,
+            ionic_strength: 0.0,
+            temperature: 298.15,
+        }
+    }
+
+    /// Pure water at 25 °C
+    pub fn water() -> Self {
+        Self {
+            relative_permittivity: 78.4,
+            ..Self::vacuum()
+        }
+    }
+
+    /// Salt water at 25 °C with the given ionic strength in mol/L
+    pub fn saline(ionic_strength: f64) -> Self {
+        Self::water().with_ionic_strength(ionic_strength)
+    }
+
+    /// Set the ionic strength in mol/L
+    pub fn with_ionic_strength(mut self, ionic_strength: f64) -> Self {
+        assert!(ionic_strength >= 0.0, "Ionic strength cannot be negative");
+        self.ionic_strength = ionic_strength;
+        self
+    }
+
+    /// Inverse Debye length κ in 1/m (0 without dissolved ions)
+    pub fn inverse_debye_length(&self) -> f64 {
+        // mol/L → mol/m³
+        let concentration = self.ionic_strength * 1000.0;
+        let numerator = 2.0 * AVOGADRO_CONSTANT * ELEMENTARY_CHARGE.powi(2) * concentration;
+        let denominator = self.relative_permittivity * VACUUM_PERMITTIVITY * BOLTZMANN_CONSTANT * self.temperature;
+        (numerator / denominator).sqrt()
+    }
+
+    /// Debye screening length 1/κ in meters, or `None` without dissolved ions
+    pub fn debye_length(&self) -> Option<f64> {
+        let kappa = self.inverse_debye_length();
+        (kappa > 0.0).then(|| 1.0 / kappa)
+    }
+
+    /// Factor e^(-κr)/ε_r applied to the vacuum potential at distance r
+    pub fn screening(&self, distance: f64) -> f64 {
+        (-self.inverse_debye_length() * distance).exp() / self.relative_permittivity
+    }
+
+    /// Electrostatic potential energy of two (Gaussian) charges in Joules.
+    ///
+    /// # Arguments
+    /// * `q1`, `q2` - Charges in Coulombs
+    /// * `distance` - Separation in meters
+    /// * `width1`, `width2` - Gaussian widths σ in meters (0 for point charges)
+    pub fn potential_energy(&self, q1: f64, q2: f64, distance: f64, width1: f64, width2: f64) -> f64 {
+        gaussian_coulomb_potential(q1, q2, distance, width1, width2) * self.screening(distance)
+    }
+
+    /// Magnitude of the force between two (Gaussian) charges in Newtons.
+    ///
+    /// F = -d/dr [V₀(r) e^(-κr)]/ε_r = [F₀(r) + κ V₀(r)] e^(-κr)/ε_r.
+    /// Positive for repulsion, negative for attraction.
+    pub fn force_magnitude(&self, q1: f64, q2: f64, distance: f64, width1: f64, width2: f64) -> f64 {
+        let kappa = self.inverse_debye_length();
+        let vacuum_force = gaussian_coulomb_force_magnitude(q1, q2, distance, width1, width2);
+        let screening_force = if kappa > 0.0 {
+            kappa * gaussian_coulomb_potential(q1, q2, distance, width1, width2)
+        } else {
+            0.0
+        };
+        (vacuum_force + screening_force) * self.screening(distance)
+    }
+
+    /// Force on charge 1 due to charge 2 in Newtons (zero when the centres coincide).
+    pub fn force(&self, q1: f64, q2: f64, r1: DVec3, r2: DVec3, width1: f64, width2: f64) -> DVec3 {
+        let displacement = r1 - r2;
+        let distance = displacement.length();
+        if distance == 0.0 {
+            return DVec3::ZERO;
+        }
+        displacement / distance * self.force_magnitude(q1, q2, distance, width1, width2)
+    }
+
+    /// Electric field at a point due to all source charges, in V/m.
+    pub fn electric_field(&self, point: DVec3, charges: &[SourceCharge]) -> DVec3 {
+        charges
+            .iter()
+            .map(|source| self.force(1.0, source.charge, point, source.position, 0.0, source.width))
+            .sum()
+    }
+
+    /// Electric potential at a point due to all source charges, in Volts.
+    pub fn electric_potential(&self, point: DVec3, charges: &[SourceCharge]) -> f64 {
+        charges
+            .iter()
+            .map(|source| {
+                let distance = point.distance(source.position);
+                if distance == 0.0 && source.width == 0.0 {
+                    return source.charge.signum() * f64::INFINITY;
+                }
+                self.potential_energy(1.0, source.charge, distance, 0.0, source.width)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::ANGSTROM;
+    use crate::physics::coulomb::{coulomb_force, electric_field};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vacuum_matches_plain_coulomb() {
+        let vacuum = Medium::vacuum();
+        let (r1, r2) = (DVec3::ZERO, DVec3::new(ANGSTROM, 0.0, 0.0));
+        let force = vacuum.force(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, r1, r2, 0.0, 0.0);
+
+        assert_eq!(vacuum.debye_length(), None);
+        assert_relative_eq!(force.x, coulomb_force(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, r1, r2).x, max_relative = 1e-12);
+
+        let charges = [SourceCharge::point(r2, ELEMENTARY_CHARGE)];
+        assert_eq!(vacuum.electric_field(r1, &charges), electric_field(r1, &charges));
+    }
+
+    #[test]
+    fn water_weakens_forces_by_its_permittivity() {
+        let distance = 3.0 * ANGSTROM;
+        let vacuum = Medium::vacuum().force_magnitude(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, distance, 0.0, 0.0);
+        let water = Medium::water().force_magnitude(ELEMENTARY_CHARGE, ELEMENTARY_CHARGE, distance, 0.0, 0.0);
+        assert_relative_eq!(vacuum / water, 78.4, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn debye_length_of_saline() {
+        // 1/κ ≈ 0.304 nm / √I for a 1:1 salt in water at 25 °C
+        let debye = Medium::saline(0.1).debye_length().unwrap();
+        assert_relative_eq!(debye / 1.0e-9, 0.304 / 0.1_f64.sqrt(), max_relative = 0.01);
+
+        // Quadrupling the salt halves the screening length
+        let concentrated = Medium::saline(0.4).debye_length().unwrap();
+        assert_relative_eq!(debye / concentrated, 2.0, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn screened_force_is_minus_gradient() {
+        let medium = Medium::saline(0.15);
+        let (q1, q2, width) = (ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, 0.4 * ANGSTROM);
+        let r = 2.5 * ANGSTROM;
+        let h = 1e-5 * ANGSTROM;
+
+        let slope = (medium.potential_energy(q1, q2, r + h, width, 0.0)
+            - medium.potential_energy(q1, q2, r - h, width, 0.0)) / (2.0 * h);
+        assert_relative_eq!(medium.force_magnitude(q1, q2, r, width, 0.0), -slope, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn salt_dissolves_in_water() {
+        // Na⁺Cl⁻ at contact (2.8 Å): tightly bound in vacuum, about thermal in water
+        let contact = 2.8 * ANGSTROM;
+        let thermal = BOLTZMANN_CONSTANT * 298.15;
+        let binding = |medium: Medium| {
+            -medium.potential_energy(ELEMENTARY_CHARGE, -ELEMENTARY_CHARGE, contact, 0.0, 0.0)
+        };
+
+        assert!(binding(Medium::vacuum()) > 100.0 * thermal);
+        assert!(binding(Medium::water()) < 5.0 * thermal);
+        assert!(binding(Medium::saline(0.5)) < binding(Medium::water()));
+    }
+}
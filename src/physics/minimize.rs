@@ -0,0 +1,431 @@
+// Geometry optimization
+// "Relax this structure": move the particles downhill on the potential
+// energy surface until the forces vanish. Three methods:
+// - Steepest descent: step along the force, growing the step while the
+//   energy drops and halving it when it rises. Robust but slow.
+// - FIRE (Fast Inertial Relaxation Engine): damped dynamics that steers
+//   the velocity toward the force and speeds up while going downhill.
+// - L-BFGS: quasi-Newton steps from the last few gradient differences,
+//   with a backtracking line search. Fastest near the minimum.
+// Works on plain position arrays, so it runs headlessly (e.g. to prepare
+// lesson starting geometries) as well as behind a button in the app.
+
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+
+/// Marks a particle the optimizer must leave where it is.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Pinned;
+
+/// Optimization algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    SteepestDescent,
+    Fire,
+    #[default]
+    Lbfgs,
+}
+
+/// Optimizer settings.
+#[derive(Resource, Debug, Clone)]
+pub struct MinimizerConfig {
+    pub method: Method,
+    /// Converged once no free particle feels more than this force (Newtons)
+    pub max_force: f64,
+    /// Converged once the energy changes by less than this on two
+    /// successive steps (Joules; 0 disables)
+    pub energy_change: f64,
+    /// Largest distance any particle moves in one step (meters)
+    pub max_step: f64,
+    pub max_steps: usize,
+    /// Correction pairs L-BFGS remembers
+    pub memory: usize,
+}
+
+impl Default for MinimizerConfig {
+    fn default() -> Self {
+        Self {
+            method: Method::default(),
+            // 0.01 eV/Å
+            max_force: 0.01 * ELEMENTARY_CHARGE / ANGSTROM,
+            energy_change: 1e-8 * ELEMENTARY_CHARGE,
+            max_step: 0.2 * ANGSTROM,
+            max_steps: 5000,
+            memory: 8,
+        }
+    }
+}
+
+/// Why the optimizer stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Convergence {
+    /// Largest force below `max_force`
+    MaxForce,
+    /// Energy no longer changing
+    EnergyChange,
+    /// Ran out of steps
+    StepLimit,
+}
+
+/// The relaxed structure.
+#[derive(Debug, Clone)]
+pub struct Minimization {
+    /// Final positions in meters
+    pub positions: Vec<DVec3>,
+    /// Final energy in Joules
+    pub energy: f64,
+    /// Largest force on a free particle in Newtons
+    pub max_force: f64,
+    /// Energy evaluations used
+    pub evaluations: usize,
+    pub steps: usize,
+    pub convergence: Convergence,
+}
+
+impl Minimization {
+    /// Whether a convergence criterion was met
+    pub fn converged(&self) -> bool {
+        self.convergence != Convergence::StepLimit
+    }
+}
+
+/// Evaluates the energy, keeping count and zeroing forces on pinned particles.
+struct Surface<'a, F> {
+    evaluate: F,
+    pinned: &'a [bool],
+    evaluations: usize,
+}
+
+impl<F: FnMut(&[DVec3]) -> (f64, Vec<DVec3>)> Surface<'_, F> {
+    fn at(&mut self, positions: &[DVec3]) -> (f64, Vec<DVec3>) {
+        self.evaluations += 1;
+        let (energy, mut forces) = (self.evaluate)(positions);
+        for (force, &pinned) in forces.iter_mut().zip(self.pinned) {
+            if pinned {
+                *force = DVec3::ZERO;
+            }
+        }
+        (energy, forces)
+    }
+}
+
+fn largest(vectors: &[DVec3]) -> f64 {
+    vectors.iter().map(|v| v.length()).fold(0.0, f64::max)
+}
+
+fn dot(a: &[DVec3], b: &[DVec3]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x.dot(*y)).sum()
+}
+
+/// Tracks the convergence criteria between steps.
+struct Monitor<'a> {
+    config: &'a MinimizerConfig,
+    quiet_steps: usize,
+}
+
+impl Monitor<'_> {
+    fn check(&mut self, forces: &[DVec3], energy_change: f64) -> Option<Convergence> {
+        if largest(forces) < self.config.max_force {
+            return Some(Convergence::MaxForce);
+        }
+        if energy_change.abs() < self.config.energy_change {
+            self.quiet_steps += 1;
+        } else {
+            self.quiet_steps = 0;
+        }
+        (self.quiet_steps >= 2).then_some(Convergence::EnergyChange)
+    }
+}
+
+/// Relax a structure to the nearest energy minimum.
+///
+/// # Arguments
+/// * `positions` - Starting positions in meters
+/// * `pinned` - Particles that must not move (empty slice: none)
+/// * `config` - Method and convergence criteria
+/// * `evaluate` - Returns the energy (Joules) and the force on each particle (Newtons)
+pub fn minimize<F>(positions: &[DVec3], pinned: &[bool], config: &MinimizerConfig, evaluate: F) -> Minimization
+where
+    F: FnMut(&[DVec3]) -> (f64, Vec<DVec3>),
+{
+    let free = vec![false; positions.len()];
+    let pinned = if pinned.is_empty() { &free[..] } else { pinned };
+    assert_eq!(pinned.len(), positions.len(), "One pinned flag per particle");
+
+    let mut surface = Surface { evaluate, pinned, evaluations: 0 };
+    let mut monitor = Monitor { config, quiet_steps: 0 };
+    let mut x = positions.to_vec();
+    let (mut energy, mut forces) = surface.at(&x);
+    let mut convergence = Convergence::StepLimit;
+    let mut steps = 0;
+
+    if largest(&forces) < config.max_force {
+        convergence = Convergence::MaxForce;
+    }
+
+    // Method state
+    let mut step_length = config.max_step;
+    let mut velocity = vec![DVec3::ZERO; x.len()];
+    let mut fire = FireState::default();
+    let force_scale = largest(&forces).max(f64::MIN_POSITIVE);
+    let mut history: VecDeque<(Vec<DVec3>, Vec<DVec3>)> = VecDeque::new();
+
+    while convergence == Convergence::StepLimit && steps < config.max_steps {
+        steps += 1;
+        let previous = energy;
+        // Rejected trial steps leave the energy unchanged but prove nothing
+        let mut moved = true;
+
+        match config.method {
+            Method::SteepestDescent => {
+                let scale = step_length / largest(&forces);
+                let trial: Vec<DVec3> = x.iter().zip(&forces).map(|(p, f)| *p + *f * scale).collect();
+                let (trial_energy, trial_forces) = surface.at(&trial);
+                if trial_energy < energy {
+                    (x, energy, forces) = (trial, trial_energy, trial_forces);
+                    step_length = (1.2 * step_length).min(config.max_step);
+                } else {
+                    step_length *= 0.5;
+                    moved = false;
+                }
+            }
+            Method::Fire => {
+                // Displacements come in units of max_step
+                let displacements = fire.step(&mut velocity, &forces, force_scale);
+                for (p, d) in x.iter_mut().zip(displacements) {
+                    *p += d.clamp_length_max(1.0) * config.max_step;
+                }
+                (energy, forces) = surface.at(&x);
+            }
+            Method::Lbfgs => {
+                let gradient: Vec<DVec3> = forces.iter().map(|f| -*f).collect();
+                let mut direction = lbfgs_direction(&gradient, &history);
+                if dot(&direction, &gradient) >= 0.0 {
+                    // Not downhill: forget the curvature and fall back to the force
+                    history.clear();
+                    direction = forces.clone();
+                }
+                // Without curvature information the force only gives a direction
+                let limit = config.max_step / largest(&direction);
+                let scale = if history.is_empty() { limit } else { limit.min(1.0) };
+                direction.iter_mut().for_each(|d| *d *= scale);
+
+                // Backtracking line search (Armijo condition)
+                let slope = dot(&direction, &gradient);
+                let mut alpha = 1.0;
+                let mut accepted = None;
+                for _ in 0..20 {
+                    let trial: Vec<DVec3> = x.iter().zip(&direction).map(|(p, d)| *p + *d * alpha).collect();
+                    let (trial_energy, trial_forces) = surface.at(&trial);
+                    if trial_energy <= energy + 1e-4 * alpha * slope {
+                        accepted = Some((trial, trial_energy, trial_forces));
+                        break;
+                    }
+                    alpha *= 0.5;
+                }
+
+                match accepted {
+                    Some((trial, trial_energy, trial_forces)) => {
+                        let s: Vec<DVec3> = trial.iter().zip(&x).map(|(a, b)| *a - *b).collect();
+                        let y: Vec<DVec3> = trial_forces.iter().zip(&forces).map(|(a, b)| *b - *a).collect();
+                        if dot(&s, &y) > 0.0 {
+                            history.push_back((s, y));
+                            if history.len() > config.memory {
+                                history.pop_front();
+                            }
+                        }
+                        (x, energy, forces) = (trial, trial_energy, trial_forces);
+                    }
+                    None => {
+                        history.clear();
+                        moved = false;
+                    }
+                }
+            }
+        }
+
+        if !moved {
+            continue;
+        }
+        if let Some(reason) = monitor.check(&forces, energy - previous) {
+            convergence = reason;
+        }
+    }
+
+    Minimization {
+        positions: x,
+        energy,
+        max_force: largest(&forces),
+        evaluations: surface.evaluations,
+        steps,
+        convergence,
+    }
+}
+
+/// L-BFGS two-loop recursion: an approximation of -H⁻¹ g.
+fn lbfgs_direction(gradient: &[DVec3], history: &VecDeque<(Vec<DVec3>, Vec<DVec3>)>) -> Vec<DVec3> {
+    let mut q = gradient.to_vec();
+    let mut alphas = Vec::with_capacity(history.len());
+    for (s, y) in history.iter().rev() {
+        let rho = 1.0 / dot(y, s);
+        let alpha = rho * dot(s, &q);
+        q.iter_mut().zip(y).for_each(|(qi, yi)| *qi -= alpha * *yi);
+        alphas.push((rho, alpha));
+    }
+
+    // Initial inverse Hessian γI from the most recent pair
+    let gamma = history.back().map_or(1.0, |(s, y)| dot(s, y) / dot(y, y));
+    q.iter_mut().for_each(|qi| *qi *= gamma);
+
+    for ((s, y), (rho, alpha)) in history.iter().zip(alphas.into_iter().rev()) {
+        let beta = rho * dot(y, &q);
+        q.iter_mut().zip(s).for_each(|(qi, si)| *qi += (alpha - beta) * *si);
+    }
+    q.iter().map(|qi| -*qi).collect()
+}
+
+/// FIRE parameters and adaptive state (Bitzek et al. 2006).
+struct FireState {
+    dt: f64,
+    alpha: f64,
+    downhill_steps: usize,
+}
+
+impl Default for FireState {
+    fn default() -> Self {
+        Self { dt: 0.1, alpha: 0.1, downhill_steps: 0 }
+    }
+}
+
+impl FireState {
+    const DT_MAX: f64 = 1.0;
+    const ALPHA_START: f64 = 0.1;
+    const MIN_DOWNHILL: usize = 5;
+
+    /// Update velocities (unit mass, forces scaled by the initial largest force).
+    ///
+    /// # Returns
+    /// The displacement of each particle, v dt.
+    fn step(&mut self, velocity: &mut [DVec3], forces: &[DVec3], force_scale: f64) -> Vec<DVec3> {
+        let scaled: Vec<DVec3> = forces.iter().map(|f| *f / force_scale).collect();
+        velocity.iter_mut().zip(&scaled).for_each(|(v, f)| *v += *f * self.dt);
+
+        if dot(velocity, &scaled) > 0.0 {
+            // Steer the velocity toward the force
+            let speed = dot(velocity, velocity).sqrt();
+            let strength = dot(&scaled, &scaled).sqrt();
+            for (v, f) in velocity.iter_mut().zip(&scaled) {
+                *v = (1.0 - self.alpha) * *v + self.alpha * speed * *f / strength;
+            }
+            self.downhill_steps += 1;
+            if self.downhill_steps > Self::MIN_DOWNHILL {
+                self.dt = (1.1 * self.dt).min(Self::DT_MAX);
+                self.alpha *= 0.99;
+            }
+        } else {
+            // Uphill: stop and start again more carefully
+            velocity.iter_mut().for_each(|v| *v = DVec3::ZERO);
+            self.dt *= 0.5;
+            self.alpha = Self::ALPHA_START;
+            self.downhill_steps = 0;
+        }
+        velocity.iter().map(|v| *v * self.dt).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::force_field::{AtomType, ForceField, Topology};
+    use approx::assert_relative_eq;
+
+    /// Ethane with stretched, squashed bonds and a half-eclipsed twist
+    fn distorted_ethane() -> (Topology, Vec<DVec3>) {
+        use AtomType::*;
+        let mut topology = Topology::new().with_atom(CarbonSp3).with_atom(CarbonSp3).with_bond(0, 1);
+        let mut positions = vec![DVec3::new(0.0, 0.0, -0.9), DVec3::new(0.0, 0.0, 0.8)];
+        for (carbon, side, twist, length) in [(0, -1.0, 0.0, 1.2), (1, 1.0, 25.0, 0.95)] {
+            for k in 0..3 {
+                let azimuth = (120.0 * k as f64 + twist).to_radians();
+                let direction = DVec3::new(0.94 * azimuth.cos(), 0.94 * azimuth.sin(), side * 0.34);
+                positions.push(positions[carbon] + length * direction);
+                topology = topology.with_atom(HydrogenOnCarbon).with_bond(carbon, positions.len() - 1);
+            }
+        }
+        (topology, positions.into_iter().map(|p| p * ANGSTROM).collect())
+    }
+
+    fn relax(method: Method, pinned: &[bool]) -> Minimization {
+        let (topology, positions) = distorted_ethane();
+        let field = ForceField::default();
+        let config = MinimizerConfig { method, energy_change: 0.0, max_steps: 20_000, ..Default::default() };
+        minimize(&positions, pinned, &config, |x| {
+            let evaluation = field.evaluate(&topology, x).unwrap();
+            (evaluation.energy.total(), evaluation.forces)
+        })
+    }
+
+    #[test]
+    fn all_methods_reach_the_same_minimum() {
+        let results: Vec<Minimization> =
+            [Method::SteepestDescent, Method::Fire, Method::Lbfgs].map(|m| relax(m, &[])).into();
+
+        for result in &results {
+            assert_eq!(result.convergence, Convergence::MaxForce);
+            assert!(result.max_force < MinimizerConfig::default().max_force);
+            // Relaxed C-C bond near r₀ = 1.526 Å
+            let cc = result.positions[0].distance(result.positions[1]) / ANGSTROM;
+            assert_relative_eq!(cc, 1.526, epsilon = 0.02);
+        }
+        let energies: Vec<f64> = results.iter().map(|r| r.energy / ELEMENTARY_CHARGE).collect();
+        assert_relative_eq!(energies[0], energies[2], epsilon = 1e-3);
+        assert_relative_eq!(energies[1], energies[2], epsilon = 1e-3);
+
+        // The quasi-Newton method needs far fewer evaluations than steepest descent
+        assert!(results[2].evaluations < results[0].evaluations);
+    }
+
+    #[test]
+    fn pinned_particles_stay_put() {
+        let (_, start) = distorted_ethane();
+        let mut pinned = vec![false; start.len()];
+        pinned[0] = true;
+        pinned[3] = true;
+
+        for method in [Method::SteepestDescent, Method::Fire, Method::Lbfgs] {
+            let result = relax(method, &pinned);
+            assert!(result.converged());
+            assert_eq!(result.positions[0], start[0]);
+            assert_eq!(result.positions[3], start[3]);
+            assert_ne!(result.positions[1], start[1]);
+        }
+    }
+
+    #[test]
+    fn energy_change_criterion_stops_early() {
+        // A very flat quadratic well: the energy barely changes per step
+        let stiffness = 1e-30;
+        let config = MinimizerConfig {
+            method: Method::SteepestDescent,
+            max_force: 0.0,
+            energy_change: 1e-6 * ELEMENTARY_CHARGE,
+            ..Default::default()
+        };
+        let result = minimize(&[DVec3::new(1e-9, 0.0, 0.0)], &[], &config, |x| {
+            (0.5 * stiffness * x[0].length_squared(), vec![-stiffness * x[0]])
+        });
+        assert_eq!(result.convergence, Convergence::EnergyChange);
+        assert!(result.steps < 10);
+    }
+
+    #[test]
+    fn already_relaxed_structure_needs_no_steps() {
+        let result = minimize(&[DVec3::ZERO], &[], &MinimizerConfig::default(), |x| {
+            (x[0].length_squared(), vec![-2.0 * x[0]])
+        });
+        assert_eq!(result.steps, 0);
+        assert_eq!(result.convergence, Convergence::MaxForce);
+    }
+}
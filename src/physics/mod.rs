@@ -3,3 +3,25 @@
 pub mod constants;
 pub mod coulomb;
 pub mod simulation;
+pub mod schrodinger;
+pub mod wavepacket;
+pub mod spectroscopy;
+pub mod bohr;
+pub mod ionization;
+pub mod qeq;
+pub mod field_lines;
+pub mod external;
+pub mod magnetic;
+pub mod mass_spectrometer;
+pub mod medium;
+pub mod force_field;
+pub mod bonding;
+pub mod bond_order;
+pub mod vsepr;
+pub mod minimize;
+pub mod curves;
+pub mod lennard_jones;
+pub mod observables;
+pub mod structure;
+pub mod diffusion;
+pub mod conservation;
@@ -0,0 +1,344 @@
+// Thermodynamic observables of a set of particles
+// Temperature follows from equipartition, ½kT per quadratic degree of freedom:
+//   T = 2 K / (f k_B),  f = N·d - (d if net momentum is fixed) - constraints
+// Speeds of an equilibrated gas follow Maxwell–Boltzmann,
+//   f(v) = 4π v² (m / 2πkT)^(3/2) e^(-mv²/2kT)       (3D)
+//   f(v) = (m v / kT) e^(-mv²/2kT)                   (2D)
+// and each velocity component is Gaussian with variance kT/m.
+
+use glam::DVec3;
+use super::constants::BOLTZMANN_CONSTANT;
+use super::simulation::{kinetic_energy, Integratable};
+use crate::math::random::Random;
+
+/// How many independent quadratic velocity terms a system has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegreesOfFreedom {
+    /// Spatial dimensions the particles move in (2 for the planar scene, 3 in space)
+    pub dimensions: usize,
+    /// Whether the total momentum is held at zero (removes one term per dimension)
+    pub momentum_removed: bool,
+    /// Holonomic constraints such as rigid bonds (one term each)
+    pub constraints: usize,
+}
+
+impl Default for DegreesOfFreedom {
+    fn default() -> Self {
+        Self {
+            dimensions: 3,
+            momentum_removed: true,
+            constraints: 0,
+        }
+    }
+}
+
+impl DegreesOfFreedom {
+    /// Particles confined to the xy plane
+    pub fn planar() -> Self {
+        Self { dimensions: 2, ..Self::default() }
+    }
+
+    /// Add holonomic constraints
+    pub fn with_constraints(mut self, constraints: usize) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Degrees of freedom of n particles (0 if the constraints use them all)
+    pub fn count(&self, particles: usize) -> usize {
+        let removed = if self.momentum_removed { self.dimensions } else { 0 } + self.constraints;
+        (particles * self.dimensions).saturating_sub(removed)
+    }
+}
+
+/// Total kinetic energy in Joules
+pub fn total_kinetic_energy<T: Integratable>(particles: &[T]) -> f64 {
+    particles.iter().map(kinetic_energy).sum()
+}
+
+/// Total momentum in kg⋅m/s
+pub fn total_momentum<T: Integratable>(particles: &[T]) -> DVec3 {
+    particles.iter().map(|p| p.mass() * p.velocity()).sum()
+}
+
+/// Velocity of the centre of mass in m/s (zero for an empty set)
+pub fn center_of_mass_velocity<T: Integratable>(particles: &[T]) -> DVec3 {
+    let mass: f64 = particles.iter().map(|p| p.mass()).sum();
+    if mass == 0.0 {
+        return DVec3::ZERO;
+    }
+    total_momentum(particles) / mass
+}
+
+/// Kinetic temperature in Kelvin, or None when there are no degrees of freedom.
+pub fn temperature<T: Integratable>(particles: &[T], dof: &DegreesOfFreedom) -> Option<f64> {
+    let count = dof.count(particles.len());
+    (count > 0).then(|| 2.0 * total_kinetic_energy(particles) / (count as f64 * BOLTZMANN_CONSTANT))
+}
+
+/// Temperature carried by each Cartesian axis, in Kelvin.
+///
+/// In equilibrium every active axis holds the same share; a hot x and a
+/// cold y means energy is not being exchanged between them. Axes beyond
+/// `dof.dimensions` read 0. Their mean over the active axes is `temperature`.
+pub fn axis_temperatures<T: Integratable>(particles: &[T], dof: &DegreesOfFreedom) -> [f64; 3] {
+    let per_axis = dof.count(particles.len()) as f64 / dof.dimensions as f64;
+    let mut temperatures = [0.0; 3];
+    if per_axis <= 0.0 {
+        return temperatures;
+    }
+    for (axis, temperature) in temperatures.iter_mut().enumerate().take(dof.dimensions) {
+        let twice_kinetic: f64 = particles.iter().map(|p| p.mass() * p.velocity()[axis].powi(2)).sum();
+        *temperature = twice_kinetic / (per_axis * BOLTZMANN_CONSTANT);
+    }
+    temperatures
+}
+
+/// Maxwell–Boltzmann probability density of a single velocity component (per m/s)
+pub fn maxwell_boltzmann_component(velocity: f64, mass: f64, temperature: f64) -> f64 {
+    let variance = BOLTZMANN_CONSTANT * temperature / mass;
+    (-velocity * velocity / (2.0 * variance)).exp() / (std::f64::consts::TAU * variance).sqrt()
+}
+
+/// Maxwell–Boltzmann probability density of the speed (per m/s) in 2 or 3 dimensions
+pub fn maxwell_boltzmann_speed(speed: f64, mass: f64, temperature: f64, dimensions: usize) -> f64 {
+    if speed < 0.0 {
+        return 0.0;
+    }
+    let a = mass / (BOLTZMANN_CONSTANT * temperature);
+    let boltzmann = (-0.5 * a * speed * speed).exp();
+    match dimensions {
+        2 => a * speed * boltzmann,
+        3 => (2.0 / std::f64::consts::PI).sqrt() * a.powf(1.5) * speed * speed * boltzmann,
+        _ => panic!("Maxwell–Boltzmann speeds are defined here for 2 or 3 dimensions"),
+    }
+}
+
+/// Equal-width histogram of a scalar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Left edge of the first bin
+    pub lower: f64,
+    /// Width of every bin
+    pub bin_width: f64,
+    /// Samples in each bin
+    pub counts: Vec<usize>,
+    /// All samples added, including those outside the bins
+    pub total: usize,
+}
+
+/// Goodness of fit of a histogram to a probability density.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistogramFit {
+    /// Pearson χ² over the bins that expect at least 5 samples
+    pub chi_squared: f64,
+    /// Number of bins that entered χ²
+    pub bins: usize,
+}
+
+impl HistogramFit {
+    /// χ² per bin; close to 1 when the samples follow the density
+    pub fn reduced_chi_squared(&self) -> f64 {
+        self.chi_squared / self.bins.max(1) as f64
+    }
+}
+
+impl Histogram {
+    /// Empty histogram of `bins` equal bins spanning [lower, upper)
+    pub fn new(lower: f64, upper: f64, bins: usize) -> Self {
+        assert!(upper > lower && bins > 0, "Histogram needs a range and at least one bin");
+        Self {
+            lower,
+            bin_width: (upper - lower) / bins as f64,
+            counts: vec![0; bins],
+            total: 0,
+        }
+    }
+
+    /// Count one sample
+    pub fn add(&mut self, value: f64) {
+        self.total += 1;
+        let bin = ((value - self.lower) / self.bin_width).floor();
+        if bin >= 0.0 && (bin as usize) < self.counts.len() {
+            self.counts[bin as usize] += 1;
+        }
+    }
+
+    /// Centre of bin i
+    pub fn centre(&self, bin: usize) -> f64 {
+        self.lower + (bin as f64 + 0.5) * self.bin_width
+    }
+
+    /// Estimated probability density in bin i (integrates to the in-range fraction)
+    pub fn density(&self, bin: usize) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.counts[bin] as f64 / (self.total as f64 * self.bin_width)
+    }
+
+    /// Expected count in every bin for samples drawn from a density (Simpson's rule per bin)
+    pub fn expected_counts(&self, pdf: impl Fn(f64) -> f64) -> Vec<f64> {
+        (0..self.counts.len())
+            .map(|bin| {
+                let left = self.lower + bin as f64 * self.bin_width;
+                let h = self.bin_width / 4.0;
+                let integral = h / 3.0
+                    * (pdf(left) + 4.0 * pdf(left + h) + 2.0 * pdf(left + 2.0 * h) + 4.0 * pdf(left + 3.0 * h) + pdf(left + 4.0 * h));
+                integral * self.total as f64
+            })
+            .collect()
+    }
+
+    /// Compare the samples with a probability density.
+    pub fn fit(&self, pdf: impl Fn(f64) -> f64) -> HistogramFit {
+        let mut fit = HistogramFit { chi_squared: 0.0, bins: 0 };
+        for (&observed, expected) in self.counts.iter().zip(self.expected_counts(pdf)) {
+            if expected >= 5.0 {
+                fit.chi_squared += (observed as f64 - expected).powi(2) / expected;
+                fit.bins += 1;
+            }
+        }
+        fit
+    }
+}
+
+/// Histogram of particle speeds over [0, max_speed)
+pub fn speed_histogram<T: Integratable>(particles: &[T], max_speed: f64, bins: usize) -> Histogram {
+    let mut histogram = Histogram::new(0.0, max_speed, bins);
+    for particle in particles {
+        histogram.add(particle.velocity().length());
+    }
+    histogram
+}
+
+/// Histogram of one velocity component (axis 0, 1 or 2) over [-limit, limit)
+pub fn velocity_histogram<T: Integratable>(particles: &[T], axis: usize, limit: f64, bins: usize) -> Histogram {
+    let mut histogram = Histogram::new(-limit, limit, bins);
+    for particle in particles {
+        histogram.add(particle.velocity()[axis]);
+    }
+    histogram
+}
+
+/// Give particles random thermal velocities at a target temperature.
+///
+/// Components are drawn from the Maxwell–Boltzmann distribution of each
+/// particle's mass, the centre-of-mass drift is removed (when `dof` says
+/// momentum is fixed), and the result is rescaled so `temperature` returns
+/// exactly the target. Axes beyond `dof.dimensions` are set to zero.
+pub fn initialize_velocities<T: Integratable>(
+    particles: &mut [T],
+    target: f64,
+    dof: &DegreesOfFreedom,
+    random: &mut Random,
+) {
+    for particle in particles.iter_mut() {
+        let spread = (BOLTZMANN_CONSTANT * target / particle.mass()).sqrt();
+        let mut velocity = DVec3::ZERO;
+        for axis in 0..dof.dimensions {
+            velocity[axis] = spread * random.gaussian();
+        }
+        particle.set_velocity(velocity);
+    }
+
+    if dof.momentum_removed {
+        let drift = center_of_mass_velocity(particles);
+        for particle in particles.iter_mut() {
+            particle.set_velocity(particle.velocity() - drift);
+        }
+    }
+
+    if let Some(current) = temperature(particles, dof).filter(|&t| t > 0.0) {
+        let scale = (target / current).sqrt();
+        for particle in particles.iter_mut() {
+            particle.set_velocity(particle.velocity() * scale);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::particles::proton::Proton;
+    use crate::physics::constants::PROTON_MASS;
+    use approx::assert_relative_eq;
+
+    fn gas(count: usize, target: f64, dof: &DegreesOfFreedom, seed: u64) -> Vec<Proton> {
+        let mut particles: Vec<Proton> = (0..count).map(|i| Proton::new(DVec3::X * i as f64)).collect();
+        initialize_velocities(&mut particles, target, dof, &mut Random::new(seed));
+        particles
+    }
+
+    #[test]
+    fn degrees_of_freedom() {
+        assert_eq!(DegreesOfFreedom::default().count(10), 27);
+        assert_eq!(DegreesOfFreedom::planar().with_constraints(4).count(10), 14);
+        assert_eq!(DegreesOfFreedom { momentum_removed: false, ..DegreesOfFreedom::planar() }.count(1), 2);
+        assert_eq!(DegreesOfFreedom::default().count(1), 0);
+
+        // A single particle whose momentum is pinned has no temperature
+        let still = [Proton::new(DVec3::ZERO)];
+        assert_eq!(temperature(&still, &DegreesOfFreedom::default()), None);
+    }
+
+    #[test]
+    fn two_body_temperature() {
+        // Equal and opposite: zero momentum, 3 remaining degrees of freedom
+        let mut particles = [Proton::new(DVec3::ZERO), Proton::new(DVec3::X)];
+        particles[0].velocity = DVec3::new(1000.0, 0.0, 0.0);
+        particles[1].velocity = DVec3::new(-1000.0, 0.0, 0.0);
+
+        let kinetic = PROTON_MASS * 1.0e6;
+        assert_relative_eq!(total_kinetic_energy(&particles), kinetic, max_relative = 1e-12);
+        let t = temperature(&particles, &DegreesOfFreedom::default()).unwrap();
+        assert_relative_eq!(t, 2.0 * kinetic / (3.0 * BOLTZMANN_CONSTANT), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn initialization_hits_the_target_with_no_drift() {
+        let dof = DegreesOfFreedom::default();
+        let particles = gas(500, 300.0, &dof, 11);
+
+        assert_relative_eq!(temperature(&particles, &dof).unwrap(), 300.0, max_relative = 1e-10);
+        let momentum = total_momentum(&particles).length();
+        assert!(momentum < 1e-12 * PROTON_MASS * 2000.0, "Net momentum {momentum}");
+    }
+
+    #[test]
+    fn equipartition_across_axes() {
+        let planar = DegreesOfFreedom::planar();
+        let particles = gas(4000, 500.0, &planar, 3);
+        let [tx, ty, tz] = axis_temperatures(&particles, &planar);
+        assert!((tx - 500.0).abs() < 25.0 && (ty - 500.0).abs() < 25.0, "{tx} {ty}");
+        assert_eq!(tz, 0.0);
+        assert_relative_eq!(0.5 * (tx + ty), 500.0, max_relative = 1e-10);
+    }
+
+    #[test]
+    fn speeds_follow_maxwell_boltzmann() {
+        let dof = DegreesOfFreedom::default();
+        let particles = gas(20_000, 300.0, &dof, 5);
+        let thermal = (BOLTZMANN_CONSTANT * 300.0 / PROTON_MASS).sqrt();
+
+        // The densities are normalised
+        let mut range = Histogram::new(0.0, 10.0 * thermal, 50);
+        range.total = 1;
+        for dimensions in [2, 3] {
+            let probability: f64 = range.expected_counts(|v| maxwell_boltzmann_speed(v, PROTON_MASS, 300.0, dimensions)).iter().sum();
+            assert_relative_eq!(probability, 1.0, max_relative = 1e-6);
+        }
+
+        let histogram = speed_histogram(&particles, 5.0 * thermal, 40);
+        let fit = histogram.fit(|v| maxwell_boltzmann_speed(v, PROTON_MASS, 300.0, 3));
+        assert!(fit.bins > 30 && fit.reduced_chi_squared() < 2.0, "{fit:?}");
+
+        let components = velocity_histogram(&particles, 1, 5.0 * thermal, 40);
+        let fit = components.fit(|v| maxwell_boltzmann_component(v, PROTON_MASS, 300.0));
+        assert!(fit.reduced_chi_squared() < 2.0, "{fit:?}");
+
+        // Against the wrong temperature the fit is clearly poor
+        let wrong = histogram.fit(|v| maxwell_boltzmann_speed(v, PROTON_MASS, 400.0, 3));
+        assert!(wrong.reduced_chi_squared() > 10.0, "{wrong:?}");
+    }
+}
@@ -0,0 +1,255 @@
+// Charge equilibration (QEq / electronegativity equalization)
+// Partial charges minimise
+//   E(q) = Σᵢ (χᵢ qᵢ + ηᵢ qᵢ²) + Σᵢ<ⱼ Jᵢⱼ(rᵢⱼ) qᵢ qⱼ   subject to Σ qᵢ = Q
+// Setting every ∂E/∂qᵢ equal to a common electronegativity χ̄ gives one linear
+// system per geometry. Close pairs use a shielded Coulomb interaction
+//   Jᵢⱼ = k / (r³ + γᵢⱼ⁻³)^(1/3),   γᵢⱼ = √(γᵢγⱼ)
+// so that overlapping atoms do not polarise without bound.
+// Internally in Ångström and eV.
+
+use glam::DVec3;
+use super::constants::{ANGSTROM, COULOMB_CONSTANT, DEBYE, ELEMENTARY_CHARGE};
+use crate::math::linalg::solve_linear;
+
+/// Electronegativity equalization parameters for one element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QeqParameters {
+    /// Electronegativity χ in eV
+    pub electronegativity: f64,
+    /// Hardness η in eV (self-energy η q²)
+    pub hardness: f64,
+    /// Shielding parameter γ in 1/Å
+    pub shielding: f64,
+}
+
+impl QeqParameters {
+    /// Parameters for nuclear charge number Z, from ReaxFF-style EEM sets.
+    pub fn for_element(z: u32) -> Option<Self> {
+        let (electronegativity, hardness, shielding) = match z {
+            1 => (3.7248, 9.6093, 0.8203),
+            6 => (5.9666, 7.0000, 0.9000),
+            7 => (6.8418, 6.9235, 0.9745),
+            8 => (8.5000, 8.3122, 1.0898),
+            _ => return None,
+        };
+        Some(Self { electronegativity, hardness, shielding })
+    }
+}
+
+/// Coulomb constant in eV⋅Å/e²
+fn coulomb_ev_angstrom() -> f64 {
+    COULOMB_CONSTANT * ELEMENTARY_CHARGE / ANGSTROM
+}
+
+/// Shielded Coulomb interaction between unit charges in eV.
+///
+/// # Arguments
+/// * `distance` - Separation in Å
+/// * `a`, `b` - Parameters of the two atoms
+pub fn shielded_coulomb(distance: f64, a: &QeqParameters, b: &QeqParameters) -> f64 {
+    let gamma = (a.shielding * b.shielding).sqrt();
+    coulomb_ev_angstrom() / (distance.powi(3) + gamma.powi(-3)).cbrt()
+}
+
+/// An atom taking part in charge equilibration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QeqAtom {
+    /// Nuclear charge number Z
+    pub z: u32,
+    /// Position in meters
+    pub position: DVec3,
+}
+
+/// A molecule (or cluster) whose partial charges are equilibrated.
+#[derive(Debug, Clone, Default)]
+pub struct QeqSystem {
+    pub atoms: Vec<QeqAtom>,
+    /// Total charge in units of e
+    pub total_charge: f64,
+}
+
+impl QeqSystem {
+    /// An empty, neutral system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an atom of charge number `z` at `position` (meters).
+    pub fn with_atom(mut self, z: u32, position: DVec3) -> Self {
+        self.atoms.push(QeqAtom { z, position });
+        self
+    }
+
+    /// Set the total charge in units of e.
+    pub fn with_total_charge(mut self, charge: f64) -> Self {
+        self.total_charge = charge;
+        self
+    }
+
+    /// Move the atoms to a new geometry (same order as they were added).
+    pub fn set_positions(&mut self, positions: &[DVec3]) {
+        assert_eq!(positions.len(), self.atoms.len(), "One position per atom");
+        for (atom, &position) in self.atoms.iter_mut().zip(positions) {
+            atom.position = position;
+        }
+    }
+
+    /// Equilibrate the charges for the current geometry.
+    ///
+    /// # Returns
+    /// `None` if an element has no parameters or the system is empty.
+    pub fn solve(&self) -> Option<QeqSolution> {
+        let n = self.atoms.len();
+        if n == 0 {
+            return None;
+        }
+
+        let parameters: Vec<QeqParameters> = self.atoms
+            .iter()
+            .map(|atom| QeqParameters::for_element(atom.z))
+            .collect::<Option<_>>()?;
+
+        // Rows 0..n: χᵢ + 2ηᵢqᵢ + Σⱼ Jᵢⱼqⱼ - χ̄ = 0; row n: Σ qᵢ = Q
+        let mut matrix = vec![vec![0.0; n + 1]; n + 1];
+        let mut rhs = vec![0.0; n + 1];
+        for i in 0..n {
+            for j in 0..n {
+                matrix[i][j] = if i == j {
+                    2.0 * parameters[i].hardness
+                } else {
+                    let distance = self.atoms[i].position.distance(self.atoms[j].position) / ANGSTROM;
+                    shielded_coulomb(distance, &parameters[i], &parameters[j])
+                };
+            }
+            matrix[i][n] = -1.0;
+            matrix[n][i] = 1.0;
+            rhs[i] = -parameters[i].electronegativity;
+        }
+        rhs[n] = self.total_charge;
+
+        let mut solution = solve_linear(&matrix, &rhs)?;
+        let electronegativity = solution.pop()?;
+
+        Some(QeqSolution {
+            charges: solution,
+            positions: self.atoms.iter().map(|atom| atom.position).collect(),
+            electronegativity,
+        })
+    }
+}
+
+/// Equilibrated partial charges for one geometry.
+#[derive(Debug, Clone)]
+pub struct QeqSolution {
+    /// Partial charge of each atom in units of e
+    pub charges: Vec<f64>,
+    /// Atom positions the charges were solved for (meters)
+    pub positions: Vec<DVec3>,
+    /// Equalized electronegativity ∂E/∂qᵢ in eV, the same on every atom
+    pub electronegativity: f64,
+}
+
+impl QeqSolution {
+    /// Molecular dipole moment Σ qᵢ rᵢ in C⋅m.
+    ///
+    /// Taken about the centre of charge magnitude, so it is origin
+    /// independent for ions as well as neutral molecules.
+    pub fn dipole_moment(&self) -> DVec3 {
+        let total: f64 = self.charges.iter().sum();
+        let weight: f64 = self.charges.iter().map(|q| q.abs()).sum();
+        let origin = if weight > 0.0 {
+            self.charges.iter().zip(&self.positions).map(|(q, r)| q.abs() * *r).sum::<DVec3>() / weight
+        } else {
+            DVec3::ZERO
+        };
+
+        let moment: DVec3 = self.charges.iter().zip(&self.positions).map(|(q, r)| *q * *r).sum();
+        (moment - total * origin) * ELEMENTARY_CHARGE
+    }
+
+    /// Magnitude of the dipole moment in Debye
+    pub fn dipole_debye(&self) -> f64 {
+        self.dipole_moment().length() / DEBYE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn water() -> QeqSystem {
+        // Gas-phase geometry: r(OH) = 0.9572 Å, ∠HOH = 104.52°
+        let half_angle = 104.52_f64.to_radians() / 2.0;
+        let bond = 0.9572 * ANGSTROM;
+        QeqSystem::new()
+            .with_atom(8, DVec3::ZERO)
+            .with_atom(1, DVec3::new(bond * half_angle.sin(), bond * half_angle.cos(), 0.0))
+            .with_atom(1, DVec3::new(-bond * half_angle.sin(), bond * half_angle.cos(), 0.0))
+    }
+
+    #[test]
+    fn water_oxygen_is_negative_with_realistic_dipole() {
+        let solution = water().solve().unwrap();
+        let [o, h1, h2] = solution.charges[..] else { panic!("three atoms") };
+
+        assert!(o < 0.0, "Oxygen should carry the negative charge: {o}");
+        assert!(h1 > 0.0);
+        assert_relative_eq!(h1, h2, epsilon = 1e-12);
+        assert_relative_eq!(o + h1 + h2, 0.0, epsilon = 1e-12);
+
+        let dipole = solution.dipole_debye();
+        assert!((dipole - 1.85).abs() < 0.15, "Water dipole {dipole} D, expected ≈1.85 D");
+
+        // Points from the oxygen toward the hydrogens
+        assert!(solution.dipole_moment().y > 0.0);
+    }
+
+    #[test]
+    fn charges_follow_geometry() {
+        let mut system = water();
+        let near = system.solve().unwrap().charges[1];
+
+        // Pull the hydrogens far away: weaker O–H coupling, less charge transfer
+        let far = 3.0 * ANGSTROM;
+        system.set_positions(&[DVec3::ZERO, DVec3::new(far, 0.0, 0.0), DVec3::new(-far, 0.0, 0.0)]);
+        let stretched = system.solve().unwrap();
+
+        assert!(stretched.charges[1] < near);
+        // Linear and symmetric: no dipole
+        assert_relative_eq!(stretched.dipole_debye(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn total_charge_is_conserved_for_ions() {
+        // Hydroxide, OH⁻
+        let solution = QeqSystem::new()
+            .with_atom(8, DVec3::ZERO)
+            .with_atom(1, DVec3::new(0.97 * ANGSTROM, 0.0, 0.0))
+            .with_total_charge(-1.0)
+            .solve()
+            .unwrap();
+
+        assert_relative_eq!(solution.charges.iter().sum::<f64>(), -1.0, epsilon = 1e-12);
+        assert!(solution.charges[0] < solution.charges[1]);
+    }
+
+    #[test]
+    fn equal_atoms_share_equally() {
+        let solution = QeqSystem::new()
+            .with_atom(6, DVec3::ZERO)
+            .with_atom(6, DVec3::new(1.54 * ANGSTROM, 0.0, 0.0))
+            .solve()
+            .unwrap();
+
+        assert_relative_eq!(solution.charges[0], 0.0, epsilon = 1e-12);
+        let carbon = QeqParameters::for_element(6).unwrap();
+        assert_relative_eq!(solution.electronegativity, carbon.electronegativity, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn unknown_elements_are_rejected() {
+        assert!(QeqSystem::new().with_atom(92, DVec3::ZERO).solve().is_none());
+        assert!(QeqSystem::new().solve().is_none());
+    }
+}
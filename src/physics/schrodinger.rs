@@ -0,0 +1,216 @@
+// Stationary 1D Schrödinger equation by finite differences
+// -ℏ²/(2m) ψ'' + V(x) ψ = E ψ, with ψ = 0 at both walls
+// ψ'' ≈ (ψ[i+1] - 2ψ[i] + ψ[i-1]) / dx² turns this into a tridiagonal eigenproblem
+
+use super::constants::HBAR;
+use crate::math::linalg::tridiagonal_lowest_eigen;
+
+/// A uniform grid between two hard walls.
+///
+/// Only interior points are stored; the wavefunction is pinned to zero at
+/// `x_min` and `x_max`, so the walls themselves form an infinite box.
+#[derive(Debug, Clone)]
+pub struct Grid1D {
+    /// Left wall position in meters
+    pub x_min: f64,
+    /// Right wall position in meters
+    pub x_max: f64,
+    /// Number of interior grid points
+    pub points: usize,
+}
+
+impl Grid1D {
+    /// Create a grid with `points` interior points between the walls
+    pub fn new(x_min: f64, x_max: f64, points: usize) -> Self {
+        assert!(x_max > x_min, "Grid needs x_max > x_min");
+        assert!(points >= 2, "Grid needs at least two interior points");
+        Self { x_min, x_max, points }
+    }
+
+    /// Distance between neighbouring points in meters
+    pub fn spacing(&self) -> f64 {
+        (self.x_max - self.x_min) / (self.points + 1) as f64
+    }
+
+    /// Positions of the interior points in meters
+    pub fn positions(&self) -> Vec<f64> {
+        let dx = self.spacing();
+        (1..=self.points).map(|i| self.x_min + i as f64 * dx).collect()
+    }
+}
+
+/// Lowest bound states of a 1D potential.
+#[derive(Debug, Clone)]
+pub struct StationaryStates {
+    /// Grid positions in meters (shared by every wavefunction)
+    pub positions: Vec<f64>,
+    /// Energy eigenvalues in Joules, ascending
+    pub energies: Vec<f64>,
+    /// Wavefunctions ψ(x) in m^(-1/2), normalized so Σ|ψ|²dx = 1
+    pub wavefunctions: Vec<Vec<f64>>,
+}
+
+impl StationaryStates {
+    /// Probability density |ψ|² of state `n` at each grid point (per meter)
+    pub fn probability_density(&self, n: usize) -> Vec<f64> {
+        self.wavefunctions[n].iter().map(|psi| psi * psi).collect()
+    }
+}
+
+/// Solve for the lowest `count` eigenstates of a particle in potential V(x).
+///
+/// # Arguments
+/// * `potential` - V(x) in Joules for x in meters
+/// * `mass` - Particle mass in kilograms
+/// * `grid` - Grid and wall positions
+/// * `count` - Number of states to return
+pub fn solve_stationary_states<V>(potential: V, mass: f64, grid: &Grid1D, count: usize) -> StationaryStates
+where
+    V: Fn(f64) -> f64,
+{
+    let dx = grid.spacing();
+    let positions = grid.positions();
+
+    // Kinetic term: ℏ²/(2m dx²) × [-1, 2, -1]
+    let kinetic = HBAR * HBAR / (2.0 * mass * dx * dx);
+    let diagonal: Vec<f64> = positions.iter().map(|&x| 2.0 * kinetic + potential(x)).collect();
+    let off_diagonal = vec![-kinetic; grid.points - 1];
+
+    let eigen = tridiagonal_lowest_eigen(&diagonal, &off_diagonal, count);
+
+    // Unit vectors → continuum normalization, with a consistent sign convention
+    let wavefunctions = eigen
+        .vectors
+        .into_iter()
+        .map(|vector| {
+            let sign = vector.iter().find(|v| v.abs() > 1e-8).map_or(1.0, |v| v.signum());
+            vector.into_iter().map(|v| sign * v / dx.sqrt()).collect()
+        })
+        .collect();
+
+    StationaryStates { positions, energies: eigen.values, wavefunctions }
+}
+
+/// Harmonic potential V(x) = ½ m ω² (x - x₀)².
+pub fn harmonic_potential(mass: f64, angular_frequency: f64, center: f64) -> impl Fn(f64) -> f64 {
+    move |x| 0.5 * mass * angular_frequency.powi(2) * (x - center).powi(2)
+}
+
+/// Symmetric double well V(x) = V₀ ((x/a)² - 1)², minima at ±a and a barrier V₀ at 0.
+pub fn double_well_potential(barrier_height: f64, half_separation: f64) -> impl Fn(f64) -> f64 {
+    move |x| barrier_height * ((x / half_separation).powi(2) - 1.0).powi(2)
+}
+
+/// Exact infinite-box level E_n = n²π²ℏ²/(2mL²), n = 1, 2, 3, ...
+pub fn particle_in_box_energy(n: u32, mass: f64, length: f64) -> f64 {
+    let n = n as f64;
+    n * n * std::f64::consts::PI.powi(2) * HBAR * HBAR / (2.0 * mass * length * length)
+}
+
+/// Exact harmonic oscillator level E_n = ℏω(n + ½), n = 0, 1, 2, ...
+pub fn harmonic_oscillator_energy(n: u32, angular_frequency: f64) -> f64 {
+    HBAR * angular_frequency * (n as f64 + 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ANGSTROM, ELECTRON_MASS, ELEMENTARY_CHARGE};
+    use approx::assert_relative_eq;
+
+    /// Count sign changes, ignoring the tiny tails near the walls
+    fn node_count(psi: &[f64]) -> usize {
+        let peak = psi.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+        let significant: Vec<f64> = psi.iter().copied().filter(|v| v.abs() > 1e-6 * peak).collect();
+        significant.windows(2).filter(|w| w[0] * w[1] < 0.0).count()
+    }
+
+    #[test]
+    fn particle_in_a_box_levels() {
+        // Electron in a 1 nm box: E₁ ≈ 0.376 eV, E_n ∝ n²
+        let length = 10.0 * ANGSTROM;
+        let grid = Grid1D::new(0.0, length, 1000);
+        let states = solve_stationary_states(|_| 0.0, ELECTRON_MASS, &grid, 4);
+
+        assert_relative_eq!(states.energies[0] / ELEMENTARY_CHARGE, 0.376, epsilon = 1e-3);
+        for n in 1..=4 {
+            let exact = particle_in_box_energy(n, ELECTRON_MASS, length);
+            assert_relative_eq!(states.energies[n as usize - 1], exact, max_relative = 1e-4);
+        }
+    }
+
+    #[test]
+    fn harmonic_oscillator_levels() {
+        // ℏω = 1 eV for an electron; walls far enough away not to matter
+        let omega = ELEMENTARY_CHARGE / HBAR;
+        let grid = Grid1D::new(-30.0 * ANGSTROM, 30.0 * ANGSTROM, 2000);
+        let states = solve_stationary_states(
+            harmonic_potential(ELECTRON_MASS, omega, 0.0),
+            ELECTRON_MASS,
+            &grid,
+            5,
+        );
+
+        for n in 0..5 {
+            let exact = harmonic_oscillator_energy(n, omega);
+            assert_relative_eq!(states.energies[n as usize], exact, max_relative = 1e-3);
+        }
+
+        // Evenly spaced ladder: ΔE = ℏω
+        let spacing = states.energies[3] - states.energies[2];
+        assert_relative_eq!(spacing, HBAR * omega, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn wavefunctions_are_normalized_with_n_nodes() {
+        let grid = Grid1D::new(0.0, 5.0 * ANGSTROM, 500);
+        let states = solve_stationary_states(|_| 0.0, ELECTRON_MASS, &grid, 4);
+        let dx = grid.spacing();
+
+        for n in 0..4 {
+            let norm: f64 = states.probability_density(n).iter().sum::<f64>() * dx;
+            assert_relative_eq!(norm, 1.0, epsilon = 1e-10);
+            assert_eq!(node_count(&states.wavefunctions[n]), n, "State {} has wrong node count", n);
+        }
+    }
+
+    #[test]
+    fn states_are_orthogonal() {
+        let grid = Grid1D::new(-10.0 * ANGSTROM, 10.0 * ANGSTROM, 800);
+        let well = double_well_potential(0.5 * ELEMENTARY_CHARGE, 3.0 * ANGSTROM);
+        let states = solve_stationary_states(well, ELECTRON_MASS, &grid, 4);
+        let dx = grid.spacing();
+
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                let overlap: f64 = states.wavefunctions[i]
+                    .iter()
+                    .zip(&states.wavefunctions[j])
+                    .map(|(a, b)| a * b * dx)
+                    .sum();
+                assert_relative_eq!(overlap, 0.0, epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn double_well_tunnel_splitting() {
+        // A high barrier pairs up the levels: a small symmetric/antisymmetric splitting
+        let grid = Grid1D::new(-20.0 * ANGSTROM, 20.0 * ANGSTROM, 1500);
+        let well = double_well_potential(3.0 * ELEMENTARY_CHARGE, 8.0 * ANGSTROM);
+        let states = solve_stationary_states(well, ELECTRON_MASS, &grid, 4);
+
+        let splitting = states.energies[1] - states.energies[0];
+        let next_gap = states.energies[2] - states.energies[1];
+        assert!(splitting > 0.0);
+        assert!(states.energies[1] < 3.0 * ELEMENTARY_CHARGE, "Doublet should lie below the barrier");
+        assert!(splitting < 0.1 * next_gap, "Ground doublet should be nearly degenerate");
+
+        // Ground state is even, first excited state is odd
+        let psi0 = &states.wavefunctions[0];
+        let psi1 = &states.wavefunctions[1];
+        let last = psi0.len() - 1;
+        assert_relative_eq!(psi0[500], psi0[last - 500], max_relative = 1e-6);
+        assert_relative_eq!(psi1[500], -psi1[last - 500], max_relative = 1e-6);
+    }
+}
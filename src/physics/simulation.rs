@@ -1,7 +1,11 @@
 // Time stepping and force integration using Velocity Verlet
 // Velocity Verlet is symplectic and stable for oscillatory systems
+// Boris rotation handles the velocity-dependent magnetic force
+// Langevin steps couple a particle to an implicit heat bath
 
 use glam::DVec3;
+use super::constants::BOLTZMANN_CONSTANT;
+use crate::math::random::Random;
 
 /// Configuration for the physics simulation.
 #[derive(Debug, Clone)]
@@ -120,6 +124,69 @@ where
     particle.set_velocity(new_vel);
 }
 
+/// Boris step for a charged particle in a magnetic field.
+///
+/// The magnetic force q v×B depends on velocity, so Velocity Verlet cannot
+/// handle it. Boris splits the step into a half kick from the ordinary
+/// forces, an exact-length rotation of v about B, and another half kick:
+/// 1. v⁻ = v + (F/m) dt/2
+/// 2. v⁺ = v⁻ rotated about B by the cyclotron angle (t = qB dt/2m, s = 2t/(1+t²))
+/// 3. v(t+dt) = v⁺ + (F/m) dt/2,  x(t+dt) = x(t) + v(t+dt) dt
+///
+/// The rotation preserves |v| exactly, so a pure magnetic field does no
+/// work however large the step. Velocities live at half steps (leapfrog).
+///
+/// # Arguments
+/// * `particle` - Particle whose accumulated force holds every velocity-independent force
+/// * `charge` - Particle charge in Coulombs
+/// * `magnetic_field` - B at the particle in Tesla
+/// * `dt` - Time step in seconds
+pub fn boris_step<T: Integratable>(particle: &mut T, charge: f64, magnetic_field: DVec3, dt: f64) {
+    let mass = particle.mass();
+    let half_kick = particle.force() / mass * (0.5 * dt);
+
+    let v_minus = particle.velocity() + half_kick;
+
+    let t = charge * magnetic_field / mass * (0.5 * dt);
+    let s = 2.0 * t / (1.0 + t.length_squared());
+    let v_prime = v_minus + v_minus.cross(t);
+    let v_plus = v_minus + v_prime.cross(s);
+
+    let new_vel = v_plus + half_kick;
+    particle.set_velocity(new_vel);
+    particle.set_position(particle.position() + new_vel * dt);
+}
+
+/// Langevin step: a particle in a heat bath of temperature T.
+///
+///   m dv/dt = F - mγ v + √(2mγkT) ξ(t)
+///
+/// The step kicks with the accumulated force, then relaxes the velocity
+/// towards the bath with the exact Ornstein–Uhlenbeck update
+///   v ← c v + √((1 - c²) kT/m) ξ,   c = e^(-γ dt)
+/// and finally drifts x(t+dt) = x(t) + v dt. Because the friction and noise
+/// are integrated exactly, free particles sample the Maxwell–Boltzmann
+/// velocities at any γ dt, and diffuse with D = kT/(mγ).
+///
+/// # Arguments
+/// * `particle` - Particle whose accumulated force holds the systematic forces
+/// * `friction` - Collision rate γ in 1/s
+/// * `temperature` - Bath temperature in Kelvin
+/// * `dt` - Time step in seconds
+/// * `random` - Source of the thermal noise
+pub fn langevin_step<T: Integratable>(particle: &mut T, friction: f64, temperature: f64, dt: f64, random: &mut Random) {
+    let mass = particle.mass();
+    let kicked = particle.velocity() + particle.force() / mass * dt;
+
+    let c = (-friction * dt).exp();
+    let spread = ((1.0 - c * c) * BOLTZMANN_CONSTANT * temperature / mass).sqrt();
+    let noise = DVec3::new(random.gaussian(), random.gaussian(), random.gaussian());
+    let new_vel = c * kicked + spread * noise;
+
+    particle.set_velocity(new_vel);
+    particle.set_position(particle.position() + new_vel * dt);
+}
+
 /// Calculate kinetic energy of a particle
 pub fn kinetic_energy<T: Integratable>(particle: &T) -> f64 {
     let vel = particle.velocity();
@@ -345,6 +412,66 @@ mod tests {
         assert_relative_eq!(final_r, r, max_relative = 0.05);
     }
 
+    #[test]
+    fn boris_cyclotron_radius_matches_theory() {
+        // Proton at 1e5 m/s in a 1 T field: r = mv/(qB) ≈ 1.04 mm
+        use crate::physics::constants::{ELEMENTARY_CHARGE, PROTON_MASS};
+
+        let speed = 1.0e5;
+        let b = DVec3::new(0.0, 0.0, 1.0);
+        let mut proton = TestParticle::new(PROTON_MASS).with_velocity(DVec3::new(speed, 0.0, 0.0));
+
+        // Period T = 2πm/(qB); integrate one full turn
+        let period = std::f64::consts::TAU * PROTON_MASS / (ELEMENTARY_CHARGE * b.z);
+        let steps = 2000;
+        let dt = period / steps as f64;
+
+        let mut max_y: f64 = 0.0;
+        let mut min_y: f64 = 0.0;
+        for _ in 0..steps {
+            boris_step(&mut proton, ELEMENTARY_CHARGE, b, dt);
+            max_y = max_y.max(proton.position.y);
+            min_y = min_y.min(proton.position.y);
+        }
+
+        let expected = PROTON_MASS * speed / (ELEMENTARY_CHARGE * b.z);
+        let diameter = max_y - min_y;
+        assert_relative_eq!(diameter / 2.0, expected, max_relative = 1e-3);
+
+        // No work done by B: the speed is exactly preserved
+        assert_relative_eq!(proton.velocity.length(), speed, max_relative = 1e-12);
+        // Back where it started after one period
+        assert!(proton.position.length() < 1e-2 * expected);
+    }
+
+    #[test]
+    fn boris_without_magnetic_field_is_a_kick() {
+        let force = DVec3::new(0.0, 2.0, 0.0);
+        let mut particle = TestParticle::new(2.0).with_force(force);
+
+        boris_step(&mut particle, 1.0, DVec3::ZERO, 0.5);
+
+        // v = (F/m) dt = 0.5, x = v dt = 0.25
+        assert_relative_eq!(particle.velocity.y, 0.5, epsilon = 1e-15);
+        assert_relative_eq!(particle.position.y, 0.25, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn langevin_thermalizes_velocities() {
+        // Start at rest; the bath heats the particles to ⟨½mv²⟩ = (3/2)kT
+        let (mass, temperature) = (1.0e-26, 300.0);
+        let mut random = Random::new(9);
+        let mut particles: Vec<TestParticle> = (0..500).map(|_| TestParticle::new(mass)).collect();
+        for _ in 0..200 {
+            for particle in particles.iter_mut() {
+                langevin_step(particle, 1.0e13, temperature, 1.0e-14, &mut random);
+            }
+        }
+
+        let mean_kinetic = particles.iter().map(kinetic_energy).sum::<f64>() / particles.len() as f64;
+        assert_relative_eq!(mean_kinetic, 1.5 * BOLTZMANN_CONSTANT * temperature, max_relative = 0.1);
+    }
+
     #[test]
     fn kinetic_energy_calculation() {
         let particle = TestParticle::new(2.0)
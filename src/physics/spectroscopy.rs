@@ -0,0 +1,212 @@
+// Hydrogenic spectroscopy
+// Levels E_n = -Z² (μ/mₑ) R / n² with R = E_h / 2 ≈ 13.6 eV
+// A transition emits or absorbs a photon with E = hf = hc/λ
+
+use super::constants::{ELECTRON_MASS, HARTREE_ENERGY, PLANCK_CONSTANT, SPEED_OF_LIGHT};
+
+/// A line in a hydrogenic emission spectrum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectralLine {
+    /// Upper principal quantum number
+    pub upper: u32,
+    /// Lower principal quantum number
+    pub lower: u32,
+    /// Photon energy in Joules
+    pub energy: f64,
+    /// Vacuum wavelength in meters
+    pub wavelength: f64,
+}
+
+/// Reduced-mass correction μ/mₑ = M/(M + mₑ) for a nucleus of mass M.
+pub fn reduced_mass_factor(nuclear_mass: f64) -> f64 {
+    nuclear_mass / (nuclear_mass + ELECTRON_MASS)
+}
+
+/// Energy of level n of a one-electron atom, in Joules (negative = bound).
+///
+/// # Arguments
+/// * `n` - Principal quantum number (n ≥ 1)
+/// * `z` - Nuclear charge number
+/// * `nuclear_mass` - Nuclear mass in kilograms
+pub fn level_energy(n: u32, z: u32, nuclear_mass: f64) -> f64 {
+    assert!(n >= 1, "Principal quantum number starts at 1");
+    let z = z as f64;
+    let n = n as f64;
+    -z * z * reduced_mass_factor(nuclear_mass) * 0.5 * HARTREE_ENERGY / (n * n)
+}
+
+/// Photon energy released when dropping from `upper` to `lower` (Rydberg formula).
+pub fn transition_energy(upper: u32, lower: u32, z: u32, nuclear_mass: f64) -> f64 {
+    assert!(upper > lower, "Emission needs upper > lower");
+    level_energy(upper, z, nuclear_mass) - level_energy(lower, z, nuclear_mass)
+}
+
+/// Vacuum wavelength λ = hc/E for a photon of energy E (Joules → meters).
+pub fn photon_wavelength(energy: f64) -> f64 {
+    PLANCK_CONSTANT * SPEED_OF_LIGHT / energy
+}
+
+/// Photon energy E = hc/λ for a vacuum wavelength (meters → Joules).
+pub fn photon_energy(wavelength: f64) -> f64 {
+    PLANCK_CONSTANT * SPEED_OF_LIGHT / wavelength
+}
+
+/// Photon frequency f = E/h in Hertz.
+pub fn photon_frequency(energy: f64) -> f64 {
+    energy / PLANCK_CONSTANT
+}
+
+/// Lines ending on `lower` (1 = Lyman, 2 = Balmer, 3 = Paschen, ...).
+pub fn series(lower: u32, count: usize, z: u32, nuclear_mass: f64) -> Vec<SpectralLine> {
+    (1..=count as u32)
+        .map(|step| {
+            let upper = lower + step;
+            let energy = transition_energy(upper, lower, z, nuclear_mass);
+            SpectralLine { upper, lower, energy, wavelength: photon_wavelength(energy) }
+        })
+        .collect()
+}
+
+/// Find the level a photon can lift an electron to from `level`.
+///
+/// Absorption happens only when the photon energy matches a gap
+/// E(m) - E(level) to within `tolerance` (relative). Levels above
+/// `max_level` are ignored.
+pub fn absorption_target(
+    level: u32,
+    photon_energy: f64,
+    z: u32,
+    nuclear_mass: f64,
+    tolerance: f64,
+    max_level: u32,
+) -> Option<u32> {
+    ((level + 1)..=max_level).find(|&upper| {
+        let gap = transition_energy(upper, level, z, nuclear_mass);
+        (photon_energy - gap).abs() <= tolerance * gap
+    })
+}
+
+/// Convert a vacuum wavelength to the wavelength measured in standard air.
+///
+/// Spectroscopy tables quote visible lines in air (Hα = 656.28 nm).
+/// Uses the Edlén (1966) dispersion formula for dry air at 15 °C.
+pub fn vacuum_to_air_wavelength(vacuum_wavelength: f64) -> f64 {
+    // σ is the vacuum wavenumber in μm⁻¹
+    let sigma2 = (1.0e-6 / vacuum_wavelength).powi(2);
+    let refractive_index = 1.0
+        + 8.342_54e-5
+        + 2.406_147e-2 / (130.0 - sigma2)
+        + 1.5998e-4 / (38.9 - sigma2);
+    vacuum_wavelength / refractive_index
+}
+
+/// Approximate display colour of monochromatic light, as sRGB in 0–1.
+///
+/// Piecewise-linear fit to the visible spectrum (Bruton's algorithm), with
+/// brightness tapering off at the violet and red ends. Wavelengths outside
+/// 380–780 nm are invisible and return black.
+pub fn wavelength_to_srgb(wavelength: f64) -> [f32; 3] {
+    let nm = wavelength * 1.0e9;
+
+    let (r, g, b) = if (380.0..440.0).contains(&nm) {
+        (-(nm - 440.0) / 60.0, 0.0, 1.0)
+    } else if (440.0..490.0).contains(&nm) {
+        (0.0, (nm - 440.0) / 50.0, 1.0)
+    } else if (490.0..510.0).contains(&nm) {
+        (0.0, 1.0, -(nm - 510.0) / 20.0)
+    } else if (510.0..580.0).contains(&nm) {
+        ((nm - 510.0) / 70.0, 1.0, 0.0)
+    } else if (580.0..645.0).contains(&nm) {
+        (1.0, -(nm - 645.0) / 65.0, 0.0)
+    } else if (645.0..=780.0).contains(&nm) {
+        (1.0, 0.0, 0.0)
+    } else {
+        return [0.0, 0.0, 0.0];
+    };
+
+    // The eye is less sensitive near the edges of the visible range
+    let intensity = if nm < 420.0 {
+        0.3 + 0.7 * (nm - 380.0) / 40.0
+    } else if nm > 700.0 {
+        0.3 + 0.7 * (780.0 - nm) / 80.0
+    } else {
+        1.0
+    };
+
+    let gamma = 0.8;
+    let channel = |c: f64| ((c * intensity).powf(gamma)) as f32;
+    [channel(r), channel(g), channel(b)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ELEMENTARY_CHARGE, PROTON_MASS};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn balmer_series_wavelengths() {
+        // Hα, Hβ, Hγ as quoted in air: 656.3, 486.1, 434.0 nm
+        let balmer = series(2, 3, 1, PROTON_MASS);
+        let expected_nm = [656.3, 486.1, 434.0];
+
+        for (line, expected) in balmer.iter().zip(expected_nm) {
+            let air_nm = vacuum_to_air_wavelength(line.wavelength) * 1.0e9;
+            assert_relative_eq!(air_nm, expected, epsilon = 0.06);
+        }
+        assert_eq!((balmer[0].upper, balmer[0].lower), (3, 2));
+    }
+
+    #[test]
+    fn lyman_alpha_and_ionization_energy() {
+        // Lyman α is 121.57 nm (vacuum UV); hydrogen ionizes at 13.598 eV
+        let lyman_alpha = series(1, 1, 1, PROTON_MASS)[0];
+        assert_relative_eq!(lyman_alpha.wavelength * 1.0e9, 121.57, epsilon = 0.01);
+
+        let ionization = -level_energy(1, 1, PROTON_MASS) / ELEMENTARY_CHARGE;
+        assert_relative_eq!(ionization, 13.598, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn helium_ion_levels_scale_with_z_squared() {
+        // He⁺ (Z = 2): four times deeper, up to the small reduced-mass change
+        let alpha_mass = 6.644_657_335_7e-27;
+        let ratio = level_energy(1, 2, alpha_mass) / level_energy(1, 1, alpha_mass);
+        assert_relative_eq!(ratio, 4.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn energy_wavelength_round_trip() {
+        let wavelength = 500.0e-9;
+        let energy = photon_energy(wavelength);
+        assert_relative_eq!(photon_wavelength(energy), wavelength, max_relative = 1e-12);
+        assert_relative_eq!(photon_frequency(energy) * wavelength, SPEED_OF_LIGHT, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn absorption_requires_matching_gap() {
+        let gap = transition_energy(3, 2, 1, PROTON_MASS);
+
+        assert_eq!(absorption_target(2, gap, 1, PROTON_MASS, 1e-3, 10), Some(3));
+        assert_eq!(absorption_target(2, 0.9 * gap, 1, PROTON_MASS, 1e-3, 10), None);
+        // A ground-state atom is transparent to Balmer light
+        assert_eq!(absorption_target(1, gap, 1, PROTON_MASS, 1e-3, 10), None);
+    }
+
+    #[test]
+    fn spectral_colours() {
+        // Hα is red, Hβ is blue-green, Hγ is violet
+        let h_alpha = wavelength_to_srgb(656.3e-9);
+        assert!(h_alpha[0] > 0.9 && h_alpha[1] < 0.1 && h_alpha[2] < 0.1);
+
+        let h_beta = wavelength_to_srgb(486.1e-9);
+        assert!(h_beta[2] > 0.9 && h_beta[1] > 0.8 && h_beta[0] < 0.1);
+
+        let h_gamma = wavelength_to_srgb(434.0e-9);
+        assert!(h_gamma[2] > h_gamma[0] && h_gamma[0] > h_gamma[1]);
+
+        // Invisible light has no colour
+        assert_eq!(wavelength_to_srgb(121.6e-9), [0.0, 0.0, 0.0]);
+        assert_eq!(wavelength_to_srgb(1875.0e-9), [0.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,446 @@
+// Structure and phase of a cluster of particles
+// g(r): pair density at distance r relative to an ideal gas of the same
+// density. Crystals show sharp shells, liquids a few damped peaks, gases ≈ 1.
+// Bond-orientational order (Steinhardt, Nelson & Ronchetti 1983): for each
+// particle average the spherical harmonics of its bond directions,
+//   q₆ₘ(i) = (1/Nᵢ) Σⱼ Y₆ₘ(r̂ᵢⱼ),   q₆(i) = √(4π/13 Σₘ |q₆ₘ(i)|²)
+// In the plane the analogue is the hexatic ψ₆(i) = (1/Nᵢ) Σⱼ e^(6iθᵢⱼ).
+// A particle is solid when enough neighbours share its orientation
+// (ten Wolde, Ruiz-Montero & Frenkel 1996), gas when it has too few
+// neighbours, and liquid otherwise.
+
+use bevy::prelude::*;
+use glam::DVec3;
+use super::constants::ANGSTROM;
+use crate::math::complex::Complex;
+
+/// Pair distribution function sampled in equal bins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialDistribution {
+    /// Bin width in meters
+    pub bin_width: f64,
+    /// g(r) at the bin centres
+    pub g: Vec<f64>,
+}
+
+impl RadialDistribution {
+    /// Distance at the centre of a bin
+    pub fn r(&self, bin: usize) -> f64 {
+        (bin as f64 + 0.5) * self.bin_width
+    }
+
+    /// Bin of the first peak rising above the ideal-gas level: the nearest-neighbour shell
+    fn peak_bin(&self) -> Option<usize> {
+        let last = self.g.len().checked_sub(1)?;
+        (0..=last).find(|&bin| {
+            let before = if bin > 0 { self.g[bin - 1] } else { 0.0 };
+            let after = if bin < last { self.g[bin + 1] } else { 0.0 };
+            self.g[bin] > 1.0 && self.g[bin] > before && self.g[bin] >= after
+        })
+    }
+
+    /// Position and height of the first peak
+    pub fn first_peak(&self) -> Option<(f64, f64)> {
+        self.peak_bin().map(|bin| (self.r(bin), self.g[bin]))
+    }
+
+    /// Position of the first minimum after the first peak: the natural coordination cutoff
+    pub fn first_minimum(&self) -> Option<f64> {
+        let peak = self.peak_bin()?;
+        (peak + 1..self.g.len().saturating_sub(1))
+            .find(|&bin| self.g[bin] <= self.g[bin - 1] && self.g[bin] < self.g[bin + 1])
+            .map(|bin| self.r(bin))
+    }
+}
+
+/// Radial distribution function of a set of particles.
+///
+/// # Arguments
+/// * `positions` - Particle positions in meters
+/// * `density` - Number density to normalise against (per m³, or per m² in the plane)
+/// * `dimensions` - 2 for the plane, 3 for space
+/// * `r_max`, `bins` - Histogram range and resolution
+///
+/// A free cluster has no walls, so shells near its surface are
+/// undercounted; compare shapes rather than absolute heights there.
+pub fn radial_distribution(positions: &[DVec3], density: f64, dimensions: usize, r_max: f64, bins: usize) -> RadialDistribution {
+    let bin_width = r_max / bins as f64;
+    let mut counts = vec![0usize; bins];
+    for (i, a) in positions.iter().enumerate() {
+        for b in &positions[i + 1..] {
+            let bin = (a.distance(*b) / bin_width) as usize;
+            if bin < bins {
+                counts[bin] += 2;
+            }
+        }
+    }
+
+    let g = counts
+        .iter()
+        .enumerate()
+        .map(|(bin, &count)| {
+            let (inner, outer) = (bin as f64 * bin_width, (bin + 1) as f64 * bin_width);
+            let shell = match dimensions {
+                2 => std::f64::consts::PI * (outer.powi(2) - inner.powi(2)),
+                _ => 4.0 / 3.0 * std::f64::consts::PI * (outer.powi(3) - inner.powi(3)),
+            };
+            count as f64 / (positions.len() as f64 * density * shell)
+        })
+        .collect();
+    RadialDistribution { bin_width, g }
+}
+
+/// Indices of every particle within `cutoff` of each particle
+pub fn neighbour_lists(positions: &[DVec3], cutoff: f64) -> Vec<Vec<usize>> {
+    let mut neighbours = vec![Vec::new(); positions.len()];
+    for (i, a) in positions.iter().enumerate() {
+        for (j, b) in positions.iter().enumerate().skip(i + 1) {
+            if a.distance(*b) < cutoff {
+                neighbours[i].push(j);
+                neighbours[j].push(i);
+            }
+        }
+    }
+    neighbours
+}
+
+/// Number of neighbours within `cutoff` of each particle
+pub fn coordination_numbers(positions: &[DVec3], cutoff: f64) -> Vec<usize> {
+    neighbour_lists(positions, cutoff).iter().map(Vec::len).collect()
+}
+
+/// Normalised associated Legendre functions N₆ₘ P₆ᵐ(x) for m = 0..=6.
+///
+/// The Condon–Shortley phase is dropped; it cancels in |q₆ₘ|² and in
+/// products q₆ₘ(i) q₆ₘ(j)*.
+fn legendre_6(x: f64) -> [f64; 7] {
+    const L: usize = 6;
+    let sine = (1.0 - x * x).max(0.0).sqrt();
+    let mut values = [0.0; 7];
+    for (m, value) in values.iter_mut().enumerate() {
+        // P_m^m = (2m-1)!! sinᵐθ, then upward in l
+        let mut p_mm = 1.0;
+        for k in 0..m {
+            p_mm *= (2 * k + 1) as f64 * sine;
+        }
+        let (mut previous, mut current) = (0.0, p_mm);
+        for l in m + 1..=L {
+            let next = ((2 * l - 1) as f64 * x * current - (l + m - 1) as f64 * previous) / (l - m) as f64;
+            (previous, current) = (current, next);
+        }
+        let ratio: f64 = (L - m + 1..=L + m).map(|k| k as f64).product();
+        *value = current * ((2 * L + 1) as f64 / (4.0 * std::f64::consts::PI) / ratio).sqrt();
+    }
+    values
+}
+
+/// Local bond-orientational order of one particle.
+///
+/// In space this holds q₆ₘ for m = 0..=6 (negative m mirror the positive
+/// ones); in the plane a single entry, ψ₆.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderVector {
+    components: Vec<Complex>,
+}
+
+impl OrderVector {
+    /// Weight of component m: m > 0 also stands for -m in space
+    fn weight(&self, m: usize) -> f64 {
+        if self.components.len() > 1 && m > 0 { 2.0 } else { 1.0 }
+    }
+
+    /// Σₘ q₆ₘ(self) q₆ₘ(other)* (real part)
+    fn dot(&self, other: &OrderVector) -> f64 {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .enumerate()
+            .map(|(m, (a, b))| self.weight(m) * (*a * b.conj()).re)
+            .sum()
+    }
+
+    /// q₆ in space, |ψ₆| in the plane (0 for an isolated particle)
+    pub fn magnitude(&self) -> f64 {
+        let norm = self.dot(self);
+        if self.components.len() > 1 {
+            (4.0 * std::f64::consts::PI / 13.0 * norm).sqrt()
+        } else {
+            norm.sqrt()
+        }
+    }
+
+    /// Orientational alignment with another particle, in [-1, 1]
+    pub fn alignment(&self, other: &OrderVector) -> f64 {
+        let norms = (self.dot(self) * other.dot(other)).sqrt();
+        if norms == 0.0 { 0.0 } else { self.dot(other) / norms }
+    }
+}
+
+/// Order vector of every particle from its bonds to its neighbours.
+pub fn order_vectors(positions: &[DVec3], neighbours: &[Vec<usize>], dimensions: usize) -> Vec<OrderVector> {
+    positions
+        .iter()
+        .zip(neighbours)
+        .map(|(centre, list)| {
+            let size = if dimensions == 2 { 1 } else { 7 };
+            let mut components = vec![Complex::ZERO; size];
+            for &j in list {
+                let bond = positions[j] - *centre;
+                let azimuth = bond.y.atan2(bond.x);
+                if dimensions == 2 {
+                    components[0] += Complex::cis(6.0 * azimuth);
+                } else {
+                    let legendre = legendre_6(bond.z / bond.length());
+                    for (m, component) in components.iter_mut().enumerate() {
+                        *component += Complex::cis(m as f64 * azimuth) * legendre[m];
+                    }
+                }
+            }
+            let scale = 1.0 / list.len().max(1) as f64;
+            OrderVector { components: components.into_iter().map(|c| c * scale).collect() }
+        })
+        .collect()
+}
+
+/// Local q₆ (or |ψ₆| in the plane) of every particle.
+pub fn local_q6(positions: &[DVec3], cutoff: f64, dimensions: usize) -> Vec<f64> {
+    let neighbours = neighbour_lists(positions, cutoff);
+    order_vectors(positions, &neighbours, dimensions).iter().map(OrderVector::magnitude).collect()
+}
+
+/// Thermodynamic phase of a particle or cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+impl Phase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Solid => "solid",
+            Phase::Liquid => "liquid",
+            Phase::Gas => "gas",
+        }
+    }
+}
+
+/// Thresholds for labelling particles solid, liquid or gas.
+#[derive(Resource, Debug, Clone)]
+pub struct PhaseClassifier {
+    /// Neighbour cutoff in meters (between the first and second shells)
+    pub cutoff: f64,
+    /// 2 for the plane, 3 for space
+    pub dimensions: usize,
+    /// Particles with fewer neighbours than this are gas
+    pub min_neighbours: usize,
+    /// Two neighbours are orientationally linked above this alignment
+    pub alignment: f64,
+    /// A particle with at least this many linked neighbours is solid
+    pub solid_bonds: usize,
+}
+
+impl Default for PhaseClassifier {
+    fn default() -> Self {
+        Self {
+            // 1.3 × the well of the scene's default Lennard-Jones pair
+            cutoff: 0.73 * ANGSTROM,
+            dimensions: 3,
+            min_neighbours: 3,
+            alignment: 0.7,
+            solid_bonds: 7,
+        }
+    }
+}
+
+/// Result of classifying a cluster.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseReport {
+    /// Label of each particle, in input order
+    pub labels: Vec<Phase>,
+    /// Neighbour count of each particle
+    pub coordination: Vec<usize>,
+    /// q₆ (or |ψ₆|) of each particle
+    pub order: Vec<f64>,
+}
+
+impl PhaseReport {
+    /// Share of particles with a label (0 for an empty cluster)
+    pub fn fraction(&self, phase: Phase) -> f64 {
+        if self.labels.is_empty() {
+            return 0.0;
+        }
+        self.labels.iter().filter(|&&label| label == phase).count() as f64 / self.labels.len() as f64
+    }
+
+    /// The most common label (None for an empty cluster); ties favour the more ordered phase
+    pub fn phase(&self) -> Option<Phase> {
+        [Phase::Solid, Phase::Liquid, Phase::Gas]
+            .into_iter()
+            .filter(|&phase| self.fraction(phase) > 0.0)
+            .max_by(|a, b| self.fraction(*a).total_cmp(&self.fraction(*b)).then(std::cmp::Ordering::Greater))
+    }
+
+    /// Average neighbour count
+    pub fn mean_coordination(&self) -> f64 {
+        self.coordination.iter().sum::<usize>() as f64 / self.coordination.len().max(1) as f64
+    }
+}
+
+impl PhaseClassifier {
+    /// Settings for particles confined to the plane (hexagonal packing has 6 neighbours)
+    pub fn planar() -> Self {
+        Self {
+            dimensions: 2,
+            min_neighbours: 2,
+            solid_bonds: 4,
+            ..Self::default()
+        }
+    }
+
+    /// Set the neighbour cutoff in meters
+    pub fn with_cutoff(mut self, cutoff: f64) -> Self {
+        self.cutoff = cutoff;
+        self
+    }
+
+    /// Label every particle.
+    pub fn classify(&self, positions: &[DVec3]) -> PhaseReport {
+        let neighbours = neighbour_lists(positions, self.cutoff);
+        let vectors = order_vectors(positions, &neighbours, self.dimensions);
+
+        let labels = neighbours
+            .iter()
+            .zip(&vectors)
+            .map(|(list, vector)| {
+                if list.len() < self.min_neighbours {
+                    return Phase::Gas;
+                }
+                let linked = list.iter().filter(|&&j| vector.alignment(&vectors[j]) > self.alignment).count();
+                if linked >= self.solid_bonds { Phase::Solid } else { Phase::Liquid }
+            })
+            .collect();
+
+        PhaseReport {
+            labels,
+            coordination: neighbours.iter().map(Vec::len).collect(),
+            order: vectors.iter().map(OrderVector::magnitude).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::random::Random;
+    use approx::assert_relative_eq;
+
+    /// Face-centred cubic block with nearest-neighbour distance 1
+    fn fcc(cells: usize) -> Vec<DVec3> {
+        let a = 2.0_f64.sqrt();
+        let basis = [DVec3::ZERO, DVec3::new(0.5, 0.5, 0.0), DVec3::new(0.5, 0.0, 0.5), DVec3::new(0.0, 0.5, 0.5)];
+        let mut positions = Vec::new();
+        for x in 0..cells {
+            for y in 0..cells {
+                for z in 0..cells {
+                    for b in basis {
+                        positions.push((DVec3::new(x as f64, y as f64, z as f64) + b) * a);
+                    }
+                }
+            }
+        }
+        positions
+    }
+
+    /// Triangular (hexagonal) sheet with spacing 1
+    fn triangular(rows: usize) -> Vec<DVec3> {
+        (0..rows * rows)
+            .map(|k| {
+                let (i, j) = ((k % rows) as f64, (k / rows) as f64);
+                DVec3::new(i + 0.5 * (j % 2.0), j * 3.0_f64.sqrt() / 2.0, 0.0)
+            })
+            .collect()
+    }
+
+    fn jitter(positions: &[DVec3], amount: f64, dimensions: usize, seed: u64) -> Vec<DVec3> {
+        let mut random = Random::new(seed);
+        positions
+            .iter()
+            .map(|p| {
+                let z = if dimensions == 3 { random.gaussian() } else { 0.0 };
+                *p + amount * DVec3::new(random.gaussian(), random.gaussian(), z)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crystal_order_parameters() {
+        // Bulk fcc atoms have q₆ = 0.5745; the centre of a 4³ block is bulk
+        let crystal = fcc(4);
+        let q6 = local_q6(&crystal, 1.2, 3);
+        let centre = crystal.iter().position(|p| p.distance(DVec3::splat(2.0 * 2.0_f64.sqrt())) < 1e-9).unwrap();
+        assert_relative_eq!(q6[centre], 0.5745, epsilon = 1e-4);
+        assert_eq!(coordination_numbers(&crystal, 1.2)[centre], 12);
+
+        // A perfect triangular sheet has |ψ₆| = 1 in the interior
+        let sheet = triangular(8);
+        let psi6 = local_q6(&sheet, 1.2, 2);
+        assert_relative_eq!(psi6[3 * 8 + 3], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn radial_distribution_shells() {
+        let sheet = triangular(30);
+        let density = 2.0 / 3.0_f64.sqrt();
+        let rdf = radial_distribution(&sheet, density, 2, 3.0, 60);
+
+        let (peak, height) = rdf.first_peak().unwrap();
+        assert_relative_eq!(peak, 1.0, epsilon = 1.5 * rdf.bin_width);
+        assert!(height > 5.0);
+        let minimum = rdf.first_minimum().unwrap();
+        assert!(minimum > 1.0 && minimum < 3.0_f64.sqrt(), "First minimum {minimum}");
+
+        // Nothing closer than the lattice spacing
+        assert!(rdf.g[..18].iter().all(|&g| g == 0.0));
+    }
+
+    #[test]
+    fn classifies_solid_liquid_and_gas() {
+        let classifier = PhaseClassifier::default().with_cutoff(1.2);
+
+        // A warm crystal is still solid
+        let crystal = classifier.classify(&jitter(&fcc(4), 0.05, 3, 1));
+        assert_eq!(crystal.phase(), Some(Phase::Solid));
+        assert!(crystal.fraction(Phase::Solid) > 0.4);
+
+        // Random packing at liquid density keeps neighbours but loses orientation
+        let mut random = Random::new(2);
+        let side = 5.0;
+        let liquid: Vec<DVec3> = (0..170)
+            .map(|_| DVec3::new(random.uniform(), random.uniform(), random.uniform()) * side)
+            .collect();
+        let report = classifier.classify(&liquid);
+        assert_eq!(report.phase(), Some(Phase::Liquid));
+        assert!(report.fraction(Phase::Solid) < 0.05);
+
+        // A dilute vapour is gas
+        let vapour: Vec<DVec3> = liquid.iter().map(|p| *p * 4.0).collect();
+        assert_eq!(classifier.classify(&vapour).phase(), Some(Phase::Gas));
+    }
+
+    #[test]
+    fn planar_classification() {
+        let classifier = PhaseClassifier::planar().with_cutoff(1.2);
+        let sheet = classifier.classify(&jitter(&triangular(10), 0.05, 2, 3));
+        assert_eq!(sheet.phase(), Some(Phase::Solid));
+        assert!(sheet.mean_coordination() > 5.0);
+
+        let mut random = Random::new(4);
+        let melt: Vec<DVec3> = (0..100)
+            .map(|_| DVec3::new(random.uniform(), random.uniform(), 0.0) * 9.0)
+            .collect();
+        assert_ne!(classifier.classify(&melt).phase(), Some(Phase::Solid));
+        assert_eq!(PhaseReport { labels: vec![], coordination: vec![], order: vec![] }.phase(), None);
+    }
+}
@@ -0,0 +1,292 @@
+// VSEPR electron-domain model
+// Each bonding pair and lone pair around a central atom is a pseudo-particle
+// at a fixed distance from the nucleus. Domains repel each other like magnets,
+//   E = Σᵢ<ⱼ 1 / |ρᵢuᵢ - ρⱼuⱼ|ᵖ
+// and relaxing their directions uᵢ gives the familiar shapes. Bonding pairs
+// sit at ρ = 1; lone pairs are held closer to the nucleus (ρ < 1), so they
+// push harder on their neighbours and crowd the bonds together (water bends
+// below 109.5°).
+
+use bevy::prelude::*;
+use glam::DVec3;
+
+/// What occupies an electron domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainKind {
+    /// A bond to a neighbouring atom
+    Bonding,
+    /// A lone pair on the central atom
+    LonePair,
+}
+
+/// One electron domain: a direction on the unit sphere around the central atom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Domain {
+    pub kind: DomainKind,
+    /// Unit vector from the central atom
+    pub direction: DVec3,
+}
+
+/// Settings for domain relaxation.
+#[derive(Resource, Debug, Clone)]
+pub struct VseprConfig {
+    /// Repulsion exponent p
+    pub exponent: f64,
+    /// Distance of a lone pair from the nucleus relative to a bonding pair
+    pub lone_pair_radius: f64,
+    /// Step length per unit force
+    pub step: f64,
+    /// Relaxation stops when no domain feels a tangential force above this
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for VseprConfig {
+    fn default() -> Self {
+        Self {
+            exponent: 3.0,
+            lone_pair_radius: 0.8,
+            step: 0.05,
+            tolerance: 1e-10,
+            max_iterations: 20_000,
+        }
+    }
+}
+
+/// The electron domains around a central atom.
+#[derive(Component, Debug, Clone)]
+pub struct ElectronDomains {
+    pub domains: Vec<Domain>,
+}
+
+impl ElectronDomains {
+    /// Spread bonding and lone-pair domains around a central atom.
+    ///
+    /// Domains start on a Fibonacci spiral, roughly but not exactly evenly
+    /// spaced, so relaxation is never stuck on a symmetric saddle.
+    pub fn new(bonding: usize, lone_pairs: usize) -> Self {
+        let count = bonding + lone_pairs;
+        let golden = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        let domains = (0..count)
+            .map(|i| {
+                let z = 1.0 - (2.0 * i as f64 + 1.0) / count as f64;
+                let radius = (1.0 - z * z).sqrt();
+                let azimuth = golden * i as f64;
+                let kind = if i < lone_pairs { DomainKind::LonePair } else { DomainKind::Bonding };
+                Domain { kind, direction: DVec3::new(radius * azimuth.cos(), radius * azimuth.sin(), z) }
+            })
+            .collect();
+        Self { domains }
+    }
+
+    /// The lowest-energy arrangement of bonding and lone-pair domains.
+    ///
+    /// A single relaxation can stall in a local minimum (e.g. bonds bunched
+    /// together in XeF₂), so the domains are first relaxed as equals into the
+    /// ideal polyhedron, then every placement of the lone pairs on its
+    /// vertices is relaxed and the best one kept.
+    pub fn relaxed(bonding: usize, lone_pairs: usize, config: &VseprConfig) -> Self {
+        let mut ideal = Self::new(bonding + lone_pairs, 0);
+        ideal.relax(config);
+
+        let count = ideal.domains.len();
+        let mut best: Option<(f64, Self)> = None;
+        for mask in 0u32..(1 << count) {
+            if mask.count_ones() as usize != lone_pairs {
+                continue;
+            }
+            let mut candidate = ideal.clone();
+            for (i, domain) in candidate.domains.iter_mut().enumerate() {
+                if mask & (1 << i) != 0 {
+                    domain.kind = DomainKind::LonePair;
+                }
+            }
+            candidate.relax(config);
+
+            let energy = candidate.energy(config);
+            if best.as_ref().is_none_or(|(lowest, _)| energy < *lowest - 1e-9) {
+                best = Some((energy, candidate));
+            }
+        }
+        best.map_or(ideal, |(_, domains)| domains)
+    }
+
+    /// Point where a domain's charge sits: lone pairs are held closer to the nucleus
+    fn centre(domain: &Domain, config: &VseprConfig) -> DVec3 {
+        match domain.kind {
+            DomainKind::Bonding => domain.direction,
+            DomainKind::LonePair => config.lone_pair_radius * domain.direction,
+        }
+    }
+
+    /// Repulsion energy of the current arrangement (dimensionless)
+    pub fn energy(&self, config: &VseprConfig) -> f64 {
+        let mut energy = 0.0;
+        for (i, a) in self.domains.iter().enumerate() {
+            for b in &self.domains[i + 1..] {
+                let distance = Self::centre(a, config).distance(Self::centre(b, config));
+                energy += distance.powf(-config.exponent);
+            }
+        }
+        energy
+    }
+
+    /// Tangential forces on each domain
+    fn forces(&self, config: &VseprConfig) -> Vec<DVec3> {
+        let p = config.exponent;
+        self.domains
+            .iter()
+            .map(|a| {
+                let force: DVec3 = self
+                    .domains
+                    .iter()
+                    .filter(|b| !std::ptr::eq(*b, a))
+                    .map(|b| {
+                        let separation = Self::centre(a, config) - Self::centre(b, config);
+                        p * separation / separation.length().powf(p + 2.0)
+                    })
+                    .sum();
+                // The radius is fixed, so only the tangential part moves the domain
+                force - force.dot(a.direction) * a.direction
+            })
+            .collect()
+    }
+
+    /// Move every domain one step along its tangential force.
+    ///
+    /// # Returns
+    /// The largest tangential force before the step.
+    pub fn step(&mut self, config: &VseprConfig) -> f64 {
+        let forces = self.forces(config);
+        let largest = forces.iter().map(|f| f.length()).fold(0.0, f64::max);
+        for (domain, force) in self.domains.iter_mut().zip(forces) {
+            domain.direction = (domain.direction + config.step * force).normalize();
+        }
+        largest
+    }
+
+    /// Relax the domains to their minimum-repulsion arrangement.
+    ///
+    /// # Returns
+    /// The number of steps taken.
+    pub fn relax(&mut self, config: &VseprConfig) -> usize {
+        for iteration in 0..config.max_iterations {
+            if self.step(config) < config.tolerance {
+                return iteration;
+            }
+        }
+        config.max_iterations
+    }
+
+    /// Count of (bonding, lone-pair) domains
+    pub fn counts(&self) -> (usize, usize) {
+        let bonding = self.domains.iter().filter(|d| d.kind == DomainKind::Bonding).count();
+        (bonding, self.domains.len() - bonding)
+    }
+
+    /// Angles between every pair of bonding domains, in degrees
+    pub fn bond_angles(&self) -> Vec<f64> {
+        let bonds: Vec<DVec3> = self
+            .domains
+            .iter()
+            .filter(|d| d.kind == DomainKind::Bonding)
+            .map(|d| d.direction)
+            .collect();
+        let mut angles = Vec::new();
+        for (i, a) in bonds.iter().enumerate() {
+            for b in &bonds[i + 1..] {
+                angles.push(a.dot(*b).clamp(-1.0, 1.0).acos().to_degrees());
+            }
+        }
+        angles
+    }
+
+    /// VSEPR name of the molecular shape (the arrangement of the bonded atoms)
+    pub fn shape_name(&self) -> &'static str {
+        match self.counts() {
+            (0, _) => "atom",
+            (1, _) => "linear",
+            (2, 0) | (2, 3) => "linear",
+            (2, _) => "bent",
+            (3, 0) => "trigonal planar",
+            (3, 1) => "trigonal pyramidal",
+            (3, _) => "T-shaped",
+            (4, 0) => "tetrahedral",
+            (4, 1) => "seesaw",
+            (4, _) => "square planar",
+            (5, 0) => "trigonal bipyramidal",
+            (5, _) => "square pyramidal",
+            (6, _) => "octahedral",
+            _ => "polyhedral",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn relaxed(bonding: usize, lone_pairs: usize) -> ElectronDomains {
+        ElectronDomains::relaxed(bonding, lone_pairs, &VseprConfig::default())
+    }
+
+    /// Sorted bond angles, rounded to 0.1°
+    fn angles(domains: &ElectronDomains) -> Vec<f64> {
+        let mut angles: Vec<f64> = domains.bond_angles().iter().map(|a| (a * 10.0).round() / 10.0).collect();
+        angles.sort_by(f64::total_cmp);
+        angles
+    }
+
+    #[test]
+    fn ideal_shapes_from_bonding_domains_alone() {
+        assert_eq!(angles(&relaxed(2, 0)), [180.0]);
+        assert_eq!(angles(&relaxed(3, 0)), [120.0; 3]);
+        assert_eq!(angles(&relaxed(4, 0)), [109.5; 6]);
+
+        // Trigonal bipyramid: 3 equatorial 120°, 6 axial-equatorial 90°, 1 axial 180°
+        let tbp = angles(&relaxed(5, 0));
+        assert_eq!(tbp, [90.0, 90.0, 90.0, 90.0, 90.0, 90.0, 120.0, 120.0, 120.0, 180.0]);
+
+        let octahedron = angles(&relaxed(6, 0));
+        assert_eq!(octahedron.iter().filter(|&&a| a == 90.0).count(), 12);
+        assert_eq!(octahedron.iter().filter(|&&a| a == 180.0).count(), 3);
+        assert_eq!(relaxed(6, 0).shape_name(), "octahedral");
+    }
+
+    #[test]
+    fn lone_pairs_compress_bond_angles() {
+        let water = relaxed(2, 2);
+        let ammonia = relaxed(3, 1);
+        let water_angle = water.bond_angles()[0];
+        let ammonia_angle = ammonia.bond_angles()[0];
+
+        assert_eq!(water.shape_name(), "bent");
+        assert_eq!(ammonia.shape_name(), "trigonal pyramidal");
+        // H₂O 104.5°, NH₃ 107° in reality: two lone pairs squeeze more than one
+        assert!(water_angle < ammonia_angle && ammonia_angle < 109.5);
+        assert!(water_angle > 100.0, "Water angle {water_angle}");
+        for angle in ammonia.bond_angles() {
+            assert_relative_eq!(angle, ammonia_angle, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn lone_pairs_take_the_roomy_positions() {
+        // XeF₂: three equatorial lone pairs leave the bonds axial
+        assert_eq!(angles(&relaxed(2, 3)), [180.0]);
+        // XeF₄: the two lone pairs sit opposite, the bonds form a square
+        let square = angles(&relaxed(4, 2));
+        assert_eq!(square, [90.0, 90.0, 90.0, 90.0, 180.0, 180.0]);
+    }
+
+    #[test]
+    fn relaxation_lowers_the_energy() {
+        let config = VseprConfig::default();
+        let mut domains = ElectronDomains::new(3, 2);
+        let start = domains.energy(&config);
+        assert!(domains.relax(&config) < config.max_iterations);
+        assert!(domains.energy(&config) < start);
+        assert!(domains.domains.iter().all(|d| (d.direction.length() - 1.0).abs() < 1e-12));
+    }
+}
@@ -0,0 +1,376 @@
+// Time-dependent Schrödinger equation by the split-operator method
+// ψ(t+dt) ≈ e^(-iV dt/2ℏ) F⁻¹ e^(-iℏk² dt/2m) F e^(-iV dt/2ℏ) ψ(t)
+// Each factor is an exact phase, so the evolution is unitary and the norm is conserved
+
+use glam::DVec2;
+use super::constants::HBAR;
+use crate::math::complex::Complex;
+use crate::math::fft::{fft, fft_2d, ifft, ifft_2d, wavenumbers};
+
+/// A periodic grid for wavepacket propagation (1D when `ny == 1`).
+///
+/// Both point counts must be powers of two for the FFT. The box wraps
+/// around, so lessons should end before packets reach the far edge.
+#[derive(Debug, Clone)]
+pub struct WavepacketGrid {
+    /// Lower-left corner in meters
+    pub origin: DVec2,
+    /// Box size in meters
+    pub size: DVec2,
+    /// Points along x
+    pub nx: usize,
+    /// Points along y (1 for a line)
+    pub ny: usize,
+}
+
+impl WavepacketGrid {
+    /// A 1D grid of `nx` points covering [x_min, x_min + length)
+    pub fn line(x_min: f64, length: f64, nx: usize) -> Self {
+        Self::plane(DVec2::new(x_min, 0.0), DVec2::new(length, 1.0), nx, 1)
+    }
+
+    /// A 2D grid of `nx × ny` points
+    pub fn plane(origin: DVec2, size: DVec2, nx: usize, ny: usize) -> Self {
+        assert!(nx.is_power_of_two() && ny.is_power_of_two(), "Grid sizes must be powers of two");
+        Self { origin, size, nx, ny }
+    }
+
+    pub fn is_1d(&self) -> bool {
+        self.ny == 1
+    }
+
+    /// Point spacing along x and y in meters
+    pub fn spacing(&self) -> DVec2 {
+        DVec2::new(self.size.x / self.nx as f64, self.size.y / self.ny as f64)
+    }
+
+    /// Length (1D) or area (2D) each point represents
+    pub fn cell_measure(&self) -> f64 {
+        let d = self.spacing();
+        if self.is_1d() { d.x } else { d.x * d.y }
+    }
+
+    /// Position of the point with flat index `index` (row-major)
+    pub fn position(&self, index: usize) -> DVec2 {
+        let d = self.spacing();
+        let (i, j) = (index % self.nx, index / self.nx);
+        let y = if self.is_1d() { 0.0 } else { self.origin.y + j as f64 * d.y };
+        DVec2::new(self.origin.x + i as f64 * d.x, y)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nx * self.ny
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A quantum particle evolving on a grid under a static potential.
+#[derive(Debug, Clone)]
+pub struct WavepacketSimulation {
+    pub grid: WavepacketGrid,
+    /// Particle mass in kilograms
+    pub mass: f64,
+    /// Time step in seconds
+    pub dt: f64,
+    /// Elapsed simulation time in seconds
+    pub time: f64,
+    psi: Vec<Complex>,
+    potential: Vec<f64>,
+    /// e^(-iV dt/2ℏ) at each point
+    potential_half_step: Vec<Complex>,
+    /// e^(-iℏk² dt/2m) at each wavevector
+    kinetic_step: Vec<Complex>,
+}
+
+impl WavepacketSimulation {
+    /// Create a simulation with potential V(x, y) in Joules (y = 0 in 1D).
+    pub fn new<V>(grid: WavepacketGrid, mass: f64, dt: f64, potential: V) -> Self
+    where
+        V: Fn(DVec2) -> f64,
+    {
+        let potential: Vec<f64> = (0..grid.len()).map(|i| potential(grid.position(i))).collect();
+
+        let spacing = grid.spacing();
+        let kx = wavenumbers(grid.nx, spacing.x);
+        let ky = if grid.is_1d() { vec![0.0] } else { wavenumbers(grid.ny, spacing.y) };
+
+        let kinetic_step = (0..grid.len())
+            .map(|index| {
+                let k2 = kx[index % grid.nx].powi(2) + ky[index / grid.nx].powi(2);
+                Complex::cis(-HBAR * k2 * dt / (2.0 * mass))
+            })
+            .collect();
+
+        let potential_half_step = potential
+            .iter()
+            .map(|v| Complex::cis(-v * dt / (2.0 * HBAR)))
+            .collect();
+
+        Self {
+            psi: vec![Complex::ZERO; grid.len()],
+            grid,
+            mass,
+            dt,
+            time: 0.0,
+            potential,
+            potential_half_step,
+            kinetic_step,
+        }
+    }
+
+    /// Replace the wavefunction with a normalized Gaussian packet.
+    ///
+    /// ψ ∝ exp(-|r - r₀|²/(4σ²)) · exp(i p·r/ℏ), so σ is the position spread.
+    ///
+    /// # Arguments
+    /// * `center` - Initial packet centre in meters
+    /// * `width` - Position standard deviation σ in meters
+    /// * `momentum` - Mean momentum in kg⋅m/s
+    pub fn launch_gaussian(&mut self, center: DVec2, width: f64, momentum: DVec2) {
+        for (index, value) in self.psi.iter_mut().enumerate() {
+            let mut r = self.grid.position(index);
+            if self.grid.is_1d() {
+                r.y = center.y;
+            }
+            let envelope = (-(r - center).length_squared() / (4.0 * width * width)).exp();
+            *value = Complex::cis(momentum.dot(r) / HBAR) * envelope;
+        }
+        self.time = 0.0;
+        self.normalize();
+    }
+
+    /// Advance one split-operator step.
+    pub fn step(&mut self) {
+        let (nx, ny) = (self.grid.nx, self.grid.ny);
+
+        for (psi, phase) in self.psi.iter_mut().zip(&self.potential_half_step) {
+            *psi *= *phase;
+        }
+
+        if self.grid.is_1d() { fft(&mut self.psi) } else { fft_2d(&mut self.psi, nx, ny) }
+        for (psi, phase) in self.psi.iter_mut().zip(&self.kinetic_step) {
+            *psi *= *phase;
+        }
+        if self.grid.is_1d() { ifft(&mut self.psi) } else { ifft_2d(&mut self.psi, nx, ny) }
+
+        for (psi, phase) in self.psi.iter_mut().zip(&self.potential_half_step) {
+            *psi *= *phase;
+        }
+
+        self.time += self.dt;
+    }
+
+    /// Advance several steps.
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Total probability ∫|ψ|² (1 for a normalized packet).
+    pub fn norm(&self) -> f64 {
+        self.psi.iter().map(|z| z.norm_sqr()).sum::<f64>() * self.grid.cell_measure()
+    }
+
+    /// Probability of finding the particle at x > `x` — the transmitted
+    /// fraction once a packet has cleared a barrier ending at `x`.
+    pub fn probability_beyond(&self, x: f64) -> f64 {
+        self.psi
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.grid.position(*index).x > x)
+            .map(|(_, z)| z.norm_sqr())
+            .sum::<f64>()
+            * self.grid.cell_measure()
+    }
+
+    /// Expectation value of position ⟨r⟩ in meters
+    pub fn mean_position(&self) -> DVec2 {
+        let sum = self
+            .psi
+            .iter()
+            .enumerate()
+            .fold(DVec2::ZERO, |sum, (index, z)| sum + self.grid.position(index) * z.norm_sqr());
+        sum * self.grid.cell_measure() / self.norm()
+    }
+
+    /// Snapshot of |ψ|² at every point (row-major), for rendering.
+    pub fn density(&self) -> Vec<f64> {
+        self.psi.iter().map(|z| z.norm_sqr()).collect()
+    }
+
+    /// Potential energy at every point in Joules (row-major)
+    pub fn potential(&self) -> &[f64] {
+        &self.potential
+    }
+
+    fn normalize(&mut self) {
+        let scale = 1.0 / self.norm().sqrt();
+        for value in self.psi.iter_mut() {
+            *value = *value * scale;
+        }
+    }
+}
+
+/// Transmission probability through a 1D rectangular barrier.
+///
+/// T = [1 + V₀² sinh²(κa) / (4E(V₀ - E))]⁻¹ below the barrier, with
+/// κ = √(2m(V₀ - E))/ℏ, and the oscillating sin² form above it.
+///
+/// # Arguments
+/// * `energy` - Particle energy E in Joules
+/// * `barrier_height` - V₀ in Joules
+/// * `width` - Barrier width a in meters
+/// * `mass` - Particle mass in kilograms
+pub fn rectangular_barrier_transmission(energy: f64, barrier_height: f64, width: f64, mass: f64) -> f64 {
+    assert!(energy > 0.0, "Transmission needs a positive kinetic energy");
+    let v0 = barrier_height;
+    let difference = v0 - energy;
+
+    if difference.abs() < 1e-12 * v0.abs() {
+        // Limit E → V₀
+        return 1.0 / (1.0 + mass * width * width * v0 / (2.0 * HBAR * HBAR));
+    }
+
+    let kappa = (2.0 * mass * difference.abs()).sqrt() / HBAR;
+    let oscillation = if difference > 0.0 {
+        (kappa * width).sinh().powi(2)
+    } else {
+        (kappa * width).sin().powi(2)
+    };
+    1.0 / (1.0 + v0 * v0 * oscillation / (4.0 * energy * difference.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::constants::{ANGSTROM, ELECTRON_MASS, ELEMENTARY_CHARGE};
+    use approx::assert_relative_eq;
+
+    /// Launch an electron at a rectangular barrier and measure the transmitted fraction.
+    /// Returns (simulated, analytic averaged over the packet's momentum spread).
+    fn barrier_run(energy_ev: f64, barrier_ev: f64) -> (f64, f64) {
+        let grid = WavepacketGrid::line(-200.0 * ANGSTROM, 400.0 * ANGSTROM, 4096);
+        let dx = grid.spacing().x;
+        let width = 20.0 * dx;
+        let v0 = barrier_ev * ELEMENTARY_CHARGE;
+
+        let mut sim = WavepacketSimulation::new(grid, ELECTRON_MASS, 2.0e-17, |r| {
+            if r.x >= -0.5 * dx && r.x < width - 0.5 * dx { v0 } else { 0.0 }
+        });
+
+        let sigma = 10.0 * ANGSTROM;
+        let p0 = (2.0 * ELECTRON_MASS * energy_ev * ELEMENTARY_CHARGE).sqrt();
+        sim.launch_gaussian(DVec2::new(-60.0 * ANGSTROM, 0.0), sigma, DVec2::new(p0, 0.0));
+
+        // Long enough for the transmitted part to reach ~+60 Å
+        let velocity = p0 / ELECTRON_MASS;
+        let steps = (120.0 * ANGSTROM / velocity / sim.dt) as usize;
+        sim.run(steps);
+        let simulated = sim.probability_beyond(width);
+
+        // |φ(k)|² ∝ exp(-2σ²(k - k₀)²)
+        let k0 = p0 / HBAR;
+        let sigma_k = 1.0 / (2.0 * sigma);
+        let (mut weighted, mut total) = (0.0, 0.0);
+        for i in -400..=400 {
+            let k = k0 + 6.0 * sigma_k * i as f64 / 400.0;
+            let weight = (-2.0 * sigma * sigma * (k - k0).powi(2)).exp();
+            let energy = (HBAR * k).powi(2) / (2.0 * ELECTRON_MASS);
+            weighted += weight * rectangular_barrier_transmission(energy, v0, width, ELECTRON_MASS);
+            total += weight;
+        }
+
+        (simulated, weighted / total)
+    }
+
+    #[test]
+    fn analytic_transmission_limits() {
+        let v0 = ELEMENTARY_CHARGE;
+        let a = 2.0 * ANGSTROM;
+
+        // Thick barrier: exponentially suppressed tunneling
+        let thin = rectangular_barrier_transmission(0.5 * v0, v0, a, ELECTRON_MASS);
+        let thick = rectangular_barrier_transmission(0.5 * v0, v0, 4.0 * a, ELECTRON_MASS);
+        assert!(thick < thin);
+
+        // Continuous across E = V₀
+        let at = rectangular_barrier_transmission(v0, v0, a, ELECTRON_MASS);
+        let below = rectangular_barrier_transmission(v0 * (1.0 - 1e-7), v0, a, ELECTRON_MASS);
+        let above = rectangular_barrier_transmission(v0 * (1.0 + 1e-7), v0, a, ELECTRON_MASS);
+        assert_relative_eq!(at, below, max_relative = 1e-5);
+        assert_relative_eq!(at, above, max_relative = 1e-5);
+
+        // Resonance: k'a = π above the barrier gives perfect transmission
+        let k_resonant = std::f64::consts::PI / a;
+        let energy = v0 + (HBAR * k_resonant).powi(2) / (2.0 * ELECTRON_MASS);
+        assert_relative_eq!(
+            rectangular_barrier_transmission(energy, v0, a, ELECTRON_MASS),
+            1.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn tunneling_matches_analytic_barrier() {
+        // Electron at 0.8 eV meeting a 1 eV, ~2 Å barrier: mostly tunnels through
+        let (simulated, analytic) = barrier_run(0.8, 1.0);
+        assert!(analytic > 0.5 && analytic < 0.95);
+        assert_relative_eq!(simulated, analytic, epsilon = 0.01);
+    }
+
+    #[test]
+    fn higher_barrier_transmits_less() {
+        let (simulated, analytic) = barrier_run(0.5, 2.0);
+        assert_relative_eq!(simulated, analytic, epsilon = 0.01);
+
+        let (easier, _) = barrier_run(0.5, 1.0);
+        assert!(simulated < easier);
+    }
+
+    #[test]
+    fn norm_is_conserved_through_a_barrier() {
+        let grid = WavepacketGrid::line(-100.0 * ANGSTROM, 200.0 * ANGSTROM, 1024);
+        let mut sim = WavepacketSimulation::new(grid, ELECTRON_MASS, 2.0e-17, |r| {
+            if r.x.abs() < ANGSTROM { 3.0 * ELEMENTARY_CHARGE } else { 0.0 }
+        });
+        let p0 = (2.0 * ELECTRON_MASS * ELEMENTARY_CHARGE).sqrt();
+        sim.launch_gaussian(DVec2::new(-30.0 * ANGSTROM, 0.0), 5.0 * ANGSTROM, DVec2::new(p0, 0.0));
+
+        assert_relative_eq!(sim.norm(), 1.0, epsilon = 1e-12);
+        sim.run(500);
+        assert_relative_eq!(sim.norm(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(sim.time, 500.0 * 2.0e-17, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn free_packet_in_2d_moves_at_group_velocity() {
+        let grid = WavepacketGrid::plane(
+            DVec2::splat(-40.0 * ANGSTROM),
+            DVec2::splat(80.0 * ANGSTROM),
+            128,
+            128,
+        );
+        let mut sim = WavepacketSimulation::new(grid, ELECTRON_MASS, 1.0e-17, |_| 0.0);
+
+        let momentum = DVec2::new(1.0, 0.5) * 1.0e-25;
+        sim.launch_gaussian(DVec2::new(-10.0 * ANGSTROM, -5.0 * ANGSTROM), 4.0 * ANGSTROM, momentum);
+        let start = sim.mean_position();
+
+        sim.run(200);
+
+        // ⟨r⟩ moves as p t / m (Ehrenfest)
+        let expected = start + momentum / ELECTRON_MASS * sim.time;
+        let actual = sim.mean_position();
+        assert_relative_eq!(actual.x, expected.x, epsilon = 0.05 * ANGSTROM);
+        assert_relative_eq!(actual.y, expected.y, epsilon = 0.05 * ANGSTROM);
+        assert_relative_eq!(sim.norm(), 1.0, epsilon = 1e-10);
+
+        let density = sim.density();
+        assert_eq!(density.len(), 128 * 128);
+        assert!(density.iter().all(|d| *d >= 0.0));
+    }
+}
@@ -0,0 +1,310 @@
+// Hückel molecular orbital theory for conjugated π systems
+// H_ii = α + h_i β, H_ij = k_ij β for bonded atoms, 0 otherwise
+// Orbital energies are reported as x in E = α + xβ (β < 0, so larger x is more stable)
+
+use crate::math::linalg::symmetric_eigen;
+
+/// Orbitals closer than this (in units of β) are treated as degenerate
+const DEGENERACY_TOLERANCE: f64 = 1e-8;
+
+/// A π-contributing atom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HuckelAtom {
+    /// Coulomb parameter h in α_X = α + hβ (0 for carbon)
+    pub coulomb: f64,
+    /// π electrons this atom contributes to the neutral system
+    pub pi_electrons: u32,
+}
+
+impl HuckelAtom {
+    /// An sp² carbon: h = 0, one π electron
+    pub fn carbon() -> Self {
+        Self { coulomb: 0.0, pi_electrons: 1 }
+    }
+
+    /// A heteroatom with custom Coulomb parameter and electron count
+    pub fn hetero(coulomb: f64, pi_electrons: u32) -> Self {
+        Self { coulomb, pi_electrons }
+    }
+}
+
+/// A π bond between two atoms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HuckelBond {
+    pub a: usize,
+    pub b: usize,
+    /// Resonance parameter k in β_XY = kβ (1 for C–C)
+    pub resonance: f64,
+}
+
+/// A conjugated π system described by its bond graph.
+#[derive(Debug, Clone, Default)]
+pub struct PiSystem {
+    pub atoms: Vec<HuckelAtom>,
+    pub bonds: Vec<HuckelBond>,
+    /// Net charge of the π system (+1 removes an electron)
+    pub charge: i32,
+}
+
+impl PiSystem {
+    /// Create a system of `n` carbons with no bonds yet.
+    pub fn carbons(n: usize) -> Self {
+        Self {
+            atoms: vec![HuckelAtom::carbon(); n],
+            ..Default::default()
+        }
+    }
+
+    /// Open chain of `n` carbons (ethylene, allyl, butadiene, ...)
+    pub fn carbon_chain(n: usize) -> Self {
+        (1..n).fold(Self::carbons(n), |system, i| system.with_bond(i - 1, i))
+    }
+
+    /// Ring of `n` carbons (cyclobutadiene, benzene, ...)
+    pub fn carbon_ring(n: usize) -> Self {
+        assert!(n >= 3, "A ring needs at least three atoms");
+        Self::carbon_chain(n).with_bond(n - 1, 0)
+    }
+
+    /// Add a standard C–C type bond (k = 1)
+    pub fn with_bond(self, a: usize, b: usize) -> Self {
+        self.with_scaled_bond(a, b, 1.0)
+    }
+
+    /// Add a bond with resonance parameter k
+    pub fn with_scaled_bond(mut self, a: usize, b: usize, resonance: f64) -> Self {
+        assert!(a != b && a < self.atoms.len() && b < self.atoms.len(), "Invalid bond {}-{}", a, b);
+        self.bonds.push(HuckelBond { a, b, resonance });
+        self
+    }
+
+    /// Replace atom `index` with a heteroatom
+    pub fn with_atom(mut self, index: usize, atom: HuckelAtom) -> Self {
+        self.atoms[index] = atom;
+        self
+    }
+
+    /// Set the net charge (e.g. +1 for the allyl cation)
+    pub fn with_charge(mut self, charge: i32) -> Self {
+        self.charge = charge;
+        self
+    }
+
+    /// Number of π electrons after applying the net charge
+    pub fn electron_count(&self) -> usize {
+        let neutral: i32 = self.atoms.iter().map(|atom| atom.pi_electrons as i32).sum();
+        let count = neutral - self.charge;
+        assert!(count >= 0, "Charge removes more π electrons than exist");
+        count as usize
+    }
+
+    /// Hückel matrix in units of β, relative to α: M_ii = h_i, M_ij = k_ij.
+    pub fn hamiltonian(&self) -> Vec<Vec<f64>> {
+        let n = self.atoms.len();
+        let mut m = vec![vec![0.0; n]; n];
+        for (i, atom) in self.atoms.iter().enumerate() {
+            m[i][i] = atom.coulomb;
+        }
+        for bond in &self.bonds {
+            m[bond.a][bond.b] = bond.resonance;
+            m[bond.b][bond.a] = bond.resonance;
+        }
+        m
+    }
+
+    /// Diagonalize the Hückel matrix and fill the orbitals.
+    pub fn solve(&self) -> HuckelSolution {
+        let n = self.atoms.len();
+        let eigen = symmetric_eigen(&self.hamiltonian());
+
+        // β is negative, so the largest x is the most stable orbital
+        let energies: Vec<f64> = eigen.values.iter().rev().copied().collect();
+        let coefficients: Vec<Vec<f64>> = eigen.vectors.iter().rev().cloned().collect();
+        let occupations = aufbau(&energies, self.electron_count());
+
+        // Charge-bond order matrix P_ij = Σ_k n_k c_ki c_kj
+        let mut density = vec![vec![0.0; n]; n];
+        for (orbital, &occupation) in coefficients.iter().zip(&occupations) {
+            for i in 0..n {
+                for j in 0..n {
+                    density[i][j] += occupation * orbital[i] * orbital[j];
+                }
+            }
+        }
+
+        let charges = self
+            .atoms
+            .iter()
+            .enumerate()
+            .map(|(i, atom)| atom.pi_electrons as f64 - density[i][i])
+            .collect();
+
+        HuckelSolution { energies, coefficients, occupations, density, charges }
+    }
+}
+
+/// Fill orbitals two electrons at a time, sharing electrons evenly
+/// across a partially filled degenerate shell.
+fn aufbau(energies: &[f64], electrons: usize) -> Vec<f64> {
+    assert!(electrons <= 2 * energies.len(), "More π electrons than orbitals can hold");
+
+    let mut occupations = vec![0.0; energies.len()];
+    let mut remaining = electrons as f64;
+    let mut start = 0;
+
+    while start < energies.len() && remaining > 0.0 {
+        let mut end = start + 1;
+        while end < energies.len() && (energies[start] - energies[end]).abs() < DEGENERACY_TOLERANCE {
+            end += 1;
+        }
+
+        let shell = remaining.min(2.0 * (end - start) as f64);
+        for occupation in &mut occupations[start..end] {
+            *occupation = shell / (end - start) as f64;
+        }
+        remaining -= shell;
+        start = end;
+    }
+
+    occupations
+}
+
+/// Orbitals and derived properties of a solved π system.
+#[derive(Debug, Clone)]
+pub struct HuckelSolution {
+    /// Orbital energies as x in E = α + xβ, most stable first
+    pub energies: Vec<f64>,
+    /// MO coefficients; `coefficients[k][i]` is atom i in orbital k
+    pub coefficients: Vec<Vec<f64>>,
+    /// Electrons in each orbital (0, 1 or 2, fractional for open degenerate shells)
+    pub occupations: Vec<f64>,
+    /// Charge-bond order matrix: π populations on the diagonal, π bond orders off it
+    pub density: Vec<Vec<f64>>,
+    /// π charge on each atom (contributed electrons minus population)
+    pub charges: Vec<f64>,
+}
+
+impl HuckelSolution {
+    /// Total π energy as the coefficient of β (E_π = Nα + xβ)
+    pub fn total_pi_energy(&self) -> f64 {
+        self.energies.iter().zip(&self.occupations).map(|(x, n)| x * n).sum()
+    }
+
+    /// π bond order between atoms a and b
+    pub fn bond_order(&self, a: usize, b: usize) -> f64 {
+        self.density[a][b]
+    }
+
+    /// Index of the highest occupied molecular orbital
+    pub fn homo(&self) -> Option<usize> {
+        self.occupations.iter().rposition(|&n| n > 0.0)
+    }
+
+    /// Index of the lowest orbital with room for another electron
+    pub fn lumo(&self) -> Option<usize> {
+        self.occupations.iter().position(|&n| n < 2.0)
+    }
+
+    /// HOMO–LUMO gap in units of |β|
+    pub fn homo_lumo_gap(&self) -> Option<f64> {
+        let homo = self.homo()?;
+        let lumo = self.lumo()?;
+        Some(self.energies[homo] - self.energies[lumo])
+    }
+
+    /// Delocalization energy in units of |β|, relative to every π electron
+    /// sitting in an isolated ethylene-like bond (x = 1 per electron).
+    pub fn delocalization_energy(&self) -> f64 {
+        let electrons: f64 = self.occupations.iter().sum();
+        self.total_pi_energy() - electrons
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn ethylene_has_single_pi_bond() {
+        let solution = PiSystem::carbon_chain(2).solve();
+
+        // x = ±1, both electrons in the bonding orbital
+        assert_relative_eq!(solution.energies[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.energies[1], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.bond_order(0, 1), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.delocalization_energy(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn butadiene_homo_lumo_gap() {
+        // x = ±1.618, ±0.618 (golden ratio), so the gap is 2 × 0.618 = 1.236|β|
+        let solution = PiSystem::carbon_chain(4).solve();
+        let golden = (1.0 + 5.0_f64.sqrt()) / 2.0;
+
+        assert_relative_eq!(solution.energies[0], golden, epsilon = 1e-12);
+        assert_relative_eq!(solution.energies[1], golden - 1.0, epsilon = 1e-12);
+        assert_eq!(solution.homo(), Some(1));
+        assert_eq!(solution.lumo(), Some(2));
+        assert_relative_eq!(solution.homo_lumo_gap().unwrap(), 2.0 * (golden - 1.0), epsilon = 1e-12);
+
+        // Terminal bonds are more double than the central one
+        assert_relative_eq!(solution.bond_order(0, 1), 0.894, epsilon = 1e-3);
+        assert_relative_eq!(solution.bond_order(1, 2), 0.447, epsilon = 1e-3);
+        assert_relative_eq!(solution.delocalization_energy(), 0.472, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn benzene_aromatic_stabilization() {
+        // E_π = 6α + 8β versus 6α + 6β for three ethylenes: 2β of stabilization
+        let benzene = PiSystem::carbon_ring(6).solve();
+        assert_relative_eq!(benzene.total_pi_energy(), 8.0, epsilon = 1e-12);
+        assert_relative_eq!(benzene.delocalization_energy(), 2.0, epsilon = 1e-12);
+
+        // Compared with open-chain hexatriene the ring still wins by ~1.01β
+        let hexatriene = PiSystem::carbon_chain(6).solve();
+        let aromatic = benzene.total_pi_energy() - hexatriene.total_pi_energy();
+        assert_relative_eq!(aromatic, 1.012, epsilon = 1e-3);
+
+        // All bonds equivalent, all carbons neutral
+        for i in 0..6 {
+            assert_relative_eq!(benzene.bond_order(i, (i + 1) % 6), 2.0 / 3.0, epsilon = 1e-12);
+            assert_relative_eq!(benzene.charges[i], 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn allyl_cation_charge_on_termini() {
+        // Two electrons in ψ₁ = (1, √2, 1)/2: each end carries +½
+        let solution = PiSystem::carbon_chain(3).with_charge(1).solve();
+
+        assert_relative_eq!(solution.energies[0], 2.0_f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(solution.charges[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(solution.charges[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.charges[2], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(solution.charges.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn cyclobutadiene_has_half_filled_degenerate_shell() {
+        // Antiaromatic: two electrons shared across the x = 0 pair, no stabilization
+        let solution = PiSystem::carbon_ring(4).solve();
+
+        assert_relative_eq!(solution.occupations[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.occupations[2], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.delocalization_energy(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.homo_lumo_gap().unwrap(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn heteroatom_draws_pi_density() {
+        // Formaldehyde-like C=O with h_O = 1, k_CO = 1: oxygen ends up negative
+        let solution = PiSystem::carbons(2)
+            .with_atom(1, HuckelAtom::hetero(1.0, 1))
+            .with_scaled_bond(0, 1, 1.0)
+            .solve();
+
+        assert!(solution.charges[1] < 0.0, "Oxygen should be δ−");
+        assert_relative_eq!(solution.charges[0], -solution.charges[1], epsilon = 1e-12);
+    }
+}
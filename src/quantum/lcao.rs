@@ -0,0 +1,470 @@
+// LCAO molecular orbitals for H₂⁺ and H₂
+// Bonding σg = (φa + φb)/√(2(1+S)), antibonding σu = (φa - φb)/√(2(1-S))
+// Integrals over 1s Slater orbitals are analytic; internally in atomic units
+
+use glam::DVec3;
+use crate::math::special::{exp_integral_e1, EULER_GAMMA};
+use crate::particles::electron::{OrbitalType, ProbabilityCloud};
+use crate::physics::constants::{BOHR_RADIUS, HARTREE_ENERGY};
+
+/// Which two-centre system the molecular orbitals are filled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiatomicSystem {
+    /// H₂⁺: one electron in σg (bonding) or σu (antibonding)
+    HydrogenMolecularIon,
+    /// H₂: σg² singlet (bonding) or σg¹σu¹ triplet (antibonding)
+    HydrogenMolecule,
+}
+
+/// How the 1s orbital exponent ζ is chosen (φ ∝ e^(-ζr/a₀)).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exponent {
+    /// Use the same exponent at every separation (ζ = 1 is the free atom)
+    Fixed(f64),
+    /// Variationally minimise the bonding energy at each separation
+    Optimized,
+}
+
+/// One-electron integrals between two 1s orbitals, in atomic units (Hartree).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LcaoIntegrals {
+    /// Overlap S = ⟨a|b⟩
+    pub overlap: f64,
+    /// Coulomb integral J = ⟨a|-1/r_b|a⟩
+    pub coulomb: f64,
+    /// Exchange (resonance) integral K = ⟨a|-1/r_a|b⟩
+    pub exchange: f64,
+    /// Kinetic coupling T = ⟨a|-∇²/2|b⟩
+    pub kinetic: f64,
+}
+
+/// Two-electron repulsion integrals (ij|kl) in atomic units (Hartree).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoElectronIntegrals {
+    /// One-centre repulsion (aa|aa)
+    pub on_site: f64,
+    /// Two-centre Coulomb repulsion (aa|bb)
+    pub coulomb: f64,
+    /// Hybrid integral (aa|ab)
+    pub hybrid: f64,
+    /// Two-centre exchange integral (ab|ab)
+    pub exchange: f64,
+}
+
+/// One sample of a bonding/antibonding potential energy curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyCurvePoint {
+    /// Internuclear distance in meters
+    pub separation: f64,
+    /// Bonding state energy in Joules (includes nuclear repulsion)
+    pub bonding: f64,
+    /// Antibonding state energy in Joules (includes nuclear repulsion)
+    pub antibonding: f64,
+}
+
+/// Calculate the one-electron integrals for two 1s orbitals.
+///
+/// # Arguments
+/// * `zeta` - Orbital exponent ζ (1.0 for a free hydrogen atom)
+/// * `separation` - Internuclear distance in bohr
+pub fn one_electron_integrals(zeta: f64, separation: f64) -> LcaoIntegrals {
+    assert!(separation > 0.0, "LCAO integrals need distinct nuclei");
+
+    // Everything depends on w = ζR, with one factor of ζ per 1/r and ζ² for ∇²
+    let w = zeta * separation;
+    let decay = (-w).exp();
+
+    let overlap = decay * (1.0 + w + w * w / 3.0);
+    let coulomb = -zeta * (1.0 - (1.0 + w) * (-2.0 * w).exp()) / w;
+    let exchange = -zeta * decay * (1.0 + w);
+    // From ⟨a|T - 1/r_b|b⟩ = -S/2 for ζ = 1, scaled by ζ²
+    let kinetic = zeta * zeta * (-0.5 * overlap + decay * (1.0 + w));
+
+    LcaoIntegrals { overlap, coulomb, exchange, kinetic }
+}
+
+/// Calculate the two-electron repulsion integrals for two 1s orbitals.
+///
+/// Closed forms from Slater's treatment of H₂; the exchange integral
+/// needs the exponential integral E₁.
+///
+/// # Arguments
+/// * `zeta` - Orbital exponent ζ
+/// * `separation` - Internuclear distance in bohr
+pub fn two_electron_integrals(zeta: f64, separation: f64) -> TwoElectronIntegrals {
+    assert!(separation > 0.0, "LCAO integrals need distinct nuclei");
+
+    let w = zeta * separation;
+    let overlap = (-w).exp() * (1.0 + w + w * w / 3.0);
+    // S' = S(-w), the overlap continued to negative distance
+    let overlap_reflected = w.exp() * (1.0 - w + w * w / 3.0);
+
+    let on_site = 5.0 / 8.0;
+
+    let coulomb = 1.0 / w
+        - (-2.0 * w).exp() * (1.0 / w + 11.0 / 8.0 + 3.0 * w / 4.0 + w * w / 6.0);
+
+    let hybrid = (-w).exp() * (w + 1.0 / 8.0 + 5.0 / (16.0 * w))
+        - (-3.0 * w).exp() * (1.0 / 8.0 + 5.0 / (16.0 * w));
+
+    let exchange = 0.2
+        * (-(-2.0 * w).exp() * (-25.0 / 8.0 + 23.0 * w / 4.0 + 3.0 * w * w + w.powi(3) / 3.0)
+            + 6.0 / w
+                * (overlap * overlap * (EULER_GAMMA + w.ln())
+                    - overlap_reflected * overlap_reflected * exp_integral_e1(4.0 * w)
+                    + 2.0 * overlap * overlap_reflected * exp_integral_e1(2.0 * w)));
+
+    TwoElectronIntegrals {
+        on_site: zeta * on_site,
+        coulomb: zeta * coulomb,
+        hybrid: zeta * hybrid,
+        exchange: zeta * exchange,
+    }
+}
+
+/// Bonding and antibonding energies in Hartree at a separation in bohr.
+fn energies_atomic_units(system: DiatomicSystem, zeta: f64, separation: f64) -> (f64, f64) {
+    let one = one_electron_integrals(zeta, separation);
+    let s = one.overlap;
+
+    // Core Hamiltonian matrix elements (kinetic + both nuclear attractions)
+    let h_aa = 0.5 * zeta * zeta - zeta + one.coulomb;
+    let h_ab = one.kinetic + 2.0 * one.exchange;
+
+    // Orbital energies of σg and σu, without nuclear repulsion
+    let h_g = (h_aa + h_ab) / (1.0 + s);
+    let h_u = (h_aa - h_ab) / (1.0 - s);
+    let nuclear = 1.0 / separation;
+
+    match system {
+        DiatomicSystem::HydrogenMolecularIon => (h_g + nuclear, h_u + nuclear),
+        DiatomicSystem::HydrogenMolecule => {
+            let two = two_electron_integrals(zeta, separation);
+
+            // Expand the MO densities over the atomic products
+            let j_gg = (two.on_site + two.coulomb + 4.0 * two.hybrid + 2.0 * two.exchange)
+                / (2.0 * (1.0 + s).powi(2));
+            let j_gu = (two.on_site + two.coulomb - 2.0 * two.exchange) / (2.0 * (1.0 - s * s));
+            let k_gu = (two.on_site - two.coulomb) / (2.0 * (1.0 - s * s));
+
+            let singlet = 2.0 * h_g + j_gg + nuclear;
+            let triplet = h_g + h_u + j_gu - k_gu + nuclear;
+            (singlet, triplet)
+        }
+    }
+}
+
+/// Find the orbital exponent that minimises the bonding energy.
+///
+/// # Arguments
+/// * `system` - H₂⁺ or H₂
+/// * `separation` - Internuclear distance in bohr
+pub fn optimal_exponent(system: DiatomicSystem, separation: f64) -> f64 {
+    golden_section_minimum(
+        |zeta| energies_atomic_units(system, zeta, separation).0,
+        0.5,
+        2.5,
+        1e-8,
+    )
+}
+
+/// Calculate the bonding and antibonding energies at a given separation.
+///
+/// The bonding energy of H₂ keeps the usual MO dissociation error: at large
+/// separation it tends to -1 + 5/16 Hartree rather than two free atoms.
+///
+/// # Arguments
+/// * `system` - H₂⁺ or H₂
+/// * `exponent` - How ζ is chosen
+/// * `separation` - Internuclear distance in meters
+///
+/// # Returns
+/// `(bonding, antibonding)` total energies in Joules.
+pub fn energies(system: DiatomicSystem, exponent: Exponent, separation: f64) -> (f64, f64) {
+    let r = separation / BOHR_RADIUS;
+    let zeta = match exponent {
+        Exponent::Fixed(zeta) => zeta,
+        Exponent::Optimized => optimal_exponent(system, r),
+    };
+
+    let (bonding, antibonding) = energies_atomic_units(system, zeta, r);
+    (bonding * HARTREE_ENERGY, antibonding * HARTREE_ENERGY)
+}
+
+/// Sample the bonding and antibonding energy curves on an even grid.
+///
+/// # Arguments
+/// * `r_min`, `r_max` - Separation range in meters
+/// * `samples` - Number of points (at least 2)
+pub fn energy_curve(
+    system: DiatomicSystem,
+    exponent: Exponent,
+    r_min: f64,
+    r_max: f64,
+    samples: usize,
+) -> Vec<EnergyCurvePoint> {
+    assert!(samples >= 2, "An energy curve needs at least two samples");
+
+    (0..samples)
+        .map(|i| {
+            let separation = r_min + (r_max - r_min) * i as f64 / (samples - 1) as f64;
+            let (bonding, antibonding) = energies(system, exponent, separation);
+            EnergyCurvePoint { separation, bonding, antibonding }
+        })
+        .collect()
+}
+
+/// Find the separation (meters) that minimises the bonding energy.
+pub fn equilibrium_separation(system: DiatomicSystem, exponent: Exponent) -> f64 {
+    let r = golden_section_minimum(
+        |r| {
+            let zeta = match exponent {
+                Exponent::Fixed(zeta) => zeta,
+                Exponent::Optimized => optimal_exponent(system, r),
+            };
+            energies_atomic_units(system, zeta, r).0
+        },
+        0.5,
+        6.0,
+        1e-6,
+    );
+    r * BOHR_RADIUS
+}
+
+/// Minimise a unimodal function on [lo, hi] by golden-section search.
+fn golden_section_minimum<F: Fn(f64) -> f64>(f: F, mut lo: f64, mut hi: f64, tol: f64) -> f64 {
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut x1 = hi - ratio * (hi - lo);
+    let mut x2 = lo + ratio * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+
+    while hi - lo > tol {
+        if f1 < f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - ratio * (hi - lo);
+            f1 = f(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + ratio * (hi - lo);
+            f2 = f(x2);
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+/// Bonding and antibonding orbitals built from two 1s probability clouds.
+///
+/// The clouds' centres are the nuclear positions and their length scale
+/// sets the orbital exponent ζ = a₀ / length_scale.
+#[derive(Debug, Clone)]
+pub struct DiatomicLcao {
+    pub a: ProbabilityCloud,
+    pub b: ProbabilityCloud,
+}
+
+impl DiatomicLcao {
+    /// Combine two 1s clouds of the same size into molecular orbitals.
+    pub fn from_clouds(a: &ProbabilityCloud, b: &ProbabilityCloud) -> Self {
+        assert_eq!(a.orbital, OrbitalType::S { n: 1 }, "LCAO needs 1s clouds");
+        assert_eq!(b.orbital, OrbitalType::S { n: 1 }, "LCAO needs 1s clouds");
+        assert!(
+            (a.length_scale - b.length_scale).abs() <= 1e-9 * a.length_scale,
+            "LCAO here is homonuclear: both clouds need the same length scale"
+        );
+
+        Self { a: a.clone(), b: b.clone() }
+    }
+
+    /// Orbital exponent ζ implied by the clouds' length scale
+    pub fn exponent(&self) -> f64 {
+        BOHR_RADIUS / self.a.length_scale
+    }
+
+    /// Internuclear distance in meters
+    pub fn separation(&self) -> f64 {
+        (self.b.center - self.a.center).length()
+    }
+
+    /// One-electron integrals at the current geometry (atomic units)
+    pub fn integrals(&self) -> LcaoIntegrals {
+        one_electron_integrals(self.exponent(), self.separation() / BOHR_RADIUS)
+    }
+
+    /// Bonding and antibonding energies (Joules) at the current geometry
+    pub fn energies(&self, system: DiatomicSystem) -> (f64, f64) {
+        energies(system, Exponent::Fixed(self.exponent()), self.separation())
+    }
+
+    /// Probability density of the bonding orbital σg at a point (per m³).
+    pub fn bonding_density(&self, point: DVec3) -> f64 {
+        let (phi_a, phi_b) = self.atomic_amplitudes(point);
+        (phi_a + phi_b).powi(2) / (2.0 * (1.0 + self.integrals().overlap))
+    }
+
+    /// Probability density of the antibonding orbital σu at a point (per m³).
+    pub fn antibonding_density(&self, point: DVec3) -> f64 {
+        let (phi_a, phi_b) = self.atomic_amplitudes(point);
+        (phi_a - phi_b).powi(2) / (2.0 * (1.0 - self.integrals().overlap))
+    }
+
+    /// Real 1s amplitudes φ = √|ψ|² of each cloud at a point
+    fn atomic_amplitudes(&self, point: DVec3) -> (f64, f64) {
+        (
+            self.a.probability_density(point).sqrt(),
+            self.b.probability_density(point).sqrt(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn clouds_at_bohr(separation: f64) -> DiatomicLcao {
+        let a = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let b = ProbabilityCloud::hydrogen_1s(DVec3::new(separation * BOHR_RADIUS, 0.0, 0.0));
+        DiatomicLcao::from_clouds(&a, &b)
+    }
+
+    #[test]
+    fn overlap_matches_closed_form() {
+        // S(2) = e⁻²(1 + 2 + 4/3) ≈ 0.5865
+        let integrals = one_electron_integrals(1.0, 2.0);
+        assert_relative_eq!(integrals.overlap, 0.586_452_9, max_relative = 1e-6);
+
+        // Orbitals become identical as the nuclei merge
+        assert_relative_eq!(one_electron_integrals(1.0, 1e-4).overlap, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn two_electron_integrals_merge_to_on_site_value() {
+        // As R → 0 every integral tends to (aa|aa) = 5ζ/8
+        let zeta = 1.3;
+        let two = two_electron_integrals(zeta, 1e-3);
+        let on_site = 5.0 * zeta / 8.0;
+
+        assert_relative_eq!(two.on_site, on_site, max_relative = 1e-12);
+        assert_relative_eq!(two.coulomb, on_site, max_relative = 1e-3);
+        assert_relative_eq!(two.hybrid, on_site, max_relative = 1e-3);
+        assert_relative_eq!(two.exchange, on_site, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn two_centre_coulomb_tends_to_point_charges() {
+        // Far apart, two 1s clouds repel like point charges: (aa|bb) → 1/R
+        let two = two_electron_integrals(1.0, 12.0);
+        assert_relative_eq!(two.coulomb, 1.0 / 12.0, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn h2_plus_equilibrium_near_two_bohr() {
+        // With a variational exponent the LCAO ion binds at R ≈ 2.00 a₀ (ζ ≈ 1.24)
+        let r_eq = equilibrium_separation(DiatomicSystem::HydrogenMolecularIon, Exponent::Optimized);
+        assert_relative_eq!(r_eq / BOHR_RADIUS, 2.0, epsilon = 0.03);
+
+        let zeta = optimal_exponent(DiatomicSystem::HydrogenMolecularIon, r_eq / BOHR_RADIUS);
+        assert_relative_eq!(zeta, 1.24, epsilon = 0.01);
+
+        let (bonding, _) = energies(DiatomicSystem::HydrogenMolecularIon, Exponent::Optimized, r_eq);
+        assert_relative_eq!(bonding / HARTREE_ENERGY, -0.5865, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn h2_plus_with_free_atom_orbitals() {
+        // Textbook ζ = 1 result: R_e ≈ 2.49 a₀, D_e ≈ 1.76 eV (0.0648 Hartree)
+        let r_eq = equilibrium_separation(DiatomicSystem::HydrogenMolecularIon, Exponent::Fixed(1.0));
+        assert_relative_eq!(r_eq / BOHR_RADIUS, 2.49, epsilon = 0.01);
+
+        let (bonding, _) = energies(DiatomicSystem::HydrogenMolecularIon, Exponent::Fixed(1.0), r_eq);
+        let binding = -0.5 - bonding / HARTREE_ENERGY;
+        assert_relative_eq!(binding, 0.0648, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn h2_molecule_binds_near_textbook_geometry() {
+        // Coulson MO treatment with ζ = 1: R_e ≈ 1.60 a₀, E ≈ -1.099 Hartree
+        let r_eq = equilibrium_separation(DiatomicSystem::HydrogenMolecule, Exponent::Fixed(1.0));
+        assert_relative_eq!(r_eq / BOHR_RADIUS, 1.60, epsilon = 0.02);
+
+        let (bonding, _) = energies(DiatomicSystem::HydrogenMolecule, Exponent::Fixed(1.0), r_eq);
+        assert_relative_eq!(bonding / HARTREE_ENERGY, -1.099, epsilon = 2e-3);
+    }
+
+    #[test]
+    fn antibonding_curves_are_repulsive() {
+        // Antibonding states lie above the separated-atom limit at every distance
+        for system in [DiatomicSystem::HydrogenMolecularIon, DiatomicSystem::HydrogenMolecule] {
+            let limit = match system {
+                DiatomicSystem::HydrogenMolecularIon => -0.5,
+                DiatomicSystem::HydrogenMolecule => -1.0,
+            } * HARTREE_ENERGY;
+
+            let curve = energy_curve(system, Exponent::Fixed(1.0), BOHR_RADIUS, 6.0 * BOHR_RADIUS, 20);
+            assert_eq!(curve.len(), 20);
+            for point in curve {
+                assert!(point.antibonding > limit, "Antibonding dipped below {} at {}", limit, point.separation);
+            }
+        }
+    }
+
+    #[test]
+    fn bonding_orbital_piles_density_between_nuclei() {
+        let lcao = clouds_at_bohr(2.0);
+        let midpoint = DVec3::new(BOHR_RADIUS, 0.0, 0.0);
+
+        // Average of the separate atoms, for comparison
+        let atoms = 0.5 * (lcao.a.probability_density(midpoint) + lcao.b.probability_density(midpoint));
+
+        assert!(lcao.bonding_density(midpoint) > atoms, "Bonding orbital should build up charge");
+        assert_relative_eq!(lcao.antibonding_density(midpoint), 0.0, epsilon = 1e-20);
+    }
+
+    #[test]
+    fn bonding_density_is_normalized() {
+        // Integrate over cylindrical shells around the bond axis
+        let lcao = clouds_at_bohr(2.0);
+        let (n_z, n_rho) = (300, 150);
+        let z_range = (-8.0 * BOHR_RADIUS, 10.0 * BOHR_RADIUS);
+        let rho_max = 9.0 * BOHR_RADIUS;
+        let dz = (z_range.1 - z_range.0) / n_z as f64;
+        let drho = rho_max / n_rho as f64;
+
+        let mut total = 0.0;
+        for i in 0..n_z {
+            let x = z_range.0 + (i as f64 + 0.5) * dz;
+            for j in 0..n_rho {
+                let rho = (j as f64 + 0.5) * drho;
+                let volume = 2.0 * std::f64::consts::PI * rho * drho * dz;
+                total += lcao.bonding_density(DVec3::new(x, rho, 0.0)) * volume;
+            }
+        }
+
+        assert_relative_eq!(total, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn clouds_set_exponent_and_separation() {
+        let lcao = clouds_at_bohr(1.4);
+        assert_relative_eq!(lcao.exponent(), 1.0, max_relative = 1e-12);
+        assert_relative_eq!(lcao.separation(), 1.4 * BOHR_RADIUS, max_relative = 1e-12);
+
+        let (bonding, antibonding) = lcao.energies(DiatomicSystem::HydrogenMolecule);
+        assert!(bonding < antibonding);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_non_1s_clouds() {
+        let a = ProbabilityCloud::hydrogen_1s(DVec3::ZERO);
+        let mut b = ProbabilityCloud::hydrogen_1s(DVec3::new(BOHR_RADIUS, 0.0, 0.0));
+        b.orbital = OrbitalType::S { n: 2 };
+        DiatomicLcao::from_clouds(&a, &b);
+    }
+}
@@ -0,0 +1,5 @@
+// Quantum chemistry models (molecular orbitals)
+
+pub mod lcao;
+pub mod huckel;
+pub mod two_center;
@@ -0,0 +1,253 @@
+// Heteronuclear two-centre electron cloud ("tug-of-war")
+// A bonding orbital ψ = c_A φ_A + c_B φ_B over two Slater-type orbitals
+// with different effective charges. Each centre has orbital energy
+// α = -Z_eff²/(2n²) Hartree; the coupling follows Wolfsberg–Helmholz,
+// β = K S (α_A + α_B)/2. Solving the 2×2 secular problem tilts the
+// coefficients toward the deeper (more electronegative) centre.
+
+use glam::DVec3;
+use super::lcao::one_electron_integrals;
+use crate::physics::constants::{BOHR_RADIUS, HARTREE_ENERGY};
+
+/// Wolfsberg–Helmholz proportionality constant for the resonance integral
+pub const WOLFSBERG_HELMHOLZ: f64 = 1.75;
+
+/// One nucleus pulling on the shared cloud.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloudCenter {
+    /// Nucleus position in meters
+    pub position: DVec3,
+    /// Effective nuclear charge felt by the valence orbital
+    pub z_eff: f64,
+    /// Principal quantum number of the valence orbital
+    pub n: u32,
+}
+
+impl CloudCenter {
+    /// A centre with the given effective charge and valence shell.
+    pub fn new(position: DVec3, z_eff: f64, n: u32) -> Self {
+        assert!(z_eff > 0.0 && n > 0, "Cloud centre needs Z_eff > 0 and n ≥ 1");
+        Self { position, z_eff, n }
+    }
+
+    /// A hydrogen 1s centre (Z_eff = 1)
+    pub fn hydrogen(position: DVec3) -> Self {
+        Self::new(position, 1.0, 1)
+    }
+
+    /// Orbital exponent ζ = Z_eff / n (φ ∝ e^(-ζr/a₀))
+    pub fn exponent(&self) -> f64 {
+        self.z_eff / self.n as f64
+    }
+
+    /// Valence orbital energy α = -Z_eff²/(2n²) in Hartree
+    pub fn orbital_energy(&self) -> f64 {
+        -0.5 * self.exponent().powi(2)
+    }
+
+    /// RMS extent of the orbital along one axis in meters, a₀/ζ
+    pub fn width(&self) -> f64 {
+        BOHR_RADIUS / self.exponent()
+    }
+}
+
+/// Gaussian-like summary of a cloud for drawing: a centroid and two spreads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloudShape {
+    /// Centre of electron density in meters
+    pub centroid: DVec3,
+    /// Unit vector from the first centre to the second
+    pub axis: DVec3,
+    /// RMS spread along the bond axis in meters
+    pub along: f64,
+    /// RMS spread perpendicular to the bond axis in meters
+    pub across: f64,
+}
+
+/// The bonding orbital shared between two centres.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoCenterCloud {
+    pub centers: [CloudCenter; 2],
+    /// Electrons in the bonding orbital (1 or 2)
+    pub electrons: u32,
+    /// Orbital coefficients, normalised so c_A² + c_B² + 2c_A c_B S = 1
+    pub coefficients: [f64; 2],
+    /// Overlap S between the two atomic orbitals
+    pub overlap: f64,
+    /// Bonding orbital energy in Joules
+    pub energy: f64,
+}
+
+impl TwoCenterCloud {
+    /// Solve for the bonding orbital shared by two centres.
+    ///
+    /// The overlap uses the mean exponent of the two orbitals, which is
+    /// exact for a homonuclear pair.
+    pub fn solve(a: CloudCenter, b: CloudCenter, electrons: u32) -> Self {
+        assert!((1..=2).contains(&electrons), "A single orbital holds one or two electrons");
+
+        let separation = a.position.distance(b.position) / BOHR_RADIUS;
+        assert!(separation > 0.0, "Cloud centres must be distinct");
+
+        let zeta = 0.5 * (a.exponent() + b.exponent());
+        let s = one_electron_integrals(zeta, separation).overlap;
+
+        let (alpha_a, alpha_b) = (a.orbital_energy(), b.orbital_energy());
+        let beta = WOLFSBERG_HELMHOLZ * s * 0.5 * (alpha_a + alpha_b);
+
+        // Lower root of det(H - ES) = 0
+        let qa = 1.0 - s * s;
+        let qb = 2.0 * beta * s - alpha_a - alpha_b;
+        let qc = alpha_a * alpha_b - beta * beta;
+        let energy = (-qb - (qb * qb - 4.0 * qa * qc).max(0.0).sqrt()) / (2.0 * qa);
+
+        // Null vector of (H - ES) from whichever row is better conditioned
+        let coupling = beta - energy * s;
+        let from_first = (-coupling, alpha_a - energy);
+        let from_second = (alpha_b - energy, -coupling);
+        let norm = |(x, y): (f64, f64)| x * x + y * y;
+        let (mut ca, mut cb) = if norm(from_first) >= norm(from_second) { from_first } else { from_second };
+
+        // Fully separated centres: the electron sits on the deeper one
+        if norm((ca, cb)) == 0.0 {
+            (ca, cb) = if alpha_a <= alpha_b { (1.0, 0.0) } else { (0.0, 1.0) };
+        }
+
+        let scale = (ca * ca + cb * cb + 2.0 * ca * cb * s).sqrt();
+        let sign = if ca + cb < 0.0 { -1.0 } else { 1.0 };
+
+        Self {
+            centers: [a, b],
+            electrons,
+            coefficients: [sign * ca / scale, sign * cb / scale],
+            overlap: s,
+            energy: energy * HARTREE_ENERGY,
+        }
+    }
+
+    /// Mulliken populations of the two centres (sum to the electron count)
+    pub fn populations(&self) -> [f64; 2] {
+        let [ca, cb] = self.coefficients;
+        let n = self.electrons as f64;
+        let shared = ca * cb * self.overlap;
+        [n * (ca * ca + shared), n * (cb * cb + shared)]
+    }
+
+    /// Partial charges in units of e relative to an even split of the
+    /// shared electrons (positive = electron-poor).
+    pub fn partial_charges(&self) -> [f64; 2] {
+        let half = 0.5 * self.electrons as f64;
+        let [pa, pb] = self.populations();
+        [half - pa, half - pb]
+    }
+
+    /// Fraction of the cloud on each centre (sums to 1)
+    pub fn weights(&self) -> [f64; 2] {
+        let n = self.electrons as f64;
+        let [pa, pb] = self.populations();
+        [pa / n, pb / n]
+    }
+
+    /// How far the cloud is pulled toward the second centre, from -1
+    /// (all on the first) through 0 (even) to 1 (all on the second).
+    pub fn polarity(&self) -> f64 {
+        let [wa, wb] = self.weights();
+        wb - wa
+    }
+
+    /// Centre of the electron density
+    pub fn centroid(&self) -> DVec3 {
+        let [wa, wb] = self.weights();
+        wa * self.centers[0].position + wb * self.centers[1].position
+    }
+
+    /// Centroid and spreads of the cloud.
+    ///
+    /// Each orbital contributes its own width; the along-axis spread also
+    /// grows with how far the centres sit from the centroid, so an evenly
+    /// shared cloud is stretched into a bond-shaped ellipse.
+    pub fn shape(&self) -> CloudShape {
+        let [a, b] = self.centers;
+        let [wa, wb] = self.weights();
+        let centroid = self.centroid();
+        let axis = (b.position - a.position).normalize();
+
+        let own = wa * a.width().powi(2) + wb * b.width().powi(2);
+        let offsets = wa * (a.position - centroid).dot(axis).powi(2)
+            + wb * (b.position - centroid).dot(axis).powi(2);
+
+        CloudShape {
+            centroid,
+            axis,
+            along: (own + offsets).sqrt(),
+            across: own.sqrt(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn pair(z_a: f64, z_b: f64, separation_bohr: f64) -> TwoCenterCloud {
+        TwoCenterCloud::solve(
+            CloudCenter::new(DVec3::ZERO, z_a, 1),
+            CloudCenter::new(DVec3::new(separation_bohr * BOHR_RADIUS, 0.0, 0.0), z_b, 1),
+            2,
+        )
+    }
+
+    #[test]
+    fn homonuclear_cloud_is_centred() {
+        let cloud = pair(1.0, 1.0, 1.4);
+        let [qa, qb] = cloud.partial_charges();
+
+        assert_relative_eq!(qa, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(qb, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(cloud.centroid().x, 0.7 * BOHR_RADIUS, epsilon = 1e-20);
+        assert_relative_eq!(cloud.coefficients[0], 1.0 / (2.0 * (1.0 + cloud.overlap)).sqrt(), epsilon = 1e-12);
+
+        // Bonding lies below the free atomic level
+        assert!(cloud.energy < -0.5 * HARTREE_ENERGY);
+
+        let shape = cloud.shape();
+        assert!(shape.along > shape.across);
+    }
+
+    #[test]
+    fn stronger_nucleus_wins_the_tug_of_war() {
+        let cloud = pair(1.0, 1.3, 1.4);
+        let [qa, qb] = cloud.partial_charges();
+
+        assert!(qb < 0.0 && qa > 0.0);
+        assert_relative_eq!(qa + qb, 0.0, epsilon = 1e-12);
+        assert!(cloud.polarity() > 0.0);
+        assert!(cloud.centroid().x > 0.7 * BOHR_RADIUS);
+
+        let populations = cloud.populations();
+        assert_relative_eq!(populations[0] + populations[1], 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn swapping_centres_mirrors_the_charges() {
+        let forward = pair(1.0, 1.5, 2.0).partial_charges();
+        let backward = pair(1.5, 1.0, 2.0).partial_charges();
+        assert_relative_eq!(forward[0], backward[1], epsilon = 1e-12);
+        assert_relative_eq!(forward[1], backward[0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn polarity_grows_with_distance_and_charge_difference() {
+        let near = pair(1.0, 1.3, 1.0).polarity();
+        let far = pair(1.0, 1.3, 4.0).polarity();
+        assert!(far > near, "Less overlap means less sharing: {near} vs {far}");
+
+        let mild = pair(1.0, 1.1, 1.5).polarity();
+        let strong = pair(1.0, 1.6, 1.5).polarity();
+        assert!(strong > mild);
+
+        // Far apart the electron ends up entirely on the deeper centre
+        assert_relative_eq!(pair(1.0, 1.3, 60.0).polarity(), 1.0, epsilon = 1e-9);
+    }
+}
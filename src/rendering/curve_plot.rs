@@ -0,0 +1,96 @@
+// Potential curve plot
+// Maps a sampled potential curve into a rectangle of world (pixel)
+// coordinates and back, so the curve can be drawn with gizmos and the
+// pointer can be hit-tested against it in physics units.
+
+use bevy::prelude::*;
+use crate::physics::constants::{ANGSTROM, ELEMENTARY_CHARGE};
+use crate::physics::curves::PotentialCurve;
+
+/// Placement and axis ranges of an on-screen potential curve.
+#[derive(Resource, Debug, Clone)]
+pub struct CurvePlot {
+    /// Lower-left corner in world coordinates (pixels)
+    pub origin: Vec2,
+    /// Width and height in pixels
+    pub size: Vec2,
+    /// Separation axis (min, max) in meters
+    pub distance_range: (f64, f64),
+    /// Energy axis (min, max) in Joules
+    pub energy_range: (f64, f64),
+}
+
+impl Default for CurvePlot {
+    fn default() -> Self {
+        Self {
+            // Bottom-right corner of an 800×600 window
+            origin: Vec2::new(150.0, -280.0),
+            size: Vec2::new(230.0, 150.0),
+            distance_range: (0.3 * ANGSTROM, 3.0 * ANGSTROM),
+            energy_range: (-3.0 * ELEMENTARY_CHARGE, 3.0 * ELEMENTARY_CHARGE),
+        }
+    }
+}
+
+impl CurvePlot {
+    /// Whether a world point lies inside the plot rectangle
+    pub fn contains(&self, point: Vec2) -> bool {
+        let local = point - self.origin;
+        local.x >= 0.0 && local.y >= 0.0 && local.x <= self.size.x && local.y <= self.size.y
+    }
+
+    /// World position of (distance, energy); energies off the axis are pinned to its edge
+    pub fn to_screen(&self, distance: f64, energy: f64) -> Vec2 {
+        let (r0, r1) = self.distance_range;
+        let (e0, e1) = self.energy_range;
+        let x = (distance - r0) / (r1 - r0);
+        let y = ((energy - e0) / (e1 - e0)).clamp(0.0, 1.0);
+        self.origin + self.size * Vec2::new(x as f32, y as f32)
+    }
+
+    /// (distance, energy) under a world position
+    pub fn to_curve(&self, point: Vec2) -> (f64, f64) {
+        let (r0, r1) = self.distance_range;
+        let (e0, e1) = self.energy_range;
+        let local = (point - self.origin) / self.size;
+        (r0 + local.x as f64 * (r1 - r0), e0 + local.y as f64 * (e1 - e0))
+    }
+
+    /// Distance and energy spanned by a number of pixels (for hit-test tolerances)
+    pub fn tolerances(&self, pixels: f32) -> (f64, f64) {
+        let (r0, r1) = self.distance_range;
+        let (e0, e1) = self.energy_range;
+        let pixels = pixels as f64;
+        (pixels * (r1 - r0) / self.size.x as f64, pixels * (e1 - e0) / self.size.y as f64)
+    }
+
+    /// The curve as a world-space polyline, ready for `Gizmos::linestrip_2d`
+    pub fn polyline(&self, curve: &PotentialCurve) -> Vec<Vec2> {
+        curve.samples.iter().map(|s| self.to_screen(s.distance, s.energy)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn screen_round_trip() {
+        let plot = CurvePlot::default();
+        let point = plot.to_screen(1.2 * ANGSTROM, -0.5 * ELEMENTARY_CHARGE);
+        assert!(plot.contains(point));
+
+        let (distance, energy) = plot.to_curve(point);
+        assert_relative_eq!(distance, 1.2 * ANGSTROM, max_relative = 1e-5);
+        assert_relative_eq!(energy, -0.5 * ELEMENTARY_CHARGE, max_relative = 1e-5);
+    }
+
+    #[test]
+    fn off_axis_energies_are_pinned() {
+        let plot = CurvePlot::default();
+        let wall = plot.to_screen(0.3 * ANGSTROM, 100.0 * ELEMENTARY_CHARGE);
+        assert_eq!(wall, plot.origin + Vec2::new(0.0, plot.size.y));
+        assert!(!plot.contains(Vec2::ZERO));
+    }
+}
@@ -2,6 +2,9 @@
 // Electrons are rendered as fuzzy, shimmering probability clouds
 
 use bevy::prelude::*;
+use glam::DVec3;
+use crate::quantum::two_center::CloudShape;
+use super::proton::{physics_to_screen, ProtonRenderConfig};
 
 /// Component that marks an entity for electron cloud rendering.
 #[derive(Component, Debug, Clone)]
@@ -16,6 +19,12 @@ pub struct ElectronCloudVisual {
     pub shimmer_phase: f32,
     /// Shimmer frequency (radians per second)
     pub shimmer_frequency: f32,
+    /// Shift of the cloud centre away from its anchor (in pixels)
+    pub offset: Vec2,
+    /// Direction of the cloud's long axis (radians from +x)
+    pub axis_angle: f32,
+    /// Scale along and across the long axis relative to a round cloud
+    pub stretch: Vec2,
 }
 
 impl Default for ElectronCloudVisual {
@@ -27,6 +36,9 @@ impl Default for ElectronCloudVisual {
             opacity: 0.6,
             shimmer_phase: 0.0,
             shimmer_frequency: 2.0,  // Subtle shimmer
+            offset: Vec2::ZERO,
+            axis_angle: 0.0,
+            stretch: Vec2::ONE,
         }
     }
 }
@@ -57,6 +69,45 @@ impl ElectronCloudVisual {
         1.0 + 0.05 * self.shimmer_phase.sin()
     }
 
+    /// Deform the cloud into an ellipse shifted away from its anchor.
+    ///
+    /// # Arguments
+    /// * `offset` - Shift of the cloud centre in pixels
+    /// * `axis` - Direction of the long axis on screen
+    /// * `along`, `across` - Radii along and across the axis in pixels
+    pub fn deform(&mut self, offset: Vec2, axis: Vec2, along: f32, across: f32) {
+        self.offset = offset;
+        self.axis_angle = axis.y.atan2(axis.x);
+        self.stretch = Vec2::new(along, across) / self.radius;
+    }
+
+    /// Pull the cloud toward the stronger nucleus of a two-centre bond.
+    ///
+    /// The visual radius is taken as twice the RMS spread, which matches
+    /// the default radius for a hydrogen 1s cloud.
+    pub fn deform_toward(&mut self, shape: &CloudShape, anchor: DVec3, config: &ProtonRenderConfig) {
+        let offset = physics_to_screen(shape.centroid, config) - physics_to_screen(anchor, config);
+        let axis = Vec2::new(shape.axis.x as f32, shape.axis.y as f32);
+        let pixels = 2.0 * config.scale;
+
+        self.deform(offset, axis, (shape.along * pixels) as f32, (shape.across * pixels) as f32);
+    }
+
+    /// Return to an undeformed, centred round cloud
+    pub fn reset_deformation(&mut self) {
+        self.offset = Vec2::ZERO;
+        self.axis_angle = 0.0;
+        self.stretch = Vec2::ONE;
+    }
+
+    /// Sprite transform for a cloud anchored at `anchor` (screen pixels).
+    pub fn transform_at(&self, anchor: Vec2, z: f32) -> Transform {
+        let position = anchor + self.offset;
+        Transform::from_xyz(position.x, position.y, z)
+            .with_rotation(Quat::from_rotation_z(self.axis_angle))
+            .with_scale(self.stretch.extend(1.0))
+    }
+
     /// Calculate opacity at a given distance from center (normalized 0-1)
     /// This follows the 1s orbital probability density falloff
     pub fn opacity_at_distance(&self, normalized_distance: f32) -> f32 {
@@ -253,6 +304,34 @@ mod tests {
         assert!(r_1_percent > 0.0);
     }
 
+    #[test]
+    fn deformation_follows_two_center_shape() {
+        use crate::physics::constants::BOHR_RADIUS;
+        use crate::quantum::two_center::{CloudCenter, TwoCenterCloud};
+
+        let config = ProtonRenderConfig::default();
+        let a = CloudCenter::new(DVec3::ZERO, 1.0, 1);
+        let b = CloudCenter::new(DVec3::new(0.0, 1.4 * BOHR_RADIUS, 0.0), 1.4, 1);
+        let shape = TwoCenterCloud::solve(a, b, 2).shape();
+
+        let mut visual = ElectronCloudVisual::default();
+        let midpoint = DVec3::new(0.0, 0.7 * BOHR_RADIUS, 0.0);
+        visual.deform_toward(&shape, midpoint, &config);
+
+        // Shifted toward the stronger nucleus (up), long axis vertical
+        assert!(visual.offset.y > 0.0);
+        assert_relative_eq!(visual.offset.x, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(visual.axis_angle, std::f32::consts::FRAC_PI_2, epsilon = 1e-6);
+        assert!(visual.stretch.x > visual.stretch.y);
+
+        let transform = visual.transform_at(Vec2::new(10.0, 20.0), -1.0);
+        assert_relative_eq!(transform.translation.y, 20.0 + visual.offset.y, epsilon = 1e-4);
+        assert_relative_eq!(transform.scale.x, visual.stretch.x);
+
+        visual.reset_deformation();
+        assert_eq!(visual.transform_at(Vec2::ZERO, 0.0), Transform::IDENTITY);
+    }
+
     #[test]
     fn shimmer_update() {
         let mut visual = ElectronCloudVisual::default();
@@ -2,3 +2,5 @@
 
 pub mod proton;
 pub mod electron_cloud;
+pub mod curve_plot;
+pub mod phase;
@@ -0,0 +1,37 @@
+// Per-particle phase colouring
+// When enabled, nuclei are tinted by the phase their neighbourhood is in:
+// blue for solid, green for liquid, red for gas.
+
+use bevy::prelude::*;
+use crate::physics::structure::Phase;
+
+/// Whether particles are coloured by phase, and with which colours.
+#[derive(Resource, Debug, Clone)]
+pub struct PhaseColoring {
+    pub enabled: bool,
+    pub solid: Color,
+    pub liquid: Color,
+    pub gas: Color,
+}
+
+impl Default for PhaseColoring {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            solid: Color::srgb(0.3, 0.6, 1.0),
+            liquid: Color::srgb(0.3, 0.9, 0.5),
+            gas: Color::srgb(1.0, 0.4, 0.3),
+        }
+    }
+}
+
+impl PhaseColoring {
+    /// Colour for a phase label
+    pub fn color(&self, phase: Phase) -> Color {
+        match phase {
+            Phase::Solid => self.solid,
+            Phase::Liquid => self.liquid,
+            Phase::Gas => self.gas,
+        }
+    }
+}